@@ -153,6 +153,7 @@ from libcst._nodes.statement import (
     Del,
     Else,
     ExceptHandler,
+    ExceptStarHandler,
     Expr,
     Finally,
     For,
@@ -163,14 +164,38 @@ from libcst._nodes.statement import (
     ImportAlias,
     ImportFrom,
     IndentedBlock,
+    Match,
+    MatchAs,
+    MatchCase,
+    MatchClass,
+    MatchKeywordElement,
+    MatchList,
+    MatchMapping,
+    MatchMappingElement,
+    MatchOr,
+    MatchOrElement,
+    MatchPattern,
+    MatchSequence,
+    MatchSequenceElement,
+    MatchSingleton,
+    MatchStar,
+    MatchTuple,
+    MatchValue,
     NameItem,
     Nonlocal,
+    ParamSpec,
     Pass,
     Raise,
     Return,
     SimpleStatementLine,
     SimpleStatementSuite,
     Try,
+    TryStar,
+    TypeAlias,
+    TypeParam,
+    TypeParameters,
+    TypeVar,
+    TypeVarTuple,
     While,
     With,
     WithItem,
@@ -184,7 +209,13 @@ from libcst._nodes.whitespace import (
     SimpleWhitespace,
     TrailingWhitespace,
 )
-from libcst._parser.entrypoints import parse_expression, parse_module, parse_statement
+from libcst._parser.entrypoints import (
+    check_syntax_only,
+    parse_expression,
+    parse_module,
+    parse_module_tolerant,
+    parse_statement,
+)
 from libcst._parser.types.config import PartialParserConfig
 from libcst._removal_sentinel import RemovalSentinel, RemoveFromParent
 from libcst._version import LIBCST_VERSION
@@ -217,6 +248,8 @@ __all__ = [
     "ensure_type",  # from libcst import ensure_type is deprecated, will be removed in 0.4.0
     "visit_batched",
     "parse_module",
+    "parse_module_tolerant",
+    "check_syntax_only",
     "parse_expression",
     "parse_statement",
     "CSTNode",
@@ -359,6 +392,7 @@ __all__ = [
     "Del",
     "Else",
     "ExceptHandler",
+    "ExceptStarHandler",
     "Expr",
     "Finally",
     "For",
@@ -369,14 +403,38 @@ __all__ = [
     "ImportAlias",
     "ImportFrom",
     "IndentedBlock",
+    "Match",
+    "MatchAs",
+    "MatchCase",
+    "MatchClass",
+    "MatchKeywordElement",
+    "MatchList",
+    "MatchMapping",
+    "MatchMappingElement",
+    "MatchOr",
+    "MatchOrElement",
+    "MatchPattern",
+    "MatchSequence",
+    "MatchSequenceElement",
+    "MatchSingleton",
+    "MatchStar",
+    "MatchTuple",
+    "MatchValue",
     "NameItem",
     "Nonlocal",
+    "ParamSpec",
     "Pass",
     "Raise",
     "Return",
     "SimpleStatementLine",
     "SimpleStatementSuite",
     "Try",
+    "TryStar",
+    "TypeAlias",
+    "TypeParam",
+    "TypeParameters",
+    "TypeVar",
+    "TypeVarTuple",
     "While",
     "With",
     "WithItem",
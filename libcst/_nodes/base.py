@@ -359,14 +359,24 @@ class CSTNode(ABC):
 
         return type(self)(**cloned_fields)
 
-    def deep_equals(self, other: "CSTNode") -> bool:
+    def deep_equals(self, other: "CSTNode", *, ignore_whitespace: bool = False) -> bool:
         """
         Recursively inspects the entire tree under ``self`` and ``other`` to determine if
         the two trees are equal by representation instead of identity (``==``).
+
+        When ``ignore_whitespace`` is ``True``, whitespace, comment and empty line nodes
+        are not compared, and neither are a module's default indentation and newline,
+        whether it ends with a newline, or a block's indentation. Two trees that only
+        differ in formatting are considered equal.
+
+        >>> cst.parse_expression("f(a,b)").deep_equals(
+        ...     cst.parse_expression("f( a, b )"), ignore_whitespace=True
+        ... )
+        True
         """
         from libcst._nodes.deep_equals import deep_equals as deep_equals_impl
 
-        return deep_equals_impl(self, other)
+        return deep_equals_impl(self, other, ignore_whitespace)
 
     def deep_replace(
         self: _CSTNodeSelfT, old_node: "CSTNode", new_node: CSTNodeT
@@ -11,24 +11,82 @@ Provides the implementation of `CSTNode.deep_equals`.
 from dataclasses import fields
 from typing import Sequence
 
+from libcst._maybe_sentinel import MaybeSentinel
 from libcst._nodes.base import CSTNode
+from libcst._nodes.module import Module
+from libcst._nodes.statement import IndentedBlock
+from libcst._nodes.whitespace import (
+    BaseParenthesizableWhitespace,
+    Comment,
+    EmptyLine,
+    Newline,
+    TrailingWhitespace,
+)
 
+# Fields that only hold formatting, but aren't typed as whitespace nodes.
+_WHITESPACE_FIELDS = (
+    (Module, "default_indent"),
+    (Module, "default_newline"),
+    (Module, "has_trailing_newline"),
+    (IndentedBlock, "indent"),
+)
 
-def deep_equals(a: object, b: object) -> bool:
+
+_WHITESPACE_TYPES = (
+    BaseParenthesizableWhitespace,
+    Comment,
+    EmptyLine,
+    Newline,
+    TrailingWhitespace,
+)
+
+
+def deep_equals(a: object, b: object, ignore_whitespace: bool = False) -> bool:
     if isinstance(a, CSTNode) and isinstance(b, CSTNode):
-        return _deep_equals_cst_node(a, b)
+        return _deep_equals_cst_node(a, b, ignore_whitespace)
     elif (
         isinstance(a, Sequence)
         and not isinstance(a, (str, bytes))
         and isinstance(b, Sequence)
         and not isinstance(b, (str, bytes))
     ):
-        return _deep_equals_sequence(a, b)
+        return _deep_equals_sequence(a, b, ignore_whitespace)
     else:
         return a == b
 
 
-def _deep_equals_sequence(a: Sequence[object], b: Sequence[object]) -> bool:
+def _is_whitespace(value: object) -> bool:
+    """
+    A helper function for `CSTNode.deep_equals` with ``ignore_whitespace``.
+
+    Returns whether ``value`` is a whitespace node or a (possibly empty) sequence of
+    whitespace nodes, such as ``leading_lines``.
+    """
+    if isinstance(value, _WHITESPACE_TYPES):
+        return True
+    if isinstance(value, Sequence) and not isinstance(value, (str, bytes)):
+        return all(isinstance(el, _WHITESPACE_TYPES) for el in value)
+    return False
+
+
+def _is_ignorable_whitespace(a: object, b: object) -> bool:
+    """
+    A helper function for `CSTNode.deep_equals` with ``ignore_whitespace``.
+
+    Returns whether ``a`` and ``b`` are the values of a whitespace field. Many
+    whitespace fields default to ``MaybeSentinel.DEFAULT``, which stands for whatever
+    whitespace the parser would have produced, so it's ignored like whitespace is.
+    """
+    if a is MaybeSentinel.DEFAULT:
+        return _is_whitespace(b)
+    if b is MaybeSentinel.DEFAULT:
+        return _is_whitespace(a)
+    return _is_whitespace(a) and _is_whitespace(b)
+
+
+def _deep_equals_sequence(
+    a: Sequence[object], b: Sequence[object], ignore_whitespace: bool
+) -> bool:
     """
     A helper function for `CSTNode.deep_equals`.
 
@@ -40,18 +98,24 @@ def _deep_equals_sequence(a: Sequence[object], b: Sequence[object]) -> bool:
         return True
     if len(a) != len(b):
         return False
-    return all(deep_equals(a_el, b_el) for (a_el, b_el) in zip(a, b))
+    return all(
+        deep_equals(a_el, b_el, ignore_whitespace) for (a_el, b_el) in zip(a, b)
+    )
 
 
-def _deep_equals_cst_node(a: "CSTNode", b: "CSTNode") -> bool:
+def _deep_equals_cst_node(a: "CSTNode", b: "CSTNode", ignore_whitespace: bool) -> bool:
     if type(a) is not type(b):
         return False
     if a is b:  # short-circuit
         return True
     # Ignore metadata and other hidden fields
     for field in (f for f in fields(a) if f.compare is True):
+        if ignore_whitespace and (type(a), field.name) in _WHITESPACE_FIELDS:
+            continue
         a_value = getattr(a, field.name)
         b_value = getattr(b, field.name)
-        if not deep_equals(a_value, b_value):
+        if ignore_whitespace and _is_ignorable_whitespace(a_value, b_value):
+            continue
+        if not deep_equals(a_value, b_value, ignore_whitespace):
             return False
     return True
@@ -16,7 +16,7 @@ from tokenize import (
     Imagnumber as IMAGNUMBER_RE,
     Intnumber as INTNUMBER_RE,
 )
-from typing import Callable, Generator, Optional, Sequence, Union
+from typing import Callable, Generator, Iterator, List, Optional, Sequence, Union
 
 from typing_extensions import Literal
 
@@ -674,6 +674,15 @@ class SimpleString(_BasePrefixedString):
         quote_len = len(self.quote)
         return self.value[(prefix_len + quote_len) : (-quote_len)]
 
+    @property
+    def is_bytes(self) -> bool:
+        """
+        Returns whether the string is a bytes literal, which is the case if its
+        prefix is ``b``, ``br`` or ``rb``, in any case.
+        """
+
+        return "b" in self.prefix
+
     def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "SimpleString":
         return SimpleString(
             lpar=visit_sequence(self, "lpar", self.lpar, visitor),
@@ -1020,6 +1029,23 @@ class ConcatenatedString(BaseString):
             self.whitespace_between._codegen(state)
             self.right._codegen(state)
 
+    @property
+    def evaluated_value(self) -> Optional[str]:
+        """
+        Return an :func:`ast.literal_eval` evaluated str of the recursively
+        concatenated :py:attr:`left` and :py:attr:`right` strings, or ``None`` if any
+        part of the concatenation is a :class:`FormattedString`, since f-strings cannot
+        be evaluated statically.
+        """
+        left = self.left
+        right = self.right
+        if isinstance(left, FormattedString) or isinstance(right, FormattedString):
+            return None
+        right_val = right.evaluated_value
+        if right_val is None:
+            return None
+        return left.evaluated_value + right_val
+
 
 @add_slots
 @dataclass(frozen=True)
@@ -1904,6 +1930,51 @@ class Parameters(CSTNode):
         # Validate that we don't have random stars on non star_kwarg.
         self._validate_stars()
 
+    def iter_all_params(self) -> Iterator[Param]:
+        """
+        Iterates over every :class:`Param` in this parameter list in the order they
+        appear in the source, starting with :attr:`posonly_params` and ending with
+        :attr:`star_kwarg`. A :class:`ParamStar` or :class:`ParamSlash` isn't a
+        parameter, so it's skipped.
+        """
+
+        yield from self.posonly_params
+        yield from self.params
+        star_arg = self.star_arg
+        if isinstance(star_arg, Param):
+            yield star_arg
+        yield from self.kwonly_params
+        star_kwarg = self.star_kwarg
+        if star_kwarg is not None:
+            yield star_kwarg
+
+    def param_names(self) -> List[str]:
+        """
+        Returns the names of every parameter, in the order of
+        :meth:`iter_all_params`, without any ``*`` or ``**`` prefix.
+        """
+
+        return [param.name.value for param in self.iter_all_params()]
+
+    def get_param_by_name(self, name: str) -> Optional[Param]:
+        """
+        Returns the parameter called ``name``, wherever it appears in this parameter
+        list, or ``None`` if there isn't one. ``name`` doesn't include any ``*`` or
+        ``**`` prefix.
+        """
+
+        for param in self.iter_all_params():
+            if param.name.value == name:
+                return param
+        return None
+
+    def has_param(self, name: str) -> bool:
+        """
+        Indicates that this parameter list has a parameter called ``name``.
+        """
+
+        return self.get_param_by_name(name) is not None
+
     def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "Parameters":
         return Parameters(
             posonly_params=visit_sequence(
@@ -3,9 +3,10 @@
 # This source code is licensed under the MIT license found in the
 # LICENSE file in the root directory of this source tree.
 
+import inspect
 import re
 from abc import ABC, abstractmethod
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from typing import Optional, Sequence, Union
 
 from libcst._add_slots import add_slots
@@ -19,15 +20,21 @@ from libcst._nodes.expression import (
     BaseAssignTargetExpression,
     BaseDelTargetExpression,
     BaseExpression,
-    Call,
+    ConcatenatedString,
     ExpressionPosition,
     From,
+    LeftCurlyBrace,
     LeftParen,
+    LeftSquareBracket,
     List,
     Name,
     Parameters,
+    RightCurlyBrace,
     RightParen,
+    RightSquareBracket,
+    SimpleString,
     Tuple,
+    _BaseParenthesizedNode,
 )
 from libcst._nodes.internal import (
     CodegenState,
@@ -37,7 +44,16 @@ from libcst._nodes.internal import (
     visit_sentinel,
     visit_sequence,
 )
-from libcst._nodes.op import AssignEqual, BaseAugOp, Comma, Dot, ImportStar, Semicolon
+from libcst._nodes.op import (
+    AssignEqual,
+    BaseAugOp,
+    BitOr,
+    Colon,
+    Comma,
+    Dot,
+    ImportStar,
+    Semicolon,
+)
 from libcst._nodes.whitespace import (
     BaseParenthesizableWhitespace,
     EmptyLine,
@@ -48,6 +64,8 @@ from libcst._visitors import CSTVisitorT
 
 
 _INDENT_WHITESPACE_RE = re.compile(r"[ \f\t]+", re.UNICODE)
+# The decorators that property objects use to add a getter, setter or deleter.
+_PROPERTY_ACCESSORS = ("getter", "setter", "deleter")
 
 
 class BaseSuite(CSTNode, ABC):
@@ -938,6 +956,150 @@ class Try(BaseCompoundStatement):
                 finalbody._codegen(state)
 
 
+@add_slots
+@dataclass(frozen=True)
+class ExceptStarHandler(CSTNode):
+    """
+    An ``except*`` clause that appears after a :class:`TryStar` statement.
+    """
+
+    #: The body of the except.
+    body: BaseSuite
+
+    #: The type of exception this catches. Can be a tuple in some cases.
+    type: BaseExpression
+
+    #: The optional name that a caught exception is assigned to.
+    name: Optional[AsName] = None
+
+    #: Sequence of empty lines appearing before this compound statement line.
+    leading_lines: Sequence[EmptyLine] = ()
+
+    #: The whitespace between the ``except`` keyword and the star.
+    whitespace_after_except: SimpleWhitespace = SimpleWhitespace.field("")
+
+    #: The whitespace between the star and the type.
+    whitespace_after_star: SimpleWhitespace = SimpleWhitespace.field(" ")
+
+    #: The whitespace after any type or name node (whichever comes last) and
+    #: the colon.
+    whitespace_before_colon: SimpleWhitespace = SimpleWhitespace.field("")
+
+    def _validate(self) -> None:
+        name = self.name
+        if name is not None and not isinstance(name.name, Name):
+            raise CSTValidationError(
+                "Must use a Name node for AsName name inside ExceptStarHandler."
+            )
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "ExceptStarHandler":
+        return ExceptStarHandler(
+            leading_lines=visit_sequence(
+                self, "leading_lines", self.leading_lines, visitor
+            ),
+            whitespace_after_except=visit_required(
+                self, "whitespace_after_except", self.whitespace_after_except, visitor
+            ),
+            whitespace_after_star=visit_required(
+                self, "whitespace_after_star", self.whitespace_after_star, visitor
+            ),
+            type=visit_required(self, "type", self.type, visitor),
+            name=visit_optional(self, "name", self.name, visitor),
+            whitespace_before_colon=visit_required(
+                self, "whitespace_before_colon", self.whitespace_before_colon, visitor
+            ),
+            body=visit_required(self, "body", self.body, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        for ll in self.leading_lines:
+            ll._codegen(state)
+        state.add_indent_tokens()
+
+        with state.record_syntactic_position(self, end_node=self.body):
+            state.add_token("except")
+            self.whitespace_after_except._codegen(state)
+            state.add_token("*")
+            self.whitespace_after_star._codegen(state)
+            self.type._codegen(state)
+            namenode = self.name
+            if namenode is not None:
+                namenode._codegen(state)
+            self.whitespace_before_colon._codegen(state)
+            state.add_token(":")
+            self.body._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class TryStar(BaseCompoundStatement):
+    """
+    A ``try`` statement with ``except*`` clauses, which handle exception groups. See
+    `PEP 654 <https://www.python.org/dev/peps/pep-0654/>`_.
+    """
+
+    #: The suite that is wrapped with a try statement.
+    body: BaseSuite
+
+    #: A list of one or more exception handlers.
+    handlers: Sequence[ExceptStarHandler]
+
+    #: An optional else case.
+    orelse: Optional[Else] = None
+
+    #: An optional finally case.
+    finalbody: Optional[Finally] = None
+
+    #: Sequence of empty lines appearing before this compound statement line.
+    leading_lines: Sequence[EmptyLine] = ()
+
+    #: The whitespace that appears after the ``try`` keyword but before
+    #: the colon.
+    whitespace_before_colon: SimpleWhitespace = SimpleWhitespace.field("")
+
+    def _validate(self) -> None:
+        if len(self.handlers) == 0:
+            raise CSTValidationError(
+                "A TryStar statement must have at least one ExceptStarHandler"
+            )
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "TryStar":
+        return TryStar(
+            leading_lines=visit_sequence(
+                self, "leading_lines", self.leading_lines, visitor
+            ),
+            whitespace_before_colon=visit_required(
+                self, "whitespace_before_colon", self.whitespace_before_colon, visitor
+            ),
+            body=visit_required(self, "body", self.body, visitor),
+            handlers=visit_sequence(self, "handlers", self.handlers, visitor),
+            orelse=visit_optional(self, "orelse", self.orelse, visitor),
+            finalbody=visit_optional(self, "finalbody", self.finalbody, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        for ll in self.leading_lines:
+            ll._codegen(state)
+        state.add_indent_tokens()
+
+        end_node = self.handlers[-1]
+        orelse = self.orelse
+        end_node = end_node if orelse is None else orelse
+        finalbody = self.finalbody
+        end_node = end_node if finalbody is None else finalbody
+        with state.record_syntactic_position(self, end_node=end_node):
+            state.add_token("try")
+            self.whitespace_before_colon._codegen(state)
+            state.add_token(":")
+            self.body._codegen(state)
+            for handler in self.handlers:
+                handler._codegen(state)
+            if orelse is not None:
+                orelse._codegen(state)
+            if finalbody is not None:
+                finalbody._codegen(state)
+
+
 @add_slots
 @dataclass(frozen=True)
 class ImportAlias(CSTNode):
@@ -1410,6 +1572,41 @@ class AugAssign(BaseSmallStatement):
             semicolon._codegen(state)
 
 
+def get_docstring_impl(
+    body: Union[BaseSuite, Sequence[Union[SimpleStatementLine, BaseCompoundStatement]]],
+    clean: bool,
+) -> Optional[str]:
+    """
+    Shared implementation of ``get_docstring`` for :class:`Module`,
+    :class:`FunctionDef` and :class:`ClassDef`, modelled after
+    :func:`ast.get_docstring`.
+    """
+    if isinstance(body, Sequence):
+        if body:
+            expr = body[0]
+        else:
+            return None
+    else:
+        expr = body
+    while isinstance(expr, (BaseSuite, SimpleStatementLine)):
+        if len(expr.body) == 0:
+            return None
+        expr = expr.body[0]
+    if not isinstance(expr, Expr):
+        return None
+    val = expr.value
+    if isinstance(val, (SimpleString, ConcatenatedString)):
+        evaluated_value = val.evaluated_value
+    else:
+        return None
+    if not isinstance(evaluated_value, str):
+        # Byte strings are not docstrings.
+        return None
+    if clean:
+        return inspect.cleandoc(evaluated_value)
+    return evaluated_value
+
+
 @add_slots
 @dataclass(frozen=True)
 class Decorator(CSTNode):
@@ -1418,8 +1615,10 @@ class Decorator(CSTNode):
     """
 
     #: The decorator that will return a new function wrapping the parent
-    #: of this decorator.
-    decorator: Union[Name, Attribute, Call]
+    #: of this decorator. Before Python 3.9, this could only be a :class:`Name`,
+    #: an :class:`Attribute` or a :class:`Call` of one of those. Since `PEP 614
+    #: <https://www.python.org/dev/peps/pep-0614/>`_, any expression is allowed.
+    decorator: BaseExpression
 
     #: Line comments and empty lines before this decorator. The parent
     #: :class:`FunctionDef` or :class:`ClassDef` node owns leading lines before
@@ -1432,19 +1631,6 @@ class Decorator(CSTNode):
     #: Optional trailing comment and newline following the decorator before the next line.
     trailing_whitespace: TrailingWhitespace = TrailingWhitespace.field()
 
-    def _validate(self) -> None:
-        decorator = self.decorator
-        if len(decorator.lpar) > 0 or len(decorator.rpar) > 0:
-            raise CSTValidationError(
-                "Cannot have parens around decorator in a Decorator."
-            )
-        if isinstance(decorator, Call) and not isinstance(
-            decorator.func, (Name, Attribute)
-        ):
-            raise CSTValidationError(
-                "Decorator call function must be Name or Attribute node."
-            )
-
     def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "Decorator":
         return Decorator(
             leading_lines=visit_sequence(
@@ -1472,6 +1658,173 @@ class Decorator(CSTNode):
         self.trailing_whitespace._codegen(state)
 
 
+@add_slots
+@dataclass(frozen=True)
+class TypeVar(CSTNode):
+    """
+    A simple (non-variadic) type variable in a :class:`TypeParameters` list, such as
+    ``T`` or ``T: int``.
+    """
+
+    #: The name of the type variable.
+    name: Name
+
+    #: An optional bound on the type.
+    bound: Optional[BaseExpression] = None
+
+    #: The colon used to separate the name and bound. If not specified,
+    #: :class:`MaybeSentinel` will be replaced with a colon if there is a bound,
+    #: otherwise will be left empty.
+    colon: Union[Colon, MaybeSentinel] = MaybeSentinel.DEFAULT
+
+    def _validate(self) -> None:
+        if self.bound is None and isinstance(self.colon, Colon):
+            raise CSTValidationError("Cannot have a colon without a bound.")
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "TypeVar":
+        return TypeVar(
+            name=visit_required(self, "name", self.name, visitor),
+            colon=visit_sentinel(self, "colon", self.colon, visitor),
+            bound=visit_optional(self, "bound", self.bound, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with state.record_syntactic_position(self):
+            self.name._codegen(state)
+            bound = self.bound
+            colon = self.colon
+            if isinstance(colon, MaybeSentinel):
+                if bound is not None:
+                    state.add_token(": ")
+            else:
+                colon._codegen(state)
+            if bound is not None:
+                bound._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class TypeVarTuple(CSTNode):
+    """
+    A variadic type variable in a :class:`TypeParameters` list, such as ``*Ts``.
+    """
+
+    #: The name of this type variable tuple.
+    name: Name
+
+    #: The whitespace between the star and the name.
+    whitespace_after_star: BaseParenthesizableWhitespace = SimpleWhitespace.field("")
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "TypeVarTuple":
+        return TypeVarTuple(
+            whitespace_after_star=visit_required(
+                self, "whitespace_after_star", self.whitespace_after_star, visitor
+            ),
+            name=visit_required(self, "name", self.name, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with state.record_syntactic_position(self):
+            state.add_token("*")
+            self.whitespace_after_star._codegen(state)
+            self.name._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class ParamSpec(CSTNode):
+    """
+    A parameter specification in a :class:`TypeParameters` list, such as ``**P``.
+    """
+
+    #: The name of this parameter specification.
+    name: Name
+
+    #: The whitespace between the double star and the name.
+    whitespace_after_star: BaseParenthesizableWhitespace = SimpleWhitespace.field("")
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "ParamSpec":
+        return ParamSpec(
+            whitespace_after_star=visit_required(
+                self, "whitespace_after_star", self.whitespace_after_star, visitor
+            ),
+            name=visit_required(self, "name", self.name, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with state.record_syntactic_position(self):
+            state.add_token("**")
+            self.whitespace_after_star._codegen(state)
+            self.name._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class TypeParam(CSTNode):
+    """
+    A single type parameter that is contained in a :class:`TypeParameters` list.
+    """
+
+    #: The actual parameter.
+    param: Union[TypeVar, TypeVarTuple, ParamSpec]
+
+    #: A trailing comma. If one is not provided, :class:`MaybeSentinel` will be
+    #: replaced with a comma only if a comma is required.
+    comma: Union[Comma, MaybeSentinel] = MaybeSentinel.DEFAULT
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "TypeParam":
+        return TypeParam(
+            param=visit_required(self, "param", self.param, visitor),
+            comma=visit_sentinel(self, "comma", self.comma, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState, default_comma: bool = False) -> None:
+        self.param._codegen(state)
+        comma = self.comma
+        if isinstance(comma, MaybeSentinel):
+            if default_comma:
+                state.add_token(", ")
+        else:
+            comma._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class TypeParameters(CSTNode):
+    """
+    Type parameters when specified with PEP-695 syntax, such as ``[T, *Ts, **P]``.
+    """
+
+    #: The parameters within the square brackets.
+    params: Sequence[TypeParam] = ()
+
+    #: Opening square bracket that marks the start of these parameters.
+    lbracket: LeftSquareBracket = LeftSquareBracket.field()
+
+    #: Closing square bracket that marks the end of these parameters.
+    rbracket: RightSquareBracket = RightSquareBracket.field()
+
+    def _validate(self) -> None:
+        if len(self.params) == 0:
+            raise CSTValidationError(
+                "Type parameters must have at least one parameter."
+            )
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "TypeParameters":
+        return TypeParameters(
+            lbracket=visit_required(self, "lbracket", self.lbracket, visitor),
+            params=visit_sequence(self, "params", self.params, visitor),
+            rbracket=visit_required(self, "rbracket", self.rbracket, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        self.lbracket._codegen(state)
+        params_len = len(self.params)
+        for idx, param in enumerate(self.params):
+            param._codegen(state, default_comma=idx + 1 < params_len)
+        self.rbracket._codegen(state)
+
+
 @add_slots
 @dataclass(frozen=True)
 class FunctionDef(BaseCompoundStatement):
@@ -1525,6 +1878,13 @@ class FunctionDef(BaseCompoundStatement):
     #: the colon.
     whitespace_before_colon: SimpleWhitespace = SimpleWhitespace.field("")
 
+    #: An optional declaration of type parameters.
+    type_parameters: Optional[TypeParameters] = None
+
+    #: Whitespace between the type parameters and the opening parenthesis for the
+    #: (non-type) parameters.
+    whitespace_after_type_parameters: SimpleWhitespace = SimpleWhitespace.field("")
+
     def _validate(self) -> None:
         if len(self.name.lpar) > 0 or len(self.name.rpar) > 0:
             raise CSTValidationError("Cannot have parens around Name in a FunctionDef.")
@@ -1532,6 +1892,14 @@ class FunctionDef(BaseCompoundStatement):
             raise CSTValidationError(
                 "There must be at least one space between 'def' and name."
             )
+        if (
+            self.type_parameters is None
+            and not self.whitespace_after_type_parameters.empty
+        ):
+            raise CSTValidationError(
+                "whitespace_after_type_parameters must be empty if there are no type "
+                "parameters in FunctionDef"
+            )
 
     def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "FunctionDef":
         return FunctionDef(
@@ -1552,6 +1920,15 @@ class FunctionDef(BaseCompoundStatement):
             whitespace_after_name=visit_required(
                 self, "whitespace_after_name", self.whitespace_after_name, visitor
             ),
+            type_parameters=visit_optional(
+                self, "type_parameters", self.type_parameters, visitor
+            ),
+            whitespace_after_type_parameters=visit_required(
+                self,
+                "whitespace_after_type_parameters",
+                self.whitespace_after_type_parameters,
+                visitor,
+            ),
             whitespace_before_params=visit_required(
                 self, "whitespace_before_params", self.whitespace_before_params, visitor
             ),
@@ -1580,6 +1957,10 @@ class FunctionDef(BaseCompoundStatement):
             self.whitespace_after_def._codegen(state)
             self.name._codegen(state)
             self.whitespace_after_name._codegen(state)
+            type_parameters = self.type_parameters
+            if type_parameters is not None:
+                type_parameters._codegen(state)
+            self.whitespace_after_type_parameters._codegen(state)
             state.add_token("(")
             self.whitespace_before_params._codegen(state)
             self.params._codegen(state)
@@ -1591,25 +1972,81 @@ class FunctionDef(BaseCompoundStatement):
             state.add_token(":")
             self.body._codegen(state)
 
+    def get_docstring(self, clean: bool = True) -> Optional[str]:
+        """
+        Returns a :func:`inspect.cleandoc` cleaned docstring if the docstring is
+        available, ``None`` otherwise.
+        """
+        return get_docstring_impl(self.body, clean)
 
-@add_slots
-@dataclass(frozen=True)
-class ClassDef(BaseCompoundStatement):
-    """
-    A class definition.
-    """
-
-    #: The class name.
-    name: Name
+    def _has_decorator_named(self, name: str) -> bool:
+        for decorator in self.decorators:
+            expression = decorator.decorator
+            if isinstance(expression, Name) and expression.value == name:
+                return True
+        return False
 
-    #: The class body.
-    body: BaseSuite
+    @property
+    def is_async(self) -> bool:
+        """
+        Indicates that this is an ``async def`` function.
+        """
 
-    #: Sequence of base classes this class inherits from.
-    bases: Sequence[Arg] = ()
+        return self.asynchronous is not None
 
-    #: Sequence of keywords, such as "metaclass".
-    keywords: Sequence[Arg] = ()
+    @property
+    def is_property(self) -> bool:
+        """
+        Indicates that this function is decorated with ``@property``, or is the
+        getter, setter or deleter of a property, as in ``@name.setter``.
+        """
+
+        if self._has_decorator_named("property"):
+            return True
+        for decorator in self.decorators:
+            expression = decorator.decorator
+            if (
+                isinstance(expression, Attribute)
+                and expression.attr.value in _PROPERTY_ACCESSORS
+            ):
+                return True
+        return False
+
+    @property
+    def is_classmethod(self) -> bool:
+        """
+        Indicates that this function is decorated with ``@classmethod``.
+        """
+
+        return self._has_decorator_named("classmethod")
+
+    @property
+    def is_staticmethod(self) -> bool:
+        """
+        Indicates that this function is decorated with ``@staticmethod``.
+        """
+
+        return self._has_decorator_named("staticmethod")
+
+
+@add_slots
+@dataclass(frozen=True)
+class ClassDef(BaseCompoundStatement):
+    """
+    A class definition.
+    """
+
+    #: The class name.
+    name: Name
+
+    #: The class body.
+    body: BaseSuite
+
+    #: Sequence of base classes this class inherits from.
+    bases: Sequence[Arg] = ()
+
+    #: Sequence of keywords, such as "metaclass".
+    keywords: Sequence[Arg] = ()
 
     #: Sequence of decorators applied to this class.
     decorators: Sequence[Decorator] = ()
@@ -1642,11 +2079,26 @@ class ClassDef(BaseCompoundStatement):
     #: the colon.
     whitespace_before_colon: SimpleWhitespace = SimpleWhitespace.field("")
 
+    #: An optional declaration of type parameters.
+    type_parameters: Optional[TypeParameters] = None
+
+    #: Whitespace between the type parameters and the opening parenthesis for the
+    #: bases and keywords.
+    whitespace_after_type_parameters: SimpleWhitespace = SimpleWhitespace.field("")
+
     def _validate_whitespace(self) -> None:
         if self.whitespace_after_class.empty:
             raise CSTValidationError(
                 "There must be at least one space between 'class' and name."
             )
+        if (
+            self.type_parameters is None
+            and not self.whitespace_after_type_parameters.empty
+        ):
+            raise CSTValidationError(
+                "whitespace_after_type_parameters must be empty if there are no type "
+                "parameters in ClassDef"
+            )
 
     def _validate_parens(self) -> None:
         if len(self.name.lpar) > 0 or len(self.name.rpar) > 0:
@@ -1689,6 +2141,15 @@ class ClassDef(BaseCompoundStatement):
             whitespace_after_name=visit_required(
                 self, "whitespace_after_name", self.whitespace_after_name, visitor
             ),
+            type_parameters=visit_optional(
+                self, "type_parameters", self.type_parameters, visitor
+            ),
+            whitespace_after_type_parameters=visit_required(
+                self,
+                "whitespace_after_type_parameters",
+                self.whitespace_after_type_parameters,
+                visitor,
+            ),
             lpar=visit_sentinel(self, "lpar", self.lpar, visitor),
             bases=visit_sequence(self, "bases", self.bases, visitor),
             keywords=visit_sequence(self, "keywords", self.keywords, visitor),
@@ -1713,6 +2174,10 @@ class ClassDef(BaseCompoundStatement):
             self.whitespace_after_class._codegen(state)
             self.name._codegen(state)
             self.whitespace_after_name._codegen(state)
+            type_parameters = self.type_parameters
+            if type_parameters is not None:
+                type_parameters._codegen(state)
+            self.whitespace_after_type_parameters._codegen(state)
             lpar = self.lpar
             if isinstance(lpar, MaybeSentinel):
                 if self.bases or self.keywords:
@@ -1733,6 +2198,13 @@ class ClassDef(BaseCompoundStatement):
             state.add_token(":")
             self.body._codegen(state)
 
+    def get_docstring(self, clean: bool = True) -> Optional[str]:
+        """
+        Returns a :func:`inspect.cleandoc` cleaned docstring if the docstring is
+        available, ``None`` otherwise.
+        """
+        return get_docstring_impl(self.body, clean)
+
 
 @add_slots
 @dataclass(frozen=True)
@@ -2354,3 +2826,948 @@ class Nonlocal(BaseSmallStatement):
                 state.add_token("; ")
         elif isinstance(semicolon, Semicolon):
             semicolon._codegen(state)
+
+
+class MatchPattern(_BaseParenthesizedNode, ABC):
+    """
+    A base class for anything that can appear as a pattern in a :class:`Match`
+    statement.
+    """
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchCase(CSTNode):
+    """
+    A single ``case`` block of a :class:`Match` statement.
+    """
+
+    #: The pattern that ``subject`` will be matched against.
+    pattern: MatchPattern
+
+    #: The body of this case block, to be evaluated if ``pattern`` matches ``subject``
+    #: and ``guard`` evaluates to a truthy value.
+    body: BaseSuite
+
+    #: Optional expression that will be evaluated if ``pattern`` matches ``subject``.
+    guard: Optional[BaseExpression] = None
+
+    #: Sequence of empty lines appearing before this case block.
+    leading_lines: Sequence[EmptyLine] = ()
+
+    #: Whitespace directly after the ``case`` keyword.
+    whitespace_after_case: SimpleWhitespace = SimpleWhitespace.field(" ")
+
+    #: Whitespace before the ``if`` keyword in case there's a guard expression.
+    whitespace_before_if: SimpleWhitespace = SimpleWhitespace.field(" ")
+
+    #: Whitespace after the ``if`` keyword in case there's a guard expression.
+    whitespace_after_if: SimpleWhitespace = SimpleWhitespace.field(" ")
+
+    #: Whitespace before the colon.
+    whitespace_before_colon: SimpleWhitespace = SimpleWhitespace.field("")
+
+    def _validate(self) -> None:
+        guard = self.guard
+        if (
+            guard is not None
+            and self.whitespace_after_if.empty
+            and not guard._safe_to_use_with_word_operator(ExpressionPosition.RIGHT)
+        ):
+            raise CSTValidationError(
+                "Must have at least one space after 'if' keyword if the guard is "
+                + "not parenthesized."
+            )
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchCase":
+        return MatchCase(
+            leading_lines=visit_sequence(
+                self, "leading_lines", self.leading_lines, visitor
+            ),
+            whitespace_after_case=visit_required(
+                self, "whitespace_after_case", self.whitespace_after_case, visitor
+            ),
+            pattern=visit_required(self, "pattern", self.pattern, visitor),
+            whitespace_before_if=visit_required(
+                self, "whitespace_before_if", self.whitespace_before_if, visitor
+            ),
+            whitespace_after_if=visit_required(
+                self, "whitespace_after_if", self.whitespace_after_if, visitor
+            ),
+            guard=visit_optional(self, "guard", self.guard, visitor),
+            whitespace_before_colon=visit_required(
+                self, "whitespace_before_colon", self.whitespace_before_colon, visitor
+            ),
+            body=visit_required(self, "body", self.body, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        for ll in self.leading_lines:
+            ll._codegen(state)
+        state.add_indent_tokens()
+
+        with state.record_syntactic_position(self, end_node=self.body):
+            state.add_token("case")
+            self.whitespace_after_case._codegen(state)
+            self.pattern._codegen(state)
+
+            guard = self.guard
+            if guard is not None:
+                self.whitespace_before_if._codegen(state)
+                state.add_token("if")
+                self.whitespace_after_if._codegen(state)
+                guard._codegen(state)
+
+            self.whitespace_before_colon._codegen(state)
+            state.add_token(":")
+            self.body._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class Match(BaseCompoundStatement):
+    """
+    A ``match`` statement. See `PEP 634
+    <https://www.python.org/dev/peps/pep-0634/>`_.
+    """
+
+    #: The subject of the match.
+    subject: BaseExpression
+
+    #: A non-empty list of match cases.
+    cases: Sequence[MatchCase]
+
+    #: Sequence of empty lines appearing before this compound statement line.
+    leading_lines: Sequence[EmptyLine] = ()
+
+    #: Whitespace between the ``match`` keyword and the subject.
+    whitespace_after_match: SimpleWhitespace = SimpleWhitespace.field(" ")
+
+    #: Whitespace after the subject but before the colon.
+    whitespace_before_colon: SimpleWhitespace = SimpleWhitespace.field("")
+
+    #: Any optional trailing comment and the final ``NEWLINE`` at the end of the line.
+    whitespace_after_colon: TrailingWhitespace = TrailingWhitespace.field()
+
+    #: A string represents a specific indentation. A ``None`` value uses the modules's
+    #: default indentation. This is included because indentation is allowed to be
+    #: inconsistent across a file, just not ambiguously.
+    indent: Optional[str] = None
+
+    #: Any trailing comments or lines after the dedent that are owned by this match
+    #: block. Statements own preceeding and same-line trailing comments, but not
+    #: trailing lines, so it falls on :class:`Match` to own it. In the case
+    #: that a statement follows a :class:`Match` block, that statement will own the
+    #: comments and lines that are at the same indent as the statement, and this
+    #: :class:`Match` will own the comments and lines that are indented further.
+    footer: Sequence[EmptyLine] = ()
+
+    def _validate(self) -> None:
+        if len(self.cases) == 0:
+            raise CSTValidationError("A match statement must have at least one case.")
+
+        if self.whitespace_after_match.empty:
+            if not self.subject._safe_to_use_with_word_operator(
+                ExpressionPosition.RIGHT
+            ):
+                raise CSTValidationError(
+                    "Must have at least one space after 'match' keyword if the "
+                    + "subject is not parenthesized."
+                )
+
+        indent = self.indent
+        if indent is not None:
+            if len(indent) == 0:
+                raise CSTValidationError(
+                    "A match statement must have a non-zero width indent."
+                )
+            if _INDENT_WHITESPACE_RE.fullmatch(indent) is None:
+                raise CSTValidationError(
+                    "An indent must be composed of only whitespace characters."
+                )
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "Match":
+        return Match(
+            leading_lines=visit_sequence(
+                self, "leading_lines", self.leading_lines, visitor
+            ),
+            whitespace_after_match=visit_required(
+                self, "whitespace_after_match", self.whitespace_after_match, visitor
+            ),
+            subject=visit_required(self, "subject", self.subject, visitor),
+            whitespace_before_colon=visit_required(
+                self, "whitespace_before_colon", self.whitespace_before_colon, visitor
+            ),
+            whitespace_after_colon=visit_required(
+                self, "whitespace_after_colon", self.whitespace_after_colon, visitor
+            ),
+            indent=self.indent,
+            cases=visit_sequence(self, "cases", self.cases, visitor),
+            footer=visit_sequence(self, "footer", self.footer, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        for ll in self.leading_lines:
+            ll._codegen(state)
+        state.add_indent_tokens()
+
+        with state.record_syntactic_position(self, end_node=self.cases[-1]):
+            state.add_token("match")
+            self.whitespace_after_match._codegen(state)
+            self.subject._codegen(state)
+            self.whitespace_before_colon._codegen(state)
+            state.add_token(":")
+            self.whitespace_after_colon._codegen(state)
+
+            indent = self.indent
+            state.increase_indent(state.default_indent if indent is None else indent)
+            for c in self.cases:
+                c._codegen(state)
+
+            for f in self.footer:
+                f._codegen(state)
+
+            state.decrease_indent()
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchValue(MatchPattern):
+    """
+    A match literal or value pattern that compares by equality, such as ``1``,
+    ``"foo"``, ``-1 + 2j`` or ``Color.RED``.
+    """
+
+    #: An expression to compare to.
+    value: BaseExpression
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchValue":
+        return MatchValue(value=visit_required(self, "value", self.value, visitor))
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with state.record_syntactic_position(self):
+            self.value._codegen(state)
+
+    # Parentheses are owned by the value expression, so that there's only one way to
+    # represent a parenthesized value pattern.
+    @property
+    def lpar(self) -> Sequence[LeftParen]:
+        return self.value.lpar
+
+    @property
+    def rpar(self) -> Sequence[RightParen]:
+        return self.value.rpar
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchSingleton(MatchPattern):
+    """
+    A match literal pattern that compares by identity, one of ``True``, ``False`` or
+    ``None``.
+    """
+
+    #: A literal to compare to.
+    value: Name
+
+    def _validate(self) -> None:
+        if self.value.value not in ("True", "False", "None"):
+            raise CSTValidationError(
+                "A MatchSingleton can only match True, False or None."
+            )
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchSingleton":
+        return MatchSingleton(value=visit_required(self, "value", self.value, visitor))
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with state.record_syntactic_position(self):
+            self.value._codegen(state)
+
+    # Parentheses are owned by the value, see MatchValue.
+    @property
+    def lpar(self) -> Sequence[LeftParen]:
+        return self.value.lpar
+
+    @property
+    def rpar(self) -> Sequence[RightParen]:
+        return self.value.rpar
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchSequenceElement(CSTNode):
+    """
+    An element in a :class:`MatchList`, :class:`MatchTuple` or in the positional
+    patterns of a :class:`MatchClass`.
+    """
+
+    #: The pattern for this element.
+    value: MatchPattern
+
+    #: A trailing comma. By default, we'll only insert a comma if one is required.
+    comma: Union[Comma, MaybeSentinel] = MaybeSentinel.DEFAULT
+
+    def _visit_and_replace_children(
+        self, visitor: CSTVisitorT
+    ) -> "MatchSequenceElement":
+        return MatchSequenceElement(
+            value=visit_required(self, "value", self.value, visitor),
+            comma=visit_sentinel(self, "comma", self.comma, visitor),
+        )
+
+    def _codegen_impl(
+        self,
+        state: CodegenState,
+        default_comma: bool = False,
+        default_comma_whitespace: bool = True,
+    ) -> None:
+        with state.record_syntactic_position(self):
+            self.value._codegen(state)
+        comma = self.comma
+        if comma is MaybeSentinel.DEFAULT and default_comma:
+            state.add_token(", " if default_comma_whitespace else ",")
+        elif isinstance(comma, Comma):
+            comma._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchStar(CSTNode):
+    """
+    A starred element in a :class:`MatchList` or :class:`MatchTuple`, which captures
+    the rest of the sequence, such as ``*rest``.
+    """
+
+    #: The name of the pattern binding. A ``None`` value represents ``*_``.
+    name: Optional[Name] = None
+
+    #: A trailing comma. By default, we'll only insert a comma if one is required.
+    comma: Union[Comma, MaybeSentinel] = MaybeSentinel.DEFAULT
+
+    #: Whitespace between the star and the name.
+    whitespace_before_name: BaseParenthesizableWhitespace = SimpleWhitespace.field("")
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchStar":
+        return MatchStar(
+            whitespace_before_name=visit_required(
+                self, "whitespace_before_name", self.whitespace_before_name, visitor
+            ),
+            name=visit_optional(self, "name", self.name, visitor),
+            comma=visit_sentinel(self, "comma", self.comma, visitor),
+        )
+
+    def _codegen_impl(
+        self,
+        state: CodegenState,
+        default_comma: bool = False,
+        default_comma_whitespace: bool = True,
+    ) -> None:
+        with state.record_syntactic_position(self):
+            state.add_token("*")
+            self.whitespace_before_name._codegen(state)
+            name = self.name
+            if name is None:
+                state.add_token("_")
+            else:
+                name._codegen(state)
+        comma = self.comma
+        if comma is MaybeSentinel.DEFAULT and default_comma:
+            state.add_token(", " if default_comma_whitespace else ",")
+        elif isinstance(comma, Comma):
+            comma._codegen(state)
+
+
+class MatchSequence(MatchPattern, ABC):
+    """
+    A match sequence pattern. It's either a :class:`MatchList` or a
+    :class:`MatchTuple`. Matches a variable length sequence if one of the patterns is
+    a :class:`MatchStar`, otherwise matches a fixed length sequence.
+    """
+
+    #: Patterns to be matched against the subject elements if it is a sequence.
+    patterns: Sequence[Union[MatchSequenceElement, MatchStar]]
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchList(MatchSequence):
+    """
+    A list match pattern. It's either an "open sequence pattern" (without brackets)
+    or a regular list pattern (with brackets), such as ``[a, b, *rest]``.
+    """
+
+    #: Patterns to be matched against the subject elements if it is a sequence.
+    patterns: Sequence[Union[MatchSequenceElement, MatchStar]]
+
+    lbracket: LeftSquareBracket = LeftSquareBracket.field()
+    #: Brackets surrounding the list.
+    rbracket: RightSquareBracket = RightSquareBracket.field()
+
+    lpar: Sequence[LeftParen] = ()
+    #: Parenthesis at the beginning of the node.
+    rpar: Sequence[RightParen] = ()
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchList":
+        return MatchList(
+            lpar=visit_sequence(self, "lpar", self.lpar, visitor),
+            lbracket=visit_required(self, "lbracket", self.lbracket, visitor),
+            patterns=visit_sequence(self, "patterns", self.patterns, visitor),
+            rbracket=visit_required(self, "rbracket", self.rbracket, visitor),
+            rpar=visit_sequence(self, "rpar", self.rpar, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with self._parenthesize(state):
+            self.lbracket._codegen(state)
+            pats = self.patterns
+            for idx, pat in enumerate(pats):
+                pat._codegen(state, default_comma=(idx < len(pats) - 1))
+            self.rbracket._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchTuple(MatchSequence):
+    """
+    A tuple match pattern, such as ``(a, b, *rest)``. An "open sequence pattern"
+    directly after the ``case`` keyword (``case a, b:``) is represented as a
+    :class:`MatchTuple` without parentheses.
+    """
+
+    #: Patterns to be matched against the subject elements if it is a sequence.
+    patterns: Sequence[Union[MatchSequenceElement, MatchStar]]
+
+    lpar: Sequence[LeftParen] = field(default_factory=lambda: (LeftParen(),))
+    #: Parenthesis at the beginning of the node.
+    rpar: Sequence[RightParen] = field(default_factory=lambda: (RightParen(),))
+
+    def _validate(self) -> None:
+        super(MatchTuple, self)._validate()
+
+        if len(self.patterns) == 0 and len(self.lpar) == 0:
+            raise CSTValidationError(
+                "A zero-length tuple pattern must be wrapped in parentheses."
+            )
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchTuple":
+        return MatchTuple(
+            lpar=visit_sequence(self, "lpar", self.lpar, visitor),
+            patterns=visit_sequence(self, "patterns", self.patterns, visitor),
+            rpar=visit_sequence(self, "rpar", self.rpar, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with self._parenthesize(state):
+            pats = self.patterns
+            if len(pats) == 1:
+                pats[0]._codegen(
+                    state, default_comma=True, default_comma_whitespace=False
+                )
+            else:
+                for idx, pat in enumerate(pats):
+                    pat._codegen(state, default_comma=(idx < len(pats) - 1))
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchMappingElement(CSTNode):
+    """
+    A ``key: value`` pair in a :class:`MatchMapping`.
+    """
+
+    #: A literal or value pattern that is compared with the subject's key.
+    key: BaseExpression
+
+    #: The pattern to be matched against the subject's value.
+    pattern: MatchPattern
+
+    #: A trailing comma. By default, we'll only insert a comma if one is required.
+    comma: Union[Comma, MaybeSentinel] = MaybeSentinel.DEFAULT
+
+    #: Whitespace between ``key`` and the colon.
+    whitespace_before_colon: BaseParenthesizableWhitespace = SimpleWhitespace.field("")
+
+    #: Whitespace between the colon and ``pattern``.
+    whitespace_after_colon: BaseParenthesizableWhitespace = SimpleWhitespace.field(" ")
+
+    def _visit_and_replace_children(
+        self, visitor: CSTVisitorT
+    ) -> "MatchMappingElement":
+        return MatchMappingElement(
+            key=visit_required(self, "key", self.key, visitor),
+            whitespace_before_colon=visit_required(
+                self, "whitespace_before_colon", self.whitespace_before_colon, visitor
+            ),
+            whitespace_after_colon=visit_required(
+                self, "whitespace_after_colon", self.whitespace_after_colon, visitor
+            ),
+            pattern=visit_required(self, "pattern", self.pattern, visitor),
+            comma=visit_sentinel(self, "comma", self.comma, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState, default_comma: bool = False) -> None:
+        with state.record_syntactic_position(self):
+            self.key._codegen(state)
+            self.whitespace_before_colon._codegen(state)
+            state.add_token(":")
+            self.whitespace_after_colon._codegen(state)
+            self.pattern._codegen(state)
+        comma = self.comma
+        if comma is MaybeSentinel.DEFAULT and default_comma:
+            state.add_token(", ")
+        elif isinstance(comma, Comma):
+            comma._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchMapping(MatchPattern):
+    """
+    A match mapping pattern, such as ``{"key": value, **rest}``.
+    """
+
+    #: A sequence of mapping elements.
+    elements: Sequence[MatchMappingElement] = ()
+
+    #: Left curly brace at the beginning of the pattern.
+    lbrace: LeftCurlyBrace = LeftCurlyBrace.field()
+
+    #: Right curly brace at the end of the pattern.
+    rbrace: RightCurlyBrace = RightCurlyBrace.field()
+
+    #: An optional name to capture the remaining elements of the mapping, such as
+    #: ``rest`` in ``**rest``.
+    rest: Optional[Name] = None
+
+    #: Whitespace between ``**`` and the ``rest`` name.
+    whitespace_before_rest: BaseParenthesizableWhitespace = SimpleWhitespace.field("")
+
+    #: An optional trailing comma after ``rest``. Commas after elements are owned by
+    #: the elements themselves.
+    trailing_comma: Optional[Comma] = None
+
+    lpar: Sequence[LeftParen] = ()
+    #: Parenthesis at the beginning of the node.
+    rpar: Sequence[RightParen] = ()
+
+    def _validate(self) -> None:
+        super(MatchMapping, self)._validate()
+
+        if self.trailing_comma is not None and self.rest is None:
+            raise CSTValidationError(
+                "Cannot have a trailing comma without a rest name in a MatchMapping."
+            )
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchMapping":
+        return MatchMapping(
+            lpar=visit_sequence(self, "lpar", self.lpar, visitor),
+            lbrace=visit_required(self, "lbrace", self.lbrace, visitor),
+            elements=visit_sequence(self, "elements", self.elements, visitor),
+            whitespace_before_rest=visit_required(
+                self, "whitespace_before_rest", self.whitespace_before_rest, visitor
+            ),
+            rest=visit_optional(self, "rest", self.rest, visitor),
+            trailing_comma=visit_optional(
+                self, "trailing_comma", self.trailing_comma, visitor
+            ),
+            rbrace=visit_required(self, "rbrace", self.rbrace, visitor),
+            rpar=visit_sequence(self, "rpar", self.rpar, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with self._parenthesize(state):
+            self.lbrace._codegen(state)
+            elems = self.elements
+            rest = self.rest
+            for idx, el in enumerate(elems):
+                el._codegen(
+                    state, default_comma=(rest is not None or idx < len(elems) - 1)
+                )
+            if rest is not None:
+                state.add_token("**")
+                self.whitespace_before_rest._codegen(state)
+                rest._codegen(state)
+                trailing_comma = self.trailing_comma
+                if trailing_comma is not None:
+                    trailing_comma._codegen(state)
+            self.rbrace._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchKeywordElement(CSTNode):
+    """
+    A keyword pattern in the arguments of a :class:`MatchClass`, such as ``x=0``.
+    """
+
+    #: The name of the attribute to match against.
+    key: Name
+
+    #: The pattern to be matched against the attribute.
+    pattern: MatchPattern
+
+    #: A trailing comma. By default, we'll only insert a comma if one is required.
+    comma: Union[Comma, MaybeSentinel] = MaybeSentinel.DEFAULT
+
+    #: Whitespace between ``key`` and the equals sign.
+    whitespace_before_equal: BaseParenthesizableWhitespace = SimpleWhitespace.field("")
+
+    #: Whitespace between the equals sign and ``pattern``.
+    whitespace_after_equal: BaseParenthesizableWhitespace = SimpleWhitespace.field("")
+
+    def _visit_and_replace_children(
+        self, visitor: CSTVisitorT
+    ) -> "MatchKeywordElement":
+        return MatchKeywordElement(
+            key=visit_required(self, "key", self.key, visitor),
+            whitespace_before_equal=visit_required(
+                self, "whitespace_before_equal", self.whitespace_before_equal, visitor
+            ),
+            whitespace_after_equal=visit_required(
+                self, "whitespace_after_equal", self.whitespace_after_equal, visitor
+            ),
+            pattern=visit_required(self, "pattern", self.pattern, visitor),
+            comma=visit_sentinel(self, "comma", self.comma, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState, default_comma: bool = False) -> None:
+        with state.record_syntactic_position(self):
+            self.key._codegen(state)
+            self.whitespace_before_equal._codegen(state)
+            state.add_token("=")
+            self.whitespace_after_equal._codegen(state)
+            self.pattern._codegen(state)
+        comma = self.comma
+        if comma is MaybeSentinel.DEFAULT and default_comma:
+            state.add_token(", ")
+        elif isinstance(comma, Comma):
+            comma._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchClass(MatchPattern):
+    """
+    A match class pattern, such as ``Point(0, y=0)``.
+    """
+
+    #: An expression giving the nominal class to be matched.
+    cls: BaseExpression
+
+    #: A sequence of patterns to be matched against the class defined sequence of
+    #: pattern matching attributes.
+    patterns: Sequence[MatchSequenceElement] = ()
+
+    #: A sequence of additional attribute names and corresponding patterns to be
+    #: matched.
+    kwds: Sequence[MatchKeywordElement] = ()
+
+    #: Whitespace between the class name and the left parenthesis.
+    whitespace_after_cls: BaseParenthesizableWhitespace = SimpleWhitespace.field("")
+
+    #: Whitespace between the left parenthesis and the first pattern.
+    whitespace_before_patterns: BaseParenthesizableWhitespace = SimpleWhitespace.field(
+        ""
+    )
+
+    #: Whitespace between the last pattern and the right parenthesis.
+    whitespace_after_kwds: BaseParenthesizableWhitespace = SimpleWhitespace.field("")
+
+    lpar: Sequence[LeftParen] = ()
+    #: Parenthesis at the beginning of the node.
+    rpar: Sequence[RightParen] = ()
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchClass":
+        return MatchClass(
+            lpar=visit_sequence(self, "lpar", self.lpar, visitor),
+            cls=visit_required(self, "cls", self.cls, visitor),
+            whitespace_after_cls=visit_required(
+                self, "whitespace_after_cls", self.whitespace_after_cls, visitor
+            ),
+            whitespace_before_patterns=visit_required(
+                self,
+                "whitespace_before_patterns",
+                self.whitespace_before_patterns,
+                visitor,
+            ),
+            patterns=visit_sequence(self, "patterns", self.patterns, visitor),
+            kwds=visit_sequence(self, "kwds", self.kwds, visitor),
+            whitespace_after_kwds=visit_required(
+                self, "whitespace_after_kwds", self.whitespace_after_kwds, visitor
+            ),
+            rpar=visit_sequence(self, "rpar", self.rpar, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with self._parenthesize(state):
+            self.cls._codegen(state)
+            self.whitespace_after_cls._codegen(state)
+            state.add_token("(")
+            self.whitespace_before_patterns._codegen(state)
+            pats = self.patterns
+            kwds = self.kwds
+            for idx, pat in enumerate(pats):
+                pat._codegen(state, default_comma=(idx < len(pats) - 1 or bool(kwds)))
+            for idx, kwd in enumerate(kwds):
+                kwd._codegen(state, default_comma=(idx < len(kwds) - 1))
+            self.whitespace_after_kwds._codegen(state)
+            state.add_token(")")
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchAs(MatchPattern):
+    """
+    A match "as-pattern", capture pattern, or wildcard pattern, such as
+    ``[x] as y``, ``y`` or ``_``.
+    """
+
+    #: The match pattern that the subject will be matched against. If this is
+    #: ``None``, the node represents a capture pattern (i.e. a bare name) and will
+    #: always succeed.
+    pattern: Optional[MatchPattern] = None
+
+    #: The name that will be bound if the pattern is successful. If this is ``None``,
+    #: ``pattern`` must also be ``None`` and the node represents the wildcard pattern
+    #: (i.e. ``_``).
+    name: Optional[Name] = None
+
+    #: Whitespace between ``pattern`` and the ``as`` keyword (if ``pattern`` is not
+    #: ``None``).
+    whitespace_before_as: Union[
+        BaseParenthesizableWhitespace, MaybeSentinel
+    ] = MaybeSentinel.DEFAULT
+
+    #: Whitespace between the ``as`` keyword and ``name`` (if ``pattern`` is not
+    #: ``None``).
+    whitespace_after_as: Union[
+        BaseParenthesizableWhitespace, MaybeSentinel
+    ] = MaybeSentinel.DEFAULT
+
+    lpar: Sequence[LeftParen] = ()
+    #: Parenthesis at the beginning of the node.
+    rpar: Sequence[RightParen] = ()
+
+    def _validate(self) -> None:
+        super(MatchAs, self)._validate()
+
+        if self.name is None and self.pattern is not None:
+            raise CSTValidationError("Pattern must be None if name is None.")
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchAs":
+        return MatchAs(
+            lpar=visit_sequence(self, "lpar", self.lpar, visitor),
+            pattern=visit_optional(self, "pattern", self.pattern, visitor),
+            whitespace_before_as=visit_sentinel(
+                self, "whitespace_before_as", self.whitespace_before_as, visitor
+            ),
+            whitespace_after_as=visit_sentinel(
+                self, "whitespace_after_as", self.whitespace_after_as, visitor
+            ),
+            name=visit_optional(self, "name", self.name, visitor),
+            rpar=visit_sequence(self, "rpar", self.rpar, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with self._parenthesize(state):
+            pat = self.pattern
+            name = self.name
+            if pat is not None:
+                pat._codegen(state)
+                ws_before = self.whitespace_before_as
+                if ws_before is MaybeSentinel.DEFAULT:
+                    state.add_token(" ")
+                elif isinstance(ws_before, BaseParenthesizableWhitespace):
+                    ws_before._codegen(state)
+                state.add_token("as")
+                ws_after = self.whitespace_after_as
+                if ws_after is MaybeSentinel.DEFAULT:
+                    state.add_token(" ")
+                elif isinstance(ws_after, BaseParenthesizableWhitespace):
+                    ws_after._codegen(state)
+            if name is None:
+                state.add_token("_")
+            else:
+                name._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchOrElement(CSTNode):
+    """
+    An element in a :class:`MatchOr` node.
+    """
+
+    #: The pattern for this element.
+    pattern: MatchPattern
+
+    #: An optional ``|`` separator following the pattern. By default, we'll only
+    #: insert a separator if one is required.
+    separator: Union[BitOr, MaybeSentinel] = MaybeSentinel.DEFAULT
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchOrElement":
+        return MatchOrElement(
+            pattern=visit_required(self, "pattern", self.pattern, visitor),
+            separator=visit_sentinel(self, "separator", self.separator, visitor),
+        )
+
+    def _codegen_impl(
+        self, state: CodegenState, default_separator: bool = False
+    ) -> None:
+        with state.record_syntactic_position(self):
+            self.pattern._codegen(state)
+        sep = self.separator
+        if sep is MaybeSentinel.DEFAULT and default_separator:
+            state.add_token(" | ")
+        elif isinstance(sep, BitOr):
+            sep._codegen(state)
+
+
+@add_slots
+@dataclass(frozen=True)
+class MatchOr(MatchPattern):
+    """
+    A match "or-pattern", which matches each of its subpatterns in turn to the
+    subject until one succeeds, such as ``1 | 2 | 3``.
+    """
+
+    #: The subpatterns to be tried in turn.
+    patterns: Sequence[MatchOrElement]
+
+    lpar: Sequence[LeftParen] = ()
+    #: Parenthesis at the beginning of the node.
+    rpar: Sequence[RightParen] = ()
+
+    def _validate(self) -> None:
+        super(MatchOr, self)._validate()
+
+        if len(self.patterns) < 2:
+            raise CSTValidationError("A MatchOr must have at least two patterns.")
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "MatchOr":
+        return MatchOr(
+            lpar=visit_sequence(self, "lpar", self.lpar, visitor),
+            patterns=visit_sequence(self, "patterns", self.patterns, visitor),
+            rpar=visit_sequence(self, "rpar", self.rpar, visitor),
+        )
+
+    def _codegen_impl(self, state: CodegenState) -> None:
+        with self._parenthesize(state):
+            pats = self.patterns
+            for idx, pat in enumerate(pats):
+                pat._codegen(state, default_separator=(idx < len(pats) - 1))
+
+
+@add_slots
+@dataclass(frozen=True)
+class TypeAlias(BaseSmallStatement):
+    """
+    A type alias statement, such as ``type Alias[T] = list[T]``. See `PEP 695
+    <https://www.python.org/dev/peps/pep-0695/>`_.
+    """
+
+    #: The name being introduced in this statement.
+    name: Name
+
+    #: Everything on the right hand side of the ``=``.
+    value: BaseExpression
+
+    #: An optional list of type parameters, specified after the name.
+    type_parameters: Optional[TypeParameters] = None
+
+    #: Whitespace between the ``type`` soft keyword and the name.
+    whitespace_after_type: SimpleWhitespace = SimpleWhitespace.field(" ")
+
+    #: Whitespace between the name and the type parameters (if they exist) or the
+    #: ``=``. If not specified, :class:`MaybeSentinel` will be replaced with a single
+    #: space if there are no type parameters, otherwise no spaces.
+    whitespace_after_name: Union[
+        SimpleWhitespace, MaybeSentinel
+    ] = MaybeSentinel.DEFAULT
+
+    #: Whitespace between the type parameters and the ``=``. Always empty if there
+    #: are no type parameters. If not specified, :class:`MaybeSentinel` will be
+    #: replaced with a single space if there are type parameters.
+    whitespace_after_type_parameters: Union[
+        SimpleWhitespace, MaybeSentinel
+    ] = MaybeSentinel.DEFAULT
+
+    #: Whitespace between the ``=`` and the value.
+    whitespace_after_equals: SimpleWhitespace = SimpleWhitespace.field(" ")
+
+    #: Optional semicolon when this is used in a statement line. This semicolon
+    #: owns the whitespace on both sides of it when it is used.
+    semicolon: Union[Semicolon, MaybeSentinel] = MaybeSentinel.DEFAULT
+
+    def _validate(self) -> None:
+        whitespace_after_type_parameters = self.whitespace_after_type_parameters
+        if (
+            self.type_parameters is None
+            and isinstance(whitespace_after_type_parameters, SimpleWhitespace)
+            and not whitespace_after_type_parameters.empty
+        ):
+            raise CSTValidationError(
+                "whitespace_after_type_parameters must be empty when there are no "
+                + "type parameters in a TypeAlias"
+            )
+
+    def _visit_and_replace_children(self, visitor: CSTVisitorT) -> "TypeAlias":
+        return TypeAlias(
+            whitespace_after_type=visit_required(
+                self, "whitespace_after_type", self.whitespace_after_type, visitor
+            ),
+            name=visit_required(self, "name", self.name, visitor),
+            whitespace_after_name=visit_sentinel(
+                self, "whitespace_after_name", self.whitespace_after_name, visitor
+            ),
+            type_parameters=visit_optional(
+                self, "type_parameters", self.type_parameters, visitor
+            ),
+            whitespace_after_type_parameters=visit_sentinel(
+                self,
+                "whitespace_after_type_parameters",
+                self.whitespace_after_type_parameters,
+                visitor,
+            ),
+            whitespace_after_equals=visit_required(
+                self, "whitespace_after_equals", self.whitespace_after_equals, visitor
+            ),
+            value=visit_required(self, "value", self.value, visitor),
+            semicolon=visit_sentinel(self, "semicolon", self.semicolon, visitor),
+        )
+
+    def _codegen_impl(
+        self, state: CodegenState, default_semicolon: bool = False
+    ) -> None:
+        with state.record_syntactic_position(self):
+            state.add_token("type")
+            self.whitespace_after_type._codegen(state)
+            self.name._codegen(state)
+
+            type_parameters = self.type_parameters
+            whitespace_after_name = self.whitespace_after_name
+            if isinstance(whitespace_after_name, MaybeSentinel):
+                if type_parameters is None:
+                    state.add_token(" ")
+            else:
+                whitespace_after_name._codegen(state)
+
+            if type_parameters is not None:
+                type_parameters._codegen(state)
+            whitespace_after_type_parameters = self.whitespace_after_type_parameters
+            if isinstance(whitespace_after_type_parameters, MaybeSentinel):
+                if type_parameters is not None:
+                    state.add_token(" ")
+            else:
+                whitespace_after_type_parameters._codegen(state)
+
+            state.add_token("=")
+            self.whitespace_after_equals._codegen(state)
+            self.value._codegen(state)
+
+        semicolon = self.semicolon
+        if isinstance(semicolon, MaybeSentinel):
+            if default_semicolon:
+                state.add_token("; ")
+        elif isinstance(semicolon, Semicolon):
+            semicolon._codegen(state)
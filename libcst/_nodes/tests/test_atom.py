@@ -5,10 +5,11 @@
 
 # pyre-strict
 
+from ast import literal_eval
 from typing import Any
 
 import libcst as cst
-from libcst import parse_expression
+from libcst import parse_expression, parse_module
 from libcst._nodes.tests.base import CSTNodeTest
 from libcst.metadata import CodeRange
 from libcst.testing.utils import data_provider
@@ -1045,3 +1046,54 @@ class StringHelperTest(CSTNodeTest):
         self.assertEqual(multilinestring.raw_value, "")
         self.assertEqual(formatstring.prefix, "f")
         self.assertEqual(formatstring.quote, '"""')
+
+    @data_provider(
+        (
+            ("b",),
+            ("B",),
+            ("br",),
+            ("bR",),
+            ("Br",),
+            ("BR",),
+            ("rb",),
+            ("rB",),
+            ("Rb",),
+            ("RB",),
+        )
+    )
+    def test_bytes_prefixes(self, prefix: str) -> None:
+        for quote in ("'", '"', "'''", '"""'):
+            code = f"{prefix}{quote}a\\b{quote}"
+            node = cst.ensure_type(parse_expression(code), cst.SimpleString)
+            self.assertEqual(node.prefix, prefix.lower())
+            self.assertEqual(node.quote, quote)
+            self.assertEqual(node.raw_value, "a\\b")
+            self.assertTrue(node.is_bytes)
+            self.assertEqual(node.evaluated_value, literal_eval(code))
+            self.assertEqual(cst.Module([]).code_for_node(node), code)
+            self.assertEqual(parse_module(f"x = {code}\n").code, f"x = {code}\n")
+
+    def test_is_bytes(self) -> None:
+        for code in ('""', 'r""', 'u""', 'R""', 'U""'):
+            node = cst.ensure_type(parse_expression(code), cst.SimpleString)
+            self.assertFalse(node.is_bytes)
+
+    @data_provider(
+        (
+            ('b"a" "b"',),
+            ('"a" b"b"',),
+            ('"a" "b" b"c"',),
+            ('f"a" b"b"',),
+            ('rb"a" u"b"',),
+        )
+    )
+    def test_concatenated_string_and_bytes(self, code: str) -> None:
+        with self.assertRaisesRegex(
+            cst.ParserSyntaxError, "Cannot concatenate string and bytes"
+        ):
+            parse_expression(code)
+
+    @data_provider((('b"a" rb"b" Br"c"',), ('"a" r"b" f"c" u"d"',)))
+    def test_concatenated_same_type(self, code: str) -> None:
+        node = cst.ensure_type(parse_expression(code), cst.ConcatenatedString)
+        self.assertEqual(cst.Module([]).code_for_node(node), code)
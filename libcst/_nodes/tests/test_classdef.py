@@ -108,6 +108,24 @@ class ClassDefCreationTest(CSTNodeTest):
                 ),
                 "code": "class Foo(**one, **two, **three): pass\n",
             },
+            # Type parameters (PEP 695)
+            {
+                "node": cst.ClassDef(
+                    cst.Name("Foo"),
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    bases=(cst.Arg(cst.Name("Bar")),),
+                    type_parameters=cst.TypeParameters(
+                        (
+                            cst.TypeParam(
+                                cst.TypeVar(cst.Name("T")), comma=cst.Comma()
+                            ),
+                            cst.TypeParam(cst.TypeVarTuple(cst.Name("Ts"))),
+                        )
+                    ),
+                ),
+                "code": "class Foo[T,*Ts](Bar): pass\n",
+                "expected_position": CodeRange((1, 0), (1, 27)),
+            },
         )
     )
     def test_valid(self, **kwargs: Any) -> None:
@@ -141,6 +159,14 @@ class ClassDefCreationTest(CSTNodeTest):
                 ),
                 "at least one space between 'class' and name",
             ),
+            (
+                lambda: cst.ClassDef(
+                    name=cst.Name("Foo"),
+                    body=cst.SimpleStatementSuite((cst.Pass(),)),
+                    whitespace_after_type_parameters=cst.SimpleWhitespace(" "),
+                ),
+                "whitespace_after_type_parameters must be empty",
+            ),
         )
     )
     def test_invalid(
@@ -149,6 +175,10 @@ class ClassDefCreationTest(CSTNodeTest):
         self.assert_invalid(get_node, expected_re)
 
 
+def _parse_statement_force_312(code: str) -> cst.BaseCompoundStatement:
+    return parse_statement(code, config=cst.PartialParserConfig(python_version="3.12"))
+
+
 class ClassDefParserTest(CSTNodeTest):
     @data_provider(
         (
@@ -334,3 +364,55 @@ class ClassDefParserTest(CSTNodeTest):
     )
     def test_valid(self, **kwargs: Any) -> None:
         self.validate_node(**kwargs, parser=parse_statement)
+
+    @data_provider(
+        (
+            # Type parameters without bases
+            {
+                "node": cst.ClassDef(
+                    cst.Name("Foo"),
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    type_parameters=cst.TypeParameters(
+                        (
+                            cst.TypeParam(
+                                cst.TypeVar(
+                                    cst.Name("T"),
+                                    bound=cst.Name("int"),
+                                    colon=cst.Colon(
+                                        whitespace_after=cst.SimpleWhitespace(" ")
+                                    ),
+                                )
+                            ),
+                        )
+                    ),
+                    whitespace_after_name=cst.SimpleWhitespace(" "),
+                    whitespace_after_type_parameters=cst.SimpleWhitespace(" "),
+                ),
+                "code": "class Foo [T: int] : pass\n",
+            },
+            # Type parameters with bases
+            {
+                "node": cst.ClassDef(
+                    cst.Name("Foo"),
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    bases=(cst.Arg(cst.Name("Bar")),),
+                    lpar=cst.LeftParen(),
+                    rpar=cst.RightParen(),
+                    type_parameters=cst.TypeParameters(
+                        (cst.TypeParam(cst.ParamSpec(cst.Name("P"))),)
+                    ),
+                    whitespace_after_type_parameters=cst.SimpleWhitespace(" "),
+                ),
+                "code": "class Foo[**P] (Bar): pass\n",
+            },
+        )
+    )
+    def test_valid_312(self, **kwargs: Any) -> None:
+        self.validate_node(**kwargs, parser=_parse_statement_force_312)
+
+    def test_type_parameters_invalid_311(self) -> None:
+        with self.assertRaises(cst.ParserSyntaxError):
+            parse_statement(
+                "class Foo[T]: pass\n",
+                config=cst.PartialParserConfig(python_version="3.11"),
+            )
@@ -33,3 +33,14 @@ class CommentTest(CSTNodeTest):
         self, get_node: Callable[[], cst.CSTNode], expected_re: str
     ) -> None:
         self.assert_invalid(get_node, expected_re)
+
+    @data_provider(
+        (
+            ("#", ""),
+            ("#comment text", "comment text"),
+            ("#  comment text ", "comment text "),
+            ("#\t# nested", "# nested"),
+        )
+    )
+    def test_text(self, value: str, expected: str) -> None:
+        self.assertEqual(cst.Comment(value).text, expected)
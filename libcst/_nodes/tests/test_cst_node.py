@@ -121,6 +121,75 @@ class CSTNodeTest(UnitTest):
     def test_deep_equals_fails(self, a: cst.CSTNode, b: cst.CSTNode) -> None:
         self.assertFalse(a.deep_equals(b))
 
+    @data_provider(
+        {
+            "simple_whitespace": (
+                cst.parse_expression("f(a,b)"),
+                cst.parse_expression("f( a , b )"),
+            ),
+            "parenthesized_whitespace": (
+                cst.parse_expression("[a, b]"),
+                cst.parse_expression("[\n    a,\n    b\n]"),
+            ),
+            "comments_and_empty_lines": (
+                cst.parse_module("x = 1\ny = 2\n"),
+                cst.parse_module("# header\n\nx = 1  # comment\n\n\ny = 2\n"),
+            ),
+            "constructed_versus_parsed": (
+                cst.parse_statement("if  x :  pass"),
+                cst.If(
+                    test=cst.Name("x"), body=cst.SimpleStatementSuite((cst.Pass(),))
+                ),
+            ),
+            "indentation": (
+                cst.parse_module("if x:\n  pass\n"),
+                cst.parse_module("if x:\n    pass\n"),
+            ),
+            "newline": (cst.parse_module("x=1\n"), cst.parse_module("x=1\r\n")),
+            "trailing_newline": (cst.parse_module("x=1"), cst.parse_module("x=1\n")),
+            "default_whitespace_return": (
+                cst.parse_statement("return x"),
+                cst.SimpleStatementLine((cst.Return(cst.Name("x")),)),
+            ),
+            "default_whitespace_lambda": (
+                cst.parse_expression("lambda x: x"),
+                # ``star`` isn't whitespace, so it has to match what the parser sets
+                cst.Lambda(
+                    cst.Parameters(params=(cst.Param(cst.Name("x"), star=""),)),
+                    cst.Name("x"),
+                ),
+            ),
+        }
+    )
+    def test_deep_equals_ignore_whitespace_success(
+        self, a: cst.CSTNode, b: cst.CSTNode
+    ) -> None:
+        self.assertFalse(a.deep_equals(b))
+        self.assertTrue(a.deep_equals(b, ignore_whitespace=True))
+        self.assertTrue(b.deep_equals(a, ignore_whitespace=True))
+
+    @data_provider(
+        {
+            "different_names": (
+                cst.parse_expression("f(a, b)"),
+                cst.parse_expression("f(a, c)"),
+            ),
+            "trailing_comma": (
+                cst.parse_expression("f(a, b)"),
+                cst.parse_expression("f(a, b,)"),
+            ),
+            "parentheses": (cst.parse_expression("a"), cst.parse_expression("(a)")),
+            "extra_statement": (
+                cst.parse_module("x = 1\n"),
+                cst.parse_module("x = 1\npass\n"),
+            ),
+        }
+    )
+    def test_deep_equals_ignore_whitespace_fails(
+        self, a: cst.CSTNode, b: cst.CSTNode
+    ) -> None:
+        self.assertFalse(a.deep_equals(b, ignore_whitespace=True))
+
     def test_repr(self) -> None:
         self.assertEqual(
             repr(
@@ -33,3 +33,25 @@ class EmptyLineTest(CSTNodeTest):
     )
     def test_valid(self, node: cst.CSTNode, code: str) -> None:
         self.validate_node(node, code)
+
+    @data_provider(
+        (
+            (cst.EmptyLine(), True, False),
+            (cst.EmptyLine(indent=False, newline=cst.Newline("\r\n")), True, False),
+            (cst.EmptyLine(whitespace=cst.SimpleWhitespace("  ")), False, False),
+            (cst.EmptyLine(comment=cst.Comment("# comment")), False, True),
+            (
+                cst.EmptyLine(
+                    whitespace=cst.SimpleWhitespace("  "),
+                    comment=cst.Comment("# comment"),
+                ),
+                False,
+                False,
+            ),
+        )
+    )
+    def test_is_blank_and_is_comment_only(
+        self, node: cst.EmptyLine, is_blank: bool, is_comment_only: bool
+    ) -> None:
+        self.assertEqual(node.is_blank, is_blank)
+        self.assertEqual(node.is_comment_only, is_comment_only)
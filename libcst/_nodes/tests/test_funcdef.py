@@ -4,7 +4,7 @@
 # LICENSE file in the root directory of this source tree.
 
 # pyre-strict
-from typing import Any, Callable
+from typing import Any, Callable, List, Optional, Tuple
 
 import libcst as cst
 from libcst import parse_statement
@@ -666,6 +666,22 @@ class FunctionDefCreationTest(CSTNodeTest):
                 "code": "* third",
                 "expected_position": CodeRange((1, 0), (1, 7)),
             },
+            # Type parameters (PEP 695)
+            {
+                "node": cst.FunctionDef(
+                    cst.Name("foo"),
+                    cst.Parameters(
+                        (cst.Param(cst.Name("bar"), cst.Annotation(cst.Name("T"))),)
+                    ),
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    returns=cst.Annotation(cst.Name("T")),
+                    type_parameters=cst.TypeParameters(
+                        (cst.TypeParam(cst.TypeVar(cst.Name("T"))),)
+                    ),
+                ),
+                "code": "def foo[T](bar: T) -> T: pass\n",
+                "expected_position": CodeRange((1, 0), (1, 29)),
+            },
         )
     )
     def test_valid(self, **kwargs: Any) -> None:
@@ -815,21 +831,14 @@ class FunctionDefCreationTest(CSTNodeTest):
                 ),
                 r"Expecting a star prefix of '\*\*'",
             ),
-            # Validate decorator name semantics
             (
                 lambda: cst.FunctionDef(
                     cst.Name("foo"),
                     cst.Parameters(),
                     cst.SimpleStatementSuite((cst.Pass(),)),
-                    (
-                        cst.Decorator(
-                            cst.Name(
-                                "bar", lpar=(cst.LeftParen(),), rpar=(cst.RightParen(),)
-                            )
-                        ),
-                    ),
+                    whitespace_after_type_parameters=cst.SimpleWhitespace(" "),
                 ),
-                "Cannot have parens around decorator in a Decorator",
+                "whitespace_after_type_parameters must be empty",
             ),
         )
     )
@@ -848,6 +857,24 @@ def _parse_statement_force_38(code: str) -> cst.BaseCompoundStatement:
     return statement
 
 
+def _parse_statement_force_39(code: str) -> cst.BaseCompoundStatement:
+    statement = cst.parse_statement(
+        code, config=cst.PartialParserConfig(python_version="3.9")
+    )
+    if not isinstance(statement, cst.BaseCompoundStatement):
+        raise Exception("This function is expecting to parse compound statements only!")
+    return statement
+
+
+def _parse_statement_force_312(code: str) -> cst.BaseCompoundStatement:
+    statement = cst.parse_statement(
+        code, config=cst.PartialParserConfig(python_version="3.12")
+    )
+    if not isinstance(statement, cst.BaseCompoundStatement):
+        raise Exception("This function is expecting to parse compound statements only!")
+    return statement
+
+
 class FunctionDefParserTest(CSTNodeTest):
     @data_provider(
         (
@@ -1979,3 +2006,225 @@ class FunctionDefParserTest(CSTNodeTest):
     )
     def test_valid_38(self, node: cst.CSTNode, code: str) -> None:
         self.validate_node(node, code, _parse_statement_force_38)
+
+    @data_provider(
+        (
+            # Decorator with a subscript (PEP 614)
+            {
+                "node": cst.FunctionDef(
+                    cst.Name("foo"),
+                    cst.Parameters(),
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    decorators=(
+                        cst.Decorator(
+                            cst.Subscript(
+                                cst.Name("buttons"),
+                                (cst.SubscriptElement(cst.Index(cst.Integer("0"))),),
+                            ),
+                        ),
+                    ),
+                ),
+                "code": "@buttons[0]\ndef foo(): pass\n",
+            },
+            # Decorator with a call on a subscript (PEP 614)
+            {
+                "node": cst.FunctionDef(
+                    cst.Name("foo"),
+                    cst.Parameters(),
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    decorators=(
+                        cst.Decorator(
+                            cst.Call(
+                                cst.Subscript(
+                                    cst.Name("handlers"),
+                                    (cst.SubscriptElement(cst.Index(cst.Name("key"))),),
+                                ),
+                                (cst.Arg(cst.Name("x")),),
+                            ),
+                        ),
+                    ),
+                ),
+                "code": "@handlers[key](x)\ndef foo(): pass\n",
+            },
+            # Parenthesized decorator with a boolean operation (PEP 614)
+            {
+                "node": cst.FunctionDef(
+                    cst.Name("foo"),
+                    cst.Parameters(),
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    decorators=(
+                        cst.Decorator(
+                            cst.BooleanOperation(
+                                cst.Name("a"),
+                                cst.Or(),
+                                cst.Name("b"),
+                                lpar=(cst.LeftParen(),),
+                                rpar=(cst.RightParen(),),
+                            ),
+                            whitespace_after_at=cst.SimpleWhitespace(" "),
+                        ),
+                    ),
+                ),
+                "code": "@ (a or b)\ndef foo(): pass\n",
+            },
+            # Decorator with a named expression (PEP 614)
+            {
+                "node": cst.FunctionDef(
+                    cst.Name("foo"),
+                    cst.Parameters(),
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    decorators=(
+                        cst.Decorator(
+                            cst.NamedExpr(cst.Name("x"), cst.Name("y")),
+                        ),
+                    ),
+                ),
+                "code": "@x := y\ndef foo(): pass\n",
+            },
+        )
+    )
+    def test_valid_39(self, node: cst.CSTNode, code: str) -> None:
+        self.validate_node(node, code, _parse_statement_force_39)
+
+    @data_provider(
+        (
+            {"code": "@buttons[0]\ndef foo(): pass\n"},
+            {"code": "@(foo)\ndef foo(): pass\n"},
+            {"code": "@a or b\ndef foo(): pass\n"},
+        )
+    )
+    def test_decorator_expressions_invalid_38(self, code: str) -> None:
+        with self.assertRaises(cst.ParserSyntaxError):
+            _parse_statement_force_38(code)
+
+    @data_provider(
+        (
+            # Type parameters
+            {
+                "node": cst.FunctionDef(
+                    cst.Name("foo"),
+                    cst.Parameters((cst.Param(cst.Name("bar"), star=""),)),
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    type_parameters=cst.TypeParameters(
+                        (
+                            cst.TypeParam(
+                                cst.TypeVar(cst.Name("T")),
+                                comma=cst.Comma(
+                                    whitespace_after=cst.SimpleWhitespace(" ")
+                                ),
+                            ),
+                            cst.TypeParam(cst.TypeVarTuple(cst.Name("Ts"))),
+                        )
+                    ),
+                ),
+                "code": "def foo[T, *Ts](bar): pass\n",
+            },
+            # Whitespace around type parameters
+            {
+                "node": cst.FunctionDef(
+                    cst.Name("foo"),
+                    cst.Parameters(),
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    whitespace_after_name=cst.SimpleWhitespace(" "),
+                    type_parameters=cst.TypeParameters(
+                        (cst.TypeParam(cst.ParamSpec(cst.Name("P"))),)
+                    ),
+                    whitespace_after_type_parameters=cst.SimpleWhitespace(" "),
+                ),
+                "code": "def foo [**P] (): pass\n",
+            },
+        )
+    )
+    def test_valid_312(self, node: cst.CSTNode, code: str) -> None:
+        self.validate_node(node, code, _parse_statement_force_312)
+
+    def test_type_parameters_invalid_311(self) -> None:
+        with self.assertRaises(cst.ParserSyntaxError):
+            parse_statement(
+                "def foo[T](): pass\n",
+                config=cst.PartialParserConfig(python_version="3.11"),
+            )
+
+
+class ParametersHelperTest(CSTNodeTest):
+    @data_provider(
+        (
+            ("def foo(): pass\n", []),
+            ("def foo(a, b=1): pass\n", ["a", "b"]),
+            (
+                "def foo(a, /, b, *args, c, d=1, **kwargs): pass\n",
+                ["a", "b", "args", "c", "d", "kwargs"],
+            ),
+            ("def foo(a, *, b): pass\n", ["a", "b"]),
+            ("def foo(**kwargs): pass\n", ["kwargs"]),
+        )
+    )
+    def test_param_names(self, code: str, names: List[str]) -> None:
+        funcdef = cst.ensure_type(_parse_statement_force_38(code), cst.FunctionDef)
+        params = funcdef.params
+        self.assertEqual(params.param_names(), names)
+        self.assertEqual(
+            [param.name.value for param in params.iter_all_params()], names
+        )
+
+    @data_provider(
+        (
+            ("a", "posonly_params"),
+            ("b", "params"),
+            ("args", "star_arg"),
+            ("c", "kwonly_params"),
+            ("kwargs", "star_kwarg"),
+            ("d", None),
+            ("*args", None),
+        )
+    )
+    def test_get_param_by_name(self, name: str, field: Optional[str]) -> None:
+        funcdef = cst.ensure_type(
+            _parse_statement_force_38("def foo(a, /, b, *args, c, **kwargs): pass\n"),
+            cst.FunctionDef,
+        )
+        params = funcdef.params
+        param = params.get_param_by_name(name)
+        if field is None:
+            self.assertIsNone(param)
+            self.assertFalse(params.has_param(name))
+        else:
+            expected = getattr(params, field)
+            if not isinstance(expected, cst.Param):
+                expected = expected[0]
+            self.assertIs(param, expected)
+            self.assertTrue(params.has_param(name))
+
+
+class FunctionDefHelperTest(CSTNodeTest):
+    @data_provider(
+        (
+            ("def foo(): pass\n", (False, False, False, False)),
+            ("async def foo(): pass\n", (True, False, False, False)),
+            ("@property\ndef foo(self): pass\n", (False, True, False, False)),
+            ("@foo.setter\ndef foo(self, v): pass\n", (False, True, False, False)),
+            ("@foo.getter\ndef foo(self): pass\n", (False, True, False, False)),
+            ("@foo.deleter\ndef foo(self): pass\n", (False, True, False, False)),
+            ("@classmethod\ndef foo(cls): pass\n", (False, False, True, False)),
+            ("@staticmethod\ndef foo(): pass\n", (False, False, False, True)),
+            (
+                "@decorator\n@classmethod\nasync def foo(cls): pass\n",
+                (True, False, True, False),
+            ),
+            ("@foo.other\ndef foo(self): pass\n", (False, False, False, False)),
+            ("@property()\ndef foo(self): pass\n", (False, False, False, False)),
+        )
+    )
+    def test_predicates(
+        self, code: str, expected: Tuple[bool, bool, bool, bool]
+    ) -> None:
+        funcdef = cst.ensure_type(parse_statement(code), cst.FunctionDef)
+        self.assertEqual(
+            (
+                funcdef.is_async,
+                funcdef.is_property,
+                funcdef.is_classmethod,
+                funcdef.is_staticmethod,
+            ),
+            expected,
+        )
@@ -0,0 +1,466 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+
+# pyre-strict
+from typing import Any
+
+import libcst as cst
+from libcst import parse_statement
+from libcst._nodes.tests.base import CSTNodeTest
+from libcst.helpers import ensure_type
+from libcst.metadata import CodeRange
+from libcst.testing.utils import data_provider
+
+
+def _parse_statement_force_310(code: str) -> cst.BaseCompoundStatement:
+    statement = cst.parse_statement(
+        code, config=cst.PartialParserConfig(python_version="3.10")
+    )
+    if not isinstance(statement, cst.BaseCompoundStatement):
+        raise Exception("This function is expecting to parse compound statements only!")
+    return statement
+
+
+class MatchTest(CSTNodeTest):
+    @data_provider(
+        (
+            # Values and singletons
+            {
+                "node": cst.Match(
+                    subject=cst.Name("x"),
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchSingleton(cst.Name("None")),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                        cst.MatchCase(
+                            pattern=cst.MatchValue(cst.SimpleString('"foo"')),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                    ],
+                ),
+                "code": 'match x:\n    case None: pass\n    case "foo": pass\n',
+                "parser": _parse_statement_force_310,
+                "expected_position": CodeRange((1, 0), (3, 20)),
+            },
+            # Parenthesized value
+            {
+                "node": cst.Match(
+                    subject=cst.Name(
+                        "x", lpar=[cst.LeftParen()], rpar=[cst.RightParen()]
+                    ),
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchValue(
+                                cst.Name(
+                                    "y",
+                                    lpar=[
+                                        cst.LeftParen(
+                                            whitespace_after=cst.SimpleWhitespace(" ")
+                                        )
+                                    ],
+                                    rpar=[cst.RightParen()],
+                                )
+                            ),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        )
+                    ],
+                    whitespace_after_match=cst.SimpleWhitespace(""),
+                ),
+                "code": "match(x):\n    case ( y): pass\n",
+                "parser": None,
+            },
+            # Negative and complex numbers
+            {
+                "node": cst.Match(
+                    subject=cst.Name("x"),
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchValue(
+                                cst.BinaryOperation(
+                                    left=cst.UnaryOperation(
+                                        cst.Minus(), cst.Integer("1")
+                                    ),
+                                    operator=cst.Add(),
+                                    right=cst.Imaginary("2j"),
+                                )
+                            ),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        )
+                    ],
+                ),
+                "code": "match x:\n    case -1 + 2j: pass\n",
+                "parser": _parse_statement_force_310,
+            },
+            # Capture, wildcard, and as patterns with a guard
+            {
+                "node": cst.Match(
+                    subject=cst.Name("x"),
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchAs(name=cst.Name("y")),
+                            guard=cst.Name("y"),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                        cst.MatchCase(
+                            pattern=cst.MatchAs(
+                                pattern=cst.MatchAs(),
+                                name=cst.Name("z"),
+                                whitespace_before_as=cst.SimpleWhitespace(" "),
+                                whitespace_after_as=cst.SimpleWhitespace(" "),
+                            ),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                    ],
+                ),
+                "code": "match x:\n    case y if y: pass\n    case _ as z: pass\n",
+                "parser": _parse_statement_force_310,
+            },
+            # Or patterns
+            {
+                "node": cst.Match(
+                    subject=cst.Name("x"),
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchOr(
+                                [
+                                    cst.MatchOrElement(
+                                        cst.MatchValue(cst.Integer("1")),
+                                        separator=cst.BitOr(),
+                                    ),
+                                    cst.MatchOrElement(
+                                        cst.MatchValue(
+                                            cst.Attribute(cst.Name("a"), cst.Name("b"))
+                                        )
+                                    ),
+                                ]
+                            ),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        )
+                    ],
+                ),
+                "code": "match x:\n    case 1 | a.b: pass\n",
+                "parser": _parse_statement_force_310,
+            },
+            # Sequence patterns
+            {
+                "node": cst.Match(
+                    subject=cst.Tuple(
+                        [
+                            cst.Element(
+                                cst.Name("a"),
+                                comma=cst.Comma(
+                                    whitespace_after=cst.SimpleWhitespace(" ")
+                                ),
+                            ),
+                            cst.StarredElement(cst.Name("b")),
+                        ],
+                        lpar=[],
+                        rpar=[],
+                    ),
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchList(
+                                [
+                                    cst.MatchSequenceElement(
+                                        cst.MatchAs(name=cst.Name("c")),
+                                        comma=cst.Comma(
+                                            whitespace_after=cst.SimpleWhitespace(" ")
+                                        ),
+                                    ),
+                                    cst.MatchStar(),
+                                ]
+                            ),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                        cst.MatchCase(
+                            pattern=cst.MatchTuple(
+                                [
+                                    cst.MatchSequenceElement(
+                                        cst.MatchAs(name=cst.Name("c")),
+                                        comma=cst.Comma(),
+                                    )
+                                ]
+                            ),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                        cst.MatchCase(
+                            pattern=cst.MatchTuple(
+                                [
+                                    cst.MatchStar(
+                                        cst.Name("c"),
+                                        comma=cst.Comma(
+                                            whitespace_after=cst.SimpleWhitespace(" ")
+                                        ),
+                                    ),
+                                    cst.MatchSequenceElement(
+                                        cst.MatchAs(name=cst.Name("d"))
+                                    ),
+                                ],
+                                lpar=[],
+                                rpar=[],
+                            ),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                        cst.MatchCase(
+                            pattern=cst.MatchTuple([]),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                    ],
+                ),
+                "code": (
+                    "match a, *b:\n"
+                    + "    case [c, *_]: pass\n"
+                    + "    case (c,): pass\n"
+                    + "    case *c, d: pass\n"
+                    + "    case (): pass\n"
+                ),
+                "parser": _parse_statement_force_310,
+            },
+            # Mapping patterns
+            {
+                "node": cst.Match(
+                    subject=cst.Name("x"),
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchMapping(
+                                [
+                                    cst.MatchMappingElement(
+                                        key=cst.SimpleString('"a"'),
+                                        pattern=cst.MatchAs(name=cst.Name("b")),
+                                        comma=cst.Comma(
+                                            whitespace_after=cst.SimpleWhitespace(" ")
+                                        ),
+                                    )
+                                ],
+                                rest=cst.Name("rest"),
+                            ),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                        cst.MatchCase(
+                            pattern=cst.MatchMapping(),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                    ],
+                ),
+                "code": (
+                    "match x:\n"
+                    + '    case {"a": b, **rest}: pass\n'
+                    + "    case {}: pass\n"
+                ),
+                "parser": _parse_statement_force_310,
+            },
+            # Class patterns
+            {
+                "node": cst.Match(
+                    subject=cst.Name("x"),
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchClass(
+                                cls=cst.Attribute(cst.Name("a"), cst.Name("Point")),
+                                patterns=[
+                                    cst.MatchSequenceElement(
+                                        cst.MatchValue(cst.Integer("0")),
+                                        comma=cst.Comma(
+                                            whitespace_after=cst.SimpleWhitespace(" ")
+                                        ),
+                                    )
+                                ],
+                                kwds=[
+                                    cst.MatchKeywordElement(
+                                        key=cst.Name("y"),
+                                        pattern=cst.MatchAs(name=cst.Name("y")),
+                                    )
+                                ],
+                            ),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                        cst.MatchCase(
+                            pattern=cst.MatchClass(cls=cst.Name("Point")),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        ),
+                    ],
+                ),
+                "code": (
+                    "match x:\n"
+                    + "    case a.Point(0, y=y): pass\n"
+                    + "    case Point(): pass\n"
+                ),
+                "parser": _parse_statement_force_310,
+            },
+            # Indented cases with comments and a footer
+            {
+                "node": cst.Match(
+                    subject=cst.Name("x"),
+                    whitespace_after_colon=cst.TrailingWhitespace(
+                        comment=cst.Comment("# match"),
+                        whitespace=cst.SimpleWhitespace("  "),
+                    ),
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchValue(cst.Integer("1")),
+                            body=cst.IndentedBlock(
+                                (cst.SimpleStatementLine((cst.Pass(),)),)
+                            ),
+                            leading_lines=[
+                                cst.EmptyLine(comment=cst.Comment("# first case"))
+                            ],
+                        )
+                    ],
+                    footer=[cst.EmptyLine(comment=cst.Comment("# footer"))],
+                ),
+                "code": (
+                    "match x:  # match\n"
+                    + "    # first case\n"
+                    + "    case 1:\n"
+                    + "        pass\n"
+                    + "    # footer\n"
+                ),
+                "parser": _parse_statement_force_310,
+                "expected_position": CodeRange((1, 0), (4, 12)),
+            },
+            # Custom indent
+            {
+                "node": cst.Match(
+                    subject=cst.Name("x"),
+                    indent="\t",
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchValue(cst.Integer("1")),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        )
+                    ],
+                ),
+                "code": "match x:\n\tcase 1: pass\n",
+                "parser": None,
+            },
+        )
+    )
+    def test_valid(self, **kwargs: Any) -> None:
+        self.validate_node(**kwargs)
+
+    @data_provider(
+        (
+            {
+                "get_node": lambda: cst.Match(subject=cst.Name("x"), cases=()),
+                "expected_re": "at least one case",
+            },
+            {
+                "get_node": lambda: cst.Match(
+                    subject=cst.Name("x"),
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchAs(),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        )
+                    ],
+                    whitespace_after_match=cst.SimpleWhitespace(""),
+                ),
+                "expected_re": "at least one space after 'match'",
+            },
+            {
+                "get_node": lambda: cst.MatchCase(
+                    pattern=cst.MatchAs(),
+                    guard=cst.Name("x"),
+                    whitespace_after_if=cst.SimpleWhitespace(""),
+                    body=cst.SimpleStatementSuite((cst.Pass(),)),
+                ),
+                "expected_re": "at least one space after 'if'",
+            },
+            {
+                "get_node": lambda: cst.Match(
+                    subject=cst.Name("x"),
+                    indent="",
+                    cases=[
+                        cst.MatchCase(
+                            pattern=cst.MatchAs(),
+                            body=cst.SimpleStatementSuite((cst.Pass(),)),
+                        )
+                    ],
+                ),
+                "expected_re": "non-zero width indent",
+            },
+            {
+                "get_node": lambda: cst.MatchSingleton(cst.Name("x")),
+                "expected_re": "True, False or None",
+            },
+            {
+                "get_node": lambda: cst.MatchTuple([], lpar=[], rpar=[]),
+                "expected_re": "must be wrapped in parentheses",
+            },
+            {
+                "get_node": lambda: cst.MatchAs(pattern=cst.MatchAs()),
+                "expected_re": "Pattern must be None if name is None",
+            },
+            {
+                "get_node": lambda: cst.MatchOr(
+                    [cst.MatchOrElement(cst.MatchValue(cst.Integer("1")))]
+                ),
+                "expected_re": "at least two patterns",
+            },
+            {
+                "get_node": lambda: cst.MatchMapping(trailing_comma=cst.Comma()),
+                "expected_re": "trailing comma without a rest name",
+            },
+        )
+    )
+    def test_invalid(self, **kwargs: Any) -> None:
+        self.assert_invalid(**kwargs)
+
+    @data_provider(
+        (
+            # A match statement needs at least one case block
+            {"code": "match x:\n    pass\n"},
+            # Star patterns are only valid in sequences
+            {"code": "match x:\n    case *a: pass\n"},
+            {"code": "match x:\n    case (*a): pass\n"},
+            {"code": "match *x:\n    case _: pass\n"},
+            {"code": "match x:\n    case a as _: pass\n"},
+            # Only literals are allowed in literal patterns
+            {"code": "match x:\n    case f'{x}': pass\n"},
+            {"code": "match x:\n    case 1 + 2: pass\n"},
+            {"code": "match x:\n    case 1j + 2j: pass\n"},
+            {"code": "match x:\n    case {a: 1}: pass\n"},
+            {"code": "match x:\n    case {**rest, 'a': 1}: pass\n"},
+            {"code": "match x:\n    case P(a=1, b): pass\n"},
+            {"code": "match x:\n    case P(a.b=1): pass\n"},
+        )
+    )
+    def test_parser_errors(self, code: str) -> None:
+        with self.assertRaises(cst.ParserSyntaxError):
+            _parse_statement_force_310(code)
+
+    @data_provider(
+        (
+            {"code": "match = 1\n"},
+            {"code": "match.foo(case)\n"},
+            {"code": "match(x)\n"},
+            {"code": "case = match[x:y]\n"},
+        )
+    )
+    def test_soft_keywords_as_names(self, code: str) -> None:
+        module = cst.parse_module(
+            code, config=cst.PartialParserConfig(python_version="3.10")
+        )
+        self.assertEqual(module.code, code)
+        self.assertIsInstance(module.body[0], cst.SimpleStatementLine)
+
+    @data_provider(({"version": "3.10"}, {"version": "3.11"}))
+    def test_case_as_name_after_semicolon(self, version: str) -> None:
+        code = "match x:\n    case 1: pass; case = 2\n"
+        module = cst.parse_module(
+            code, config=cst.PartialParserConfig(python_version=version)
+        )
+        self.assertEqual(module.code, code)
+        case = ensure_type(module.body[0], cst.Match).cases[0]
+        statement = ensure_type(case.body, cst.SimpleStatementSuite).body[1]
+        self.assertIsInstance(statement, cst.Assign)
+
+    def test_match_invalid_39(self) -> None:
+        with self.assertRaises(cst.ParserSyntaxError):
+            parse_statement(
+                "match x:\n    case _: pass\n",
+                config=cst.PartialParserConfig(python_version="3.9"),
+            )
@@ -4,7 +4,7 @@
 # LICENSE file in the root directory of this source tree.
 
 # pyre-strict
-from typing import Tuple, cast
+from typing import Optional, Tuple, Union, cast
 
 import libcst as cst
 from libcst import parse_module, parse_statement
@@ -206,3 +206,92 @@ class ModuleTest(CSTNodeTest):
                 ),
             ),
         )
+
+    @data_provider(
+        (
+            ('"""docstring"""', "docstring"),
+            (
+                '""" multiline docstring\n\n    indented\n"""',
+                "multiline docstring\n\nindented",
+            ),
+            ('"docstring" "concatenated"', "docstringconcatenated"),
+            ('"""docstring"""; pass', "docstring"),
+            ("# comment\n'docstring'", "docstring"),
+            ('b"bytes"', None),
+            ('f"formatted"', None),
+            ('"concatenated" f"formatted"', None),
+            ('pass\n"docstring"', None),
+            ("x = 1", None),
+            ("", None),
+        )
+    )
+    def test_get_docstring(self, code: str, docstring: Optional[str]) -> None:
+        self.assertEqual(parse_module(code).get_docstring(), docstring)
+
+    def test_get_docstring_unclean(self) -> None:
+        self.assertEqual(
+            parse_module('"""\n    indented\n    docstring\n"""').get_docstring(
+                clean=False
+            ),
+            "\n    indented\n    docstring\n",
+        )
+
+    @data_provider(
+        (
+            ("def f():\n    'docstring'\n    pass", "docstring"),
+            ("def f(): 'docstring'", "docstring"),
+            ("def f():\n    pass\n    'docstring'", None),
+            ('class C:\n    """\n    docstring\n    """\n    pass', "docstring"),
+            ("class C: 'docstring'", "docstring"),
+            ("class C:\n    x = 'docstring'", None),
+        )
+    )
+    def test_get_docstring_compound_statement(
+        self, code: str, docstring: Optional[str]
+    ) -> None:
+        node = parse_statement(code)
+        self.assertIsInstance(node, (cst.FunctionDef, cst.ClassDef))
+        self.assertEqual(
+            cast(Union[cst.FunctionDef, cst.ClassDef], node).get_docstring(), docstring
+        )
+
+    @data_provider(
+        {
+            "docstring": {
+                "code": 'def f():\n    """Doc.\n\n    More.\n    """\n    pass\n',
+                "start": (2, 4),
+                "end": (5, 7),
+            },
+            "escaped_quotes": {
+                "code": "x = '''a\\'''\nb\\''''\n",
+                "start": (1, 4),
+                "end": (2, 6),
+            },
+            "raw": {
+                "code": 'x = rb"""\\d\n\\"x"""\n',
+                "start": (1, 4),
+                "end": (2, 6),
+            },
+            "fstring": {
+                "code": 'x = f"""{a}\n{b!r:>{width}}\n"""\n',
+                "start": (1, 4),
+                "end": (3, 3),
+            },
+            "concatenated": {
+                "code": 'x = ("""a\n"""\n     """b\n""")\n',
+                "start": (1, 5),
+                "end": (4, 3),
+            },
+        }
+    )
+    def test_multiline_string(
+        self, *, code: str, start: Tuple[int, int], end: Tuple[int, int]
+    ) -> None:
+        module = parse_module(code)
+        self.assertEqual(module.code, code)
+        wrapper = MetadataWrapper(module)
+        positions = wrapper.resolve(PositionProvider)
+        strings = [node for node in positions if isinstance(node, cst.BaseString)]
+        # The outermost string spans all of the others.
+        string = max(strings, key=lambda node: len(module.code_for_node(node)))
+        self.cmp_position(positions[string], start, end)
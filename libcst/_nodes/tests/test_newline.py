@@ -4,7 +4,7 @@
 # LICENSE file in the root directory of this source tree.
 
 # pyre-strict
-from typing import Callable
+from typing import Callable, Tuple
 
 import libcst as cst
 from libcst._nodes.tests.base import CSTNodeTest
@@ -33,3 +33,21 @@ class NewlineTest(CSTNodeTest):
         self, get_node: Callable[[], cst.CSTNode], expected_re: str
     ) -> None:
         self.assert_invalid(get_node, expected_re)
+
+    @data_provider(
+        (
+            (cst.Newline("\r\n"), "\n", "\r\n", (True, False, False)),
+            (cst.Newline("\n"), "\r\n", "\n", (False, True, False)),
+            (cst.Newline("\r"), "\n", "\r", (False, False, True)),
+            (cst.Newline(), "\r\n", "\r\n", (False, False, False)),
+        )
+    )
+    def test_resolve(
+        self,
+        node: cst.Newline,
+        default: str,
+        expected: str,
+        kinds: Tuple[bool, bool, bool],
+    ) -> None:
+        self.assertEqual(node.resolve(default), expected)
+        self.assertEqual((node.is_crlf, node.is_lf, node.is_cr), kinds)
@@ -102,3 +102,23 @@ class ParenthesizedWhitespaceTest(CSTNodeTest):
     )
     def test_valid(self, node: cst.CSTNode, code: str) -> None:
         self.validate_node(node, code)
+
+    @data_provider(
+        (
+            ("", 8, 0, False),
+            ("    ", 8, 4, False),
+            ("\t", 8, 8, True),
+            ("\t", 4, 4, True),
+            ("  \t", 4, 4, True),
+            ("    \t  ", 4, 10, True),
+            ("  \f ", 8, 1, False),
+            ("\t\\\n  ", 8, 2, True),
+            ("  \\\r\n\t", 4, 4, True),
+        )
+    )
+    def test_indent_level(
+        self, value: str, tab_size: int, level: int, contains_tabs: bool
+    ) -> None:
+        node = cst.SimpleWhitespace(value)
+        self.assertEqual(node.indent_level(tab_size), level)
+        self.assertEqual(node.contains_tabs, contains_tabs)
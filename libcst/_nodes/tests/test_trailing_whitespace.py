@@ -4,6 +4,8 @@
 # LICENSE file in the root directory of this source tree.
 
 # pyre-strict
+from typing import Optional
+
 import libcst as cst
 from libcst._nodes.tests.base import CSTNodeTest
 from libcst.testing.utils import data_provider
@@ -28,3 +30,17 @@ class TrailingWhitespaceTest(CSTNodeTest):
     )
     def test_valid(self, node: cst.CSTNode, code: str) -> None:
         self.validate_node(node, code)
+
+    @data_provider(
+        (
+            (cst.TrailingWhitespace(), None),
+            (cst.TrailingWhitespace(whitespace=cst.SimpleWhitespace("  ")), None),
+            (cst.TrailingWhitespace(comment=cst.Comment("#")), ""),
+            (cst.TrailingWhitespace(comment=cst.Comment("#  comment")), "comment"),
+        )
+    )
+    def test_comment_text(
+        self, node: cst.TrailingWhitespace, expected: Optional[str]
+    ) -> None:
+        self.assertEqual(node.has_comment, expected is not None)
+        self.assertEqual(node.comment_text, expected)
@@ -13,6 +13,15 @@ from libcst.metadata import CodeRange
 from libcst.testing.utils import data_provider
 
 
+def _parse_statement_force_311(code: str) -> cst.BaseCompoundStatement:
+    statement = cst.parse_statement(
+        code, config=cst.PartialParserConfig(python_version="3.11")
+    )
+    if not isinstance(statement, cst.BaseCompoundStatement):
+        raise Exception("This function is expecting to parse compound statements only!")
+    return statement
+
+
 class TryTest(CSTNodeTest):
     @data_provider(
         (
@@ -335,3 +344,128 @@ class TryTest(CSTNodeTest):
     )
     def test_invalid(self, **kwargs: Any) -> None:
         self.assert_invalid(**kwargs)
+
+
+class TryStarTest(CSTNodeTest):
+    @data_provider(
+        (
+            # Simple try/except* block
+            {
+                "node": cst.TryStar(
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    handlers=(
+                        cst.ExceptStarHandler(
+                            cst.SimpleStatementSuite((cst.Pass(),)),
+                            type=cst.Name("Exception"),
+                        ),
+                    ),
+                ),
+                "code": "try: pass\nexcept* Exception: pass\n",
+                "parser": _parse_statement_force_311,
+                "expected_position": CodeRange((1, 0), (2, 23)),
+            },
+            # Multiple handlers with names and unusual whitespace
+            {
+                "node": cst.TryStar(
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    handlers=(
+                        cst.ExceptStarHandler(
+                            cst.SimpleStatementSuite((cst.Pass(),)),
+                            type=cst.Name("TypeError"),
+                            name=cst.AsName(cst.Name("e")),
+                            whitespace_after_except=cst.SimpleWhitespace(" "),
+                            whitespace_after_star=cst.SimpleWhitespace(""),
+                        ),
+                        cst.ExceptStarHandler(
+                            cst.SimpleStatementSuite((cst.Pass(),)),
+                            type=cst.Tuple(
+                                (
+                                    cst.Element(
+                                        cst.Name("KeyError"),
+                                        comma=cst.Comma(
+                                            whitespace_after=cst.SimpleWhitespace(" ")
+                                        ),
+                                    ),
+                                    cst.Element(cst.Name("ValueError")),
+                                )
+                            ),
+                            whitespace_before_colon=cst.SimpleWhitespace(" "),
+                        ),
+                    ),
+                ),
+                "code": (
+                    "try: pass\n"
+                    + "except *TypeError as e: pass\n"
+                    + "except* (KeyError, ValueError) : pass\n"
+                ),
+                "parser": _parse_statement_force_311,
+            },
+            # Else and finally clauses
+            {
+                "node": cst.TryStar(
+                    cst.IndentedBlock((cst.SimpleStatementLine((cst.Pass(),)),)),
+                    handlers=(
+                        cst.ExceptStarHandler(
+                            cst.IndentedBlock(
+                                (cst.SimpleStatementLine((cst.Pass(),)),)
+                            ),
+                            type=cst.Name("Exception"),
+                        ),
+                    ),
+                    orelse=cst.Else(
+                        cst.IndentedBlock((cst.SimpleStatementLine((cst.Pass(),)),))
+                    ),
+                    finalbody=cst.Finally(
+                        cst.IndentedBlock((cst.SimpleStatementLine((cst.Pass(),)),))
+                    ),
+                ),
+                "code": "try:\n    pass\nexcept* Exception:\n    pass\n"
+                + "else:\n    pass\nfinally:\n    pass\n",
+                "parser": _parse_statement_force_311,
+                "expected_position": CodeRange((1, 0), (8, 8)),
+            },
+        )
+    )
+    def test_valid(self, **kwargs: Any) -> None:
+        self.validate_node(**kwargs)
+
+    @data_provider(
+        (
+            {
+                "get_node": lambda: cst.ExceptStarHandler(
+                    cst.SimpleStatementSuite((cst.Pass(),)),
+                    type=cst.Name("TypeError"),
+                    name=cst.AsName(cst.Tuple((cst.Element(cst.Name("e")),))),
+                ),
+                "expected_re": "Must use a Name node for AsName name",
+            },
+            {
+                "get_node": lambda: cst.TryStar(
+                    cst.SimpleStatementSuite((cst.Pass(),)), handlers=()
+                ),
+                "expected_re": "at least one ExceptStarHandler",
+            },
+        )
+    )
+    def test_invalid(self, **kwargs: Any) -> None:
+        self.assert_invalid(**kwargs)
+
+    @data_provider(
+        (
+            # Bare except* is not allowed
+            {"code": "try: pass\nexcept*: pass\n"},
+            # Can't mix except and except* in the same statement
+            {"code": "try: pass\nexcept* TypeError: pass\nexcept: pass\n"},
+            {"code": "try: pass\nexcept ValueError: pass\nexcept* TypeError: pass\n"},
+        )
+    )
+    def test_parser_errors(self, code: str) -> None:
+        with self.assertRaises(cst.ParserSyntaxError):
+            _parse_statement_force_311(code)
+
+    def test_except_star_invalid_310(self) -> None:
+        with self.assertRaises(cst.ParserSyntaxError):
+            cst.parse_statement(
+                "try: pass\nexcept* TypeError: pass\n",
+                config=cst.PartialParserConfig(python_version="3.10"),
+            )
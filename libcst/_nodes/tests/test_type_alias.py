@@ -0,0 +1,190 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+
+# pyre-strict
+from typing import Any
+
+import libcst as cst
+import libcst.matchers as m
+from libcst import parse_statement
+from libcst._nodes.tests.base import CSTNodeTest
+from libcst.helpers import ensure_type
+from libcst.metadata import CodeRange
+from libcst.testing.utils import data_provider
+
+
+def _parse_small_statement_force_312(code: str) -> cst.BaseSmallStatement:
+    return ensure_type(
+        parse_statement(code, config=cst.PartialParserConfig(python_version="3.12")),
+        cst.SimpleStatementLine,
+    ).body[0]
+
+
+class TypeAliasConstructionTest(CSTNodeTest):
+    @data_provider(
+        (
+            # Simple alias
+            {
+                "node": cst.TypeAlias(cst.Name("Foo"), cst.Name("Bar")),
+                "code": "type Foo = Bar",
+                "expected_position": CodeRange((1, 0), (1, 14)),
+            },
+            # Alias with every kind of type parameter
+            {
+                "node": cst.TypeAlias(
+                    cst.Name("Foo"),
+                    cst.Subscript(
+                        cst.Name("dict"),
+                        (
+                            cst.SubscriptElement(
+                                cst.Index(cst.Name("K")), comma=cst.Comma()
+                            ),
+                            cst.SubscriptElement(cst.Index(cst.Name("V"))),
+                        ),
+                    ),
+                    type_parameters=cst.TypeParameters(
+                        (
+                            cst.TypeParam(
+                                cst.TypeVar(cst.Name("K"), bound=cst.Name("str"))
+                            ),
+                            cst.TypeParam(cst.TypeVar(cst.Name("V"))),
+                            cst.TypeParam(cst.TypeVarTuple(cst.Name("Ts"))),
+                            cst.TypeParam(cst.ParamSpec(cst.Name("P"))),
+                        )
+                    ),
+                ),
+                "code": "type Foo[K: str, V, *Ts, **P] = dict[K,V]",
+            },
+            # Whitespace rendering test
+            {
+                "node": cst.TypeAlias(
+                    cst.Name("Foo"),
+                    cst.Name("T"),
+                    type_parameters=cst.TypeParameters(
+                        (cst.TypeParam(cst.TypeVar(cst.Name("T"))),)
+                    ),
+                    whitespace_after_type=cst.SimpleWhitespace("  "),
+                    whitespace_after_name=cst.SimpleWhitespace(" "),
+                    whitespace_after_type_parameters=cst.SimpleWhitespace(""),
+                    whitespace_after_equals=cst.SimpleWhitespace("  "),
+                ),
+                "code": "type  Foo [T]=  T",
+            },
+        )
+    )
+    def test_valid(self, **kwargs: Any) -> None:
+        self.validate_node(**kwargs)
+
+    @data_provider(
+        (
+            {
+                "get_node": lambda: cst.TypeAlias(
+                    cst.Name("Foo"),
+                    cst.Name("Bar"),
+                    whitespace_after_type_parameters=cst.SimpleWhitespace(" "),
+                ),
+                "expected_re": "whitespace_after_type_parameters must be empty",
+            },
+            {
+                "get_node": lambda: cst.TypeParameters(()),
+                "expected_re": "at least one parameter",
+            },
+            {
+                "get_node": lambda: cst.TypeVar(cst.Name("T"), colon=cst.Colon()),
+                "expected_re": "colon without a bound",
+            },
+        )
+    )
+    def test_invalid(self, **kwargs: Any) -> None:
+        self.assert_invalid(**kwargs)
+
+
+class TypeAliasParsingTest(CSTNodeTest):
+    @data_provider(
+        (
+            # Simple alias
+            {
+                "node": cst.TypeAlias(
+                    cst.Name("Foo"),
+                    cst.Name("Bar"),
+                    whitespace_after_name=cst.SimpleWhitespace(" "),
+                    whitespace_after_type_parameters=cst.SimpleWhitespace(""),
+                ),
+                "code": "type Foo = Bar",
+            },
+            # Alias with type parameters and unusual whitespace
+            {
+                "node": cst.TypeAlias(
+                    cst.Name("Foo"),
+                    cst.Name("T"),
+                    type_parameters=cst.TypeParameters(
+                        (
+                            cst.TypeParam(
+                                cst.TypeVar(
+                                    cst.Name("T"),
+                                    bound=cst.Name("int"),
+                                    colon=cst.Colon(
+                                        whitespace_after=cst.SimpleWhitespace(" ")
+                                    ),
+                                ),
+                                comma=cst.Comma(
+                                    whitespace_after=cst.SimpleWhitespace(" ")
+                                ),
+                            ),
+                            cst.TypeParam(
+                                cst.TypeVarTuple(
+                                    cst.Name("Ts"),
+                                    whitespace_after_star=cst.SimpleWhitespace(" "),
+                                ),
+                                comma=cst.Comma(),
+                            ),
+                            cst.TypeParam(cst.ParamSpec(cst.Name("P"))),
+                        ),
+                        lbracket=cst.LeftSquareBracket(
+                            whitespace_after=cst.SimpleWhitespace(" ")
+                        ),
+                    ),
+                    whitespace_after_type=cst.SimpleWhitespace("  "),
+                    whitespace_after_name=cst.SimpleWhitespace(""),
+                    whitespace_after_type_parameters=cst.SimpleWhitespace(" "),
+                ),
+                "code": "type  Foo[ T: int, * Ts,**P] = T",
+            },
+        )
+    )
+    def test_valid(self, **kwargs: Any) -> None:
+        self.validate_node(parser=_parse_small_statement_force_312, **kwargs)
+
+    @data_provider(
+        (
+            {"code": "type = 1", "expected": cst.Assign},
+            {"code": "type(x)", "expected": cst.Expr},
+            {"code": "type if x else y", "expected": cst.Expr},
+        )
+    )
+    def test_soft_keyword_as_name(self, code: str, expected: type) -> None:
+        self.assertIsInstance(_parse_small_statement_force_312(code), expected)
+
+    @data_provider(
+        (
+            {"code": "if x: type X = int\n"},
+            {"code": "class A: type X = int\n"},
+            {"code": "x = 1; type X = int\n"},
+            {"code": "match = 1; type X = int\n"},
+        )
+    )
+    def test_type_alias_not_at_line_start(self, code: str) -> None:
+        module = cst.parse_module(
+            code, config=cst.PartialParserConfig(python_version="3.12")
+        )
+        self.assertEqual(module.code, code)
+        self.assertEqual(len(m.findall(module, m.TypeAlias())), 1)
+
+    def test_type_alias_invalid_311(self) -> None:
+        with self.assertRaises(cst.ParserSyntaxError):
+            parse_statement(
+                "type Foo = Bar",
+                config=cst.PartialParserConfig(python_version="3.11"),
+            )
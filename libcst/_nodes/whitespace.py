@@ -103,6 +103,32 @@ class SimpleWhitespace(BaseParenthesizableWhitespace, BaseValueToken):
 
         return len(self.value) == 0
 
+    @property
+    def contains_tabs(self) -> bool:
+        """
+        Indicates that this node contains at least one tab (``\\t``) character.
+        """
+
+        return "\t" in self.value
+
+    def indent_level(self, tab_size: int = 8) -> int:
+        """
+        Returns the width of this whitespace in columns, where a tab advances to the
+        next multiple of ``tab_size`` like it does in Python's tokenizer, and a form
+        feed (``\\f``) resets the width. Only the whitespace after the last line
+        continuation is counted, since that is the part that ends up on a line.
+        """
+
+        level = 0
+        for char in NEWLINE_RE.split(self.value)[-1]:
+            if char == "\t":
+                level = (level // tab_size + 1) * tab_size
+            elif char == "\f":
+                level = 0
+            else:
+                level += 1
+        return level
+
 
 @add_slots
 @dataclass(frozen=True)
@@ -137,6 +163,45 @@ class Newline(BaseLeaf):
         value = self.value
         state.add_token(state.default_newline if value is None else value)
 
+    def resolve(self, default: str) -> str:
+        """
+        Returns the newline sequence that this node generates, given the ``default``
+        newline of the module it's in (see :attr:`Module.default_newline`).
+        """
+
+        value = self.value
+        return default if value is None else value
+
+    @property
+    def is_crlf(self) -> bool:
+        """
+        Indicates that this node's value is ``\\r\\n``. This is ``False`` for a
+        newline without a value, which uses the module's default newline, so use
+        :meth:`resolve` to check those.
+        """
+
+        return self.value == "\r\n"
+
+    @property
+    def is_lf(self) -> bool:
+        """
+        Indicates that this node's value is ``\\n``. This is ``False`` for a newline
+        without a value, which uses the module's default newline, so use
+        :meth:`resolve` to check those.
+        """
+
+        return self.value == "\n"
+
+    @property
+    def is_cr(self) -> bool:
+        """
+        Indicates that this node's value is ``\\r``. This is ``False`` for a newline
+        without a value, which uses the module's default newline, so use
+        :meth:`resolve` to check those.
+        """
+
+        return self.value == "\r"
+
 
 @add_slots
 @dataclass(frozen=True)
@@ -164,6 +229,16 @@ class Comment(BaseValueToken):
                 f"Got non-comment value for comment node: {repr(self.value)}"
             )
 
+    @property
+    def text(self) -> str:
+        """
+        The text of the comment, without the leading pound (``#``) character and any
+        whitespace that follows it. For example, this is ``"comment text"`` for
+        ``#  comment text``.
+        """
+
+        return self.value[1:].lstrip()
+
 
 @add_slots
 @dataclass(frozen=True)
@@ -196,6 +271,24 @@ class TrailingWhitespace(CSTNode):
             comment._codegen(state)
         self.newline._codegen(state)
 
+    @property
+    def has_comment(self) -> bool:
+        """
+        Indicates that this line ends with a comment.
+        """
+
+        return self.comment is not None
+
+    @property
+    def comment_text(self) -> Optional[str]:
+        """
+        The :attr:`~Comment.text` of the comment at the end of this line, or ``None``
+        if there isn't one.
+        """
+
+        comment = self.comment
+        return comment.text if comment is not None else None
+
 
 @add_slots
 @dataclass(frozen=True)
@@ -237,6 +330,24 @@ class EmptyLine(CSTNode):
             comment._codegen(state)
         self.newline._codegen(state)
 
+    @property
+    def is_blank(self) -> bool:
+        """
+        Indicates that this line is blank, meaning it doesn't have a comment or any
+        whitespace other than the indentation.
+        """
+
+        return self.comment is None and self.whitespace.empty
+
+    @property
+    def is_comment_only(self) -> bool:
+        """
+        Indicates that this line only contains a comment, without any whitespace
+        between the indentation and the comment.
+        """
+
+        return self.comment is not None and self.whitespace.empty
+
 
 @add_slots
 @dataclass(frozen=True)
@@ -1172,6 +1172,11 @@ def _convert_testlist_comp(
 
 
 @with_production("testlist_star_expr", "(test|star_expr) (',' (test|star_expr))* [',']")
+@with_production(
+    "subject_expr",
+    "(namedexpr_test|star_expr) (',' (namedexpr_test|star_expr))* [',']",
+    version=">=3.10",
+)
 @with_production("testlist", "test (',' test)* [',']")
 @with_production("exprlist", "(expr|star_expr) (',' (expr|star_expr))* [',']")
 def convert_test_or_expr_list(
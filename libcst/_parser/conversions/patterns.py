@@ -0,0 +1,508 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+
+from typing import Any, List, Optional, Sequence, Union
+
+from libcst._exceptions import PartialParserSyntaxError
+from libcst._maybe_sentinel import MaybeSentinel
+from libcst._nodes.expression import (
+    Attribute,
+    BaseExpression,
+    BinaryOperation,
+    ConcatenatedString,
+    FormattedString,
+    Imaginary,
+    LeftCurlyBrace,
+    LeftParen,
+    LeftSquareBracket,
+    Name,
+    RightCurlyBrace,
+    RightParen,
+    RightSquareBracket,
+    UnaryOperation,
+)
+from libcst._nodes.op import Add, BitOr, Comma, Dot, Minus, Subtract
+from libcst._nodes.statement import (
+    MatchAs,
+    MatchClass,
+    MatchKeywordElement,
+    MatchList,
+    MatchMapping,
+    MatchMappingElement,
+    MatchOr,
+    MatchOrElement,
+    MatchPattern,
+    MatchSequenceElement,
+    MatchSingleton,
+    MatchStar,
+    MatchTuple,
+    MatchValue,
+)
+from libcst._nodes.whitespace import SimpleWhitespace
+from libcst._parser.conversions.expression import convert_atom_basic
+from libcst._parser.custom_itertools import grouper
+from libcst._parser.production_decorator import with_production
+from libcst._parser.types.config import ParserConfig
+from libcst._parser.types.token import Token
+from libcst._parser.whitespace_parser import parse_parenthesizable_whitespace
+
+
+# Patterns don't own the whitespace before them, so unlike expressions, these
+# conversions return bare nodes instead of `WithLeadingWhitespace` partials.
+
+
+def _convert_comma(config: ParserConfig, comma_tok: Token, is_trailing: bool) -> Comma:
+    return Comma(
+        whitespace_before=parse_parenthesizable_whitespace(
+            config, comma_tok.whitespace_before
+        ),
+        # If we're a trailing comma, the whitespace after us should be consumed by the
+        # closing bracket, parenthesis, or colon.
+        whitespace_after=(
+            SimpleWhitespace("")
+            if is_trailing
+            else parse_parenthesizable_whitespace(config, comma_tok.whitespace_after)
+        ),
+    )
+
+
+def _add_parens(pattern: MatchPattern, lpar: LeftParen, rpar: RightParen) -> Any:
+    if isinstance(pattern, (MatchValue, MatchSingleton)):
+        # Parentheses around a value pattern are owned by the value itself.
+        value = pattern.value
+        return pattern.with_changes(
+            value=value.with_changes(lpar=(lpar, *value.lpar), rpar=(*value.rpar, rpar))
+        )
+    return pattern.with_changes(lpar=(lpar, *pattern.lpar), rpar=(*pattern.rpar, rpar))
+
+
+def _is_capture(pattern: MatchPattern) -> bool:
+    return (
+        isinstance(pattern, MatchAs)
+        and pattern.pattern is None
+        and pattern.name is not None
+        and not pattern.lpar
+    )
+
+
+@with_production(
+    "patterns", "maybe_star_pattern (',' maybe_star_pattern)* [',']", version=">=3.10"
+)
+def convert_patterns(config: ParserConfig, children: Sequence[Any]) -> Any:
+    if len(children) == 1:
+        # A lone pattern is not a sequence. A lone star pattern is only valid inside
+        # of square brackets, so we leave it up to our parent to reject it.
+        return children[0]
+    elements: List[Union[MatchSequenceElement, MatchStar]] = []
+    for pattern, comma_tok in grouper(children, 2):
+        if comma_tok is None:
+            comma = MaybeSentinel.DEFAULT
+        else:
+            comma = _convert_comma(config, comma_tok, comma_tok is children[-1])
+        if isinstance(pattern, MatchStar):
+            elements.append(pattern.with_changes(comma=comma))
+        else:
+            elements.append(MatchSequenceElement(value=pattern, comma=comma))
+    # lpar/rpar are the responsibility of our parent
+    return MatchTuple(patterns=elements, lpar=(), rpar=())
+
+
+@with_production("maybe_star_pattern", "star_pattern | pattern", version=">=3.10")
+def convert_maybe_star_pattern(config: ParserConfig, children: Sequence[Any]) -> Any:
+    (child,) = children
+    return child
+
+
+@with_production("star_pattern", "'*' NAME", version=">=3.10")
+def convert_star_pattern(config: ParserConfig, children: Sequence[Any]) -> Any:
+    star_tok, name_tok = children
+    return MatchStar(
+        whitespace_before_name=parse_parenthesizable_whitespace(
+            config, star_tok.whitespace_after
+        ),
+        name=None if name_tok.string == "_" else Name(name_tok.string),
+    )
+
+
+@with_production("pattern", "or_pattern ['as' NAME]", version=">=3.10")
+def convert_pattern(config: ParserConfig, children: Sequence[Any]) -> Any:
+    if len(children) == 1:
+        (child,) = children
+        return child
+
+    pattern, as_tok, name_tok = children
+    if name_tok.string == "_":
+        raise PartialParserSyntaxError("cannot use '_' as a target")
+    return MatchAs(
+        pattern=pattern,
+        whitespace_before_as=parse_parenthesizable_whitespace(
+            config, as_tok.whitespace_before
+        ),
+        whitespace_after_as=parse_parenthesizable_whitespace(
+            config, as_tok.whitespace_after
+        ),
+        name=Name(name_tok.string),
+    )
+
+
+@with_production("or_pattern", "closed_pattern ('|' closed_pattern)*", version=">=3.10")
+def convert_or_pattern(config: ParserConfig, children: Sequence[Any]) -> Any:
+    if len(children) == 1:
+        (child,) = children
+        return child
+
+    elements: List[MatchOrElement] = []
+    for pattern, bar_tok in grouper(children, 2):
+        elements.append(
+            MatchOrElement(
+                pattern=pattern,
+                separator=(
+                    MaybeSentinel.DEFAULT
+                    if bar_tok is None
+                    else BitOr(
+                        whitespace_before=parse_parenthesizable_whitespace(
+                            config, bar_tok.whitespace_before
+                        ),
+                        whitespace_after=parse_parenthesizable_whitespace(
+                            config, bar_tok.whitespace_after
+                        ),
+                    )
+                ),
+            )
+        )
+    return MatchOr(patterns=elements)
+
+
+@with_production(
+    "closed_pattern",
+    (
+        "literal_pattern | name_or_class_pattern | group_or_tuple_pattern"
+        + " | list_pattern | mapping_pattern"
+    ),
+    version=">=3.10",
+)
+def convert_closed_pattern(config: ParserConfig, children: Sequence[Any]) -> Any:
+    (child,) = children
+    return child
+
+
+def _contains_fstring(string: BaseExpression) -> bool:
+    if isinstance(string, ConcatenatedString):
+        return _contains_fstring(string.left) or _contains_fstring(string.right)
+    return isinstance(string, FormattedString)
+
+
+@with_production(
+    "literal_pattern",
+    "['-'] NUMBER [('+'|'-') NUMBER] | atom_string | 'None' | 'True' | 'False'",
+    version=">=3.10",
+)
+def convert_literal_pattern(config: ParserConfig, children: Sequence[Any]) -> Any:
+    first = children[0]
+    if not isinstance(first, Token):
+        # This is an atom_string
+        if _contains_fstring(first.value):
+            raise PartialParserSyntaxError("patterns may not match formatted string")
+        return MatchValue(value=first.value)
+    if first.string in ("None", "True", "False"):
+        return MatchSingleton(value=Name(first.string))
+
+    if first.string == "-":
+        minus_tok, number_tok, *rest = children
+        real = convert_atom_basic(config, [number_tok]).value
+        number = UnaryOperation(
+            operator=Minus(
+                whitespace_after=parse_parenthesizable_whitespace(
+                    config, minus_tok.whitespace_after
+                )
+            ),
+            expression=real,
+        )
+    else:
+        number_tok, *rest = children
+        real = number = convert_atom_basic(config, [number_tok]).value
+
+    if len(rest) == 0:
+        return MatchValue(value=number)
+
+    op_tok, imag_tok = rest
+    if isinstance(real, Imaginary):
+        raise PartialParserSyntaxError("real number required in complex literal")
+    imag = convert_atom_basic(config, [imag_tok]).value
+    if not isinstance(imag, Imaginary):
+        raise PartialParserSyntaxError("imaginary number required in complex literal")
+    op_cls = Add if op_tok.string == "+" else Subtract
+    return MatchValue(
+        value=BinaryOperation(
+            left=number,
+            operator=op_cls(
+                whitespace_before=parse_parenthesizable_whitespace(
+                    config, op_tok.whitespace_before
+                ),
+                whitespace_after=parse_parenthesizable_whitespace(
+                    config, op_tok.whitespace_after
+                ),
+            ),
+            right=imag,
+        )
+    )
+
+
+def _convert_dotted_name(config: ParserConfig, tokens: Sequence[Token]) -> Any:
+    first_tok, *rest = tokens
+    node: Union[Name, Attribute] = Name(first_tok.string)
+    for dot_tok, name_tok in grouper(rest, 2):
+        node = Attribute(
+            value=node,
+            dot=Dot(
+                whitespace_before=parse_parenthesizable_whitespace(
+                    config, dot_tok.whitespace_before
+                ),
+                whitespace_after=parse_parenthesizable_whitespace(
+                    config, dot_tok.whitespace_after
+                ),
+            ),
+            attr=Name(name_tok.string),
+        )
+    return node
+
+
+@with_production(
+    "name_or_class_pattern",
+    "NAME ('.' NAME)* ['(' [pattern_arguments] ')']",
+    version=">=3.10",
+)
+def convert_name_or_class_pattern(config: ParserConfig, children: Sequence[Any]) -> Any:
+    lpar_idx = next(
+        (
+            idx
+            for idx, child in enumerate(children)
+            if isinstance(child, Token) and child.string == "("
+        ),
+        len(children),
+    )
+    name_tokens = children[:lpar_idx]
+    cls = _convert_dotted_name(config, name_tokens)
+
+    if lpar_idx == len(children):
+        # There's no argument list, so this is a capture, wildcard or value pattern.
+        if isinstance(cls, Attribute):
+            return MatchValue(value=cls)
+        if cls.value == "_":
+            return MatchAs()
+        return MatchAs(name=cls)
+
+    lpar_tok, *args, rpar_tok = children[lpar_idx:]
+    whitespace_after_cls = parse_parenthesizable_whitespace(
+        config, lpar_tok.whitespace_before
+    )
+    whitespace_before_patterns = parse_parenthesizable_whitespace(
+        config, lpar_tok.whitespace_after
+    )
+    patterns: List[MatchSequenceElement] = []
+    kwds: List[MatchKeywordElement] = []
+    if args:
+        (arguments,) = args
+        for arg in arguments:
+            if isinstance(arg, MatchKeywordElement):
+                kwds.append(arg)
+            elif kwds:
+                raise PartialParserSyntaxError(
+                    "positional patterns follow keyword patterns"
+                )
+            else:
+                patterns.append(arg)
+    return MatchClass(
+        cls=cls,
+        whitespace_after_cls=whitespace_after_cls,
+        whitespace_before_patterns=whitespace_before_patterns,
+        patterns=patterns,
+        kwds=kwds,
+        whitespace_after_kwds=parse_parenthesizable_whitespace(
+            config, rpar_tok.whitespace_before
+        ),
+    )
+
+
+@with_production(
+    "pattern_arguments",
+    "pattern_argument (',' pattern_argument)* [',']",
+    version=">=3.10",
+)
+def convert_pattern_arguments(config: ParserConfig, children: Sequence[Any]) -> Any:
+    arguments: List[Union[MatchSequenceElement, MatchKeywordElement]] = []
+    for argument, comma_tok in grouper(children, 2):
+        if comma_tok is None:
+            arguments.append(argument)
+        else:
+            arguments.append(
+                argument.with_changes(
+                    comma=_convert_comma(config, comma_tok, comma_tok is children[-1])
+                )
+            )
+    return arguments
+
+
+@with_production("pattern_argument", "pattern ['=' pattern]", version=">=3.10")
+def convert_pattern_argument(config: ParserConfig, children: Sequence[Any]) -> Any:
+    if len(children) == 1:
+        (pattern,) = children
+        return MatchSequenceElement(value=pattern)
+
+    key, equal_tok, pattern = children
+    if not _is_capture(key):
+        raise PartialParserSyntaxError("keyword pattern must be a plain name")
+    return MatchKeywordElement(
+        key=key.name,
+        whitespace_before_equal=parse_parenthesizable_whitespace(
+            config, equal_tok.whitespace_before
+        ),
+        whitespace_after_equal=parse_parenthesizable_whitespace(
+            config, equal_tok.whitespace_after
+        ),
+        pattern=pattern,
+    )
+
+
+@with_production("group_or_tuple_pattern", "'(' [patterns] ')'", version=">=3.10")
+def convert_group_or_tuple_pattern(
+    config: ParserConfig, children: Sequence[Any]
+) -> Any:
+    lpar_tok, *body, rpar_tok = children
+    lpar = LeftParen(
+        whitespace_after=parse_parenthesizable_whitespace(
+            config, lpar_tok.whitespace_after
+        )
+    )
+    rpar = RightParen(
+        whitespace_before=parse_parenthesizable_whitespace(
+            config, rpar_tok.whitespace_before
+        )
+    )
+
+    if len(body) == 0:
+        return MatchTuple(patterns=(), lpar=(lpar,), rpar=(rpar,))
+
+    (pattern,) = body
+    if isinstance(pattern, MatchStar):
+        raise PartialParserSyntaxError("cannot use starred pattern here")
+    if isinstance(pattern, MatchTuple) and not pattern.lpar:
+        # This is an open sequence pattern, and therefore a tuple.
+        return pattern.with_changes(lpar=(lpar,), rpar=(rpar,))
+    # This is a group pattern.
+    return _add_parens(pattern, lpar, rpar)
+
+
+@with_production("list_pattern", "'[' [patterns] ']'", version=">=3.10")
+def convert_list_pattern(config: ParserConfig, children: Sequence[Any]) -> Any:
+    lbracket_tok, *body, rbracket_tok = children
+    lbracket = LeftSquareBracket(
+        whitespace_after=parse_parenthesizable_whitespace(
+            config, lbracket_tok.whitespace_after
+        )
+    )
+    rbracket = RightSquareBracket(
+        whitespace_before=parse_parenthesizable_whitespace(
+            config, rbracket_tok.whitespace_before
+        )
+    )
+
+    patterns: Sequence[Union[MatchSequenceElement, MatchStar]]
+    if len(body) == 0:
+        patterns = ()
+    else:
+        (pattern,) = body
+        if isinstance(pattern, MatchTuple) and not pattern.lpar:
+            patterns = pattern.patterns
+        elif isinstance(pattern, MatchStar):
+            patterns = (pattern,)
+        else:
+            patterns = (MatchSequenceElement(value=pattern),)
+    return MatchList(patterns=patterns, lbracket=lbracket, rbracket=rbracket)
+
+
+@with_production(
+    "mapping_pattern",
+    "'{' [mapping_item (',' mapping_item)* [',']] '}'",
+    version=">=3.10",
+)
+def convert_mapping_pattern(config: ParserConfig, children: Sequence[Any]) -> Any:
+    lbrace_tok, *body, rbrace_tok = children
+    lbrace = LeftCurlyBrace(
+        whitespace_after=parse_parenthesizable_whitespace(
+            config, lbrace_tok.whitespace_after
+        )
+    )
+
+    elements: List[MatchMappingElement] = []
+    rest: Optional[Name] = None
+    whitespace_before_rest = SimpleWhitespace("")
+    trailing_comma: Optional[Comma] = None
+    for item, comma_tok in grouper(body, 2):
+        if rest is not None:
+            raise PartialParserSyntaxError(
+                "double star pattern must be the last mapping item"
+            )
+        comma = (
+            None
+            if comma_tok is None
+            else _convert_comma(config, comma_tok, comma_tok is body[-1])
+        )
+        if isinstance(item, MatchMappingElement):
+            elements.append(item if comma is None else item.with_changes(comma=comma))
+        else:
+            whitespace_before_rest, rest = item
+            trailing_comma = comma
+
+    rbrace = RightCurlyBrace(
+        whitespace_before=parse_parenthesizable_whitespace(
+            config, rbrace_tok.whitespace_before
+        )
+    )
+    return MatchMapping(
+        lbrace=lbrace,
+        elements=elements,
+        whitespace_before_rest=whitespace_before_rest,
+        rest=rest,
+        trailing_comma=trailing_comma,
+        rbrace=rbrace,
+    )
+
+
+@with_production(
+    "mapping_item",
+    "'**' NAME | (literal_pattern | NAME ('.' NAME)*) ':' pattern",
+    version=">=3.10",
+)
+def convert_mapping_item(config: ParserConfig, children: Sequence[Any]) -> Any:
+    first = children[0]
+    if isinstance(first, Token) and first.string == "**":
+        _, name_tok = children
+        # The mapping pattern is responsible for putting this together.
+        return (
+            parse_parenthesizable_whitespace(config, first.whitespace_after),
+            Name(name_tok.string),
+        )
+
+    *key_children, colon_tok, pattern = children
+    if isinstance(first, MatchPattern):
+        (key_pattern,) = key_children
+        key = key_pattern.value
+    elif len(key_children) == 1:
+        raise PartialParserSyntaxError(
+            "mapping pattern keys may only match literals and attribute lookups"
+        )
+    else:
+        key = _convert_dotted_name(config, key_children)
+    return MatchMappingElement(
+        key=key,
+        whitespace_before_colon=parse_parenthesizable_whitespace(
+            config, colon_tok.whitespace_before
+        ),
+        whitespace_after_colon=parse_parenthesizable_whitespace(
+            config, colon_tok.whitespace_after
+        ),
+        pattern=pattern,
+    )
@@ -15,10 +15,13 @@ from libcst._nodes.expression import (
     Call,
     From,
     LeftParen,
+    LeftSquareBracket,
     Name,
     Param,
     Parameters,
     RightParen,
+    RightSquareBracket,
+    StarredElement,
 )
 from libcst._nodes.op import (
     AddAssign,
@@ -27,6 +30,7 @@ from libcst._nodes.op import (
     BitAndAssign,
     BitOrAssign,
     BitXorAssign,
+    Colon,
     Comma,
     DivideAssign,
     Dot,
@@ -55,6 +59,7 @@ from libcst._nodes.statement import (
     Del,
     Else,
     ExceptHandler,
+    ExceptStarHandler,
     Expr,
     Finally,
     For,
@@ -65,14 +70,24 @@ from libcst._nodes.statement import (
     ImportAlias,
     ImportFrom,
     IndentedBlock,
+    Match,
+    MatchCase,
+    MatchStar,
     NameItem,
     Nonlocal,
+    ParamSpec,
     Pass,
     Raise,
     Return,
     SimpleStatementLine,
     SimpleStatementSuite,
     Try,
+    TryStar,
+    TypeAlias,
+    TypeParam,
+    TypeParameters,
+    TypeVar,
+    TypeVarTuple,
     While,
     With,
     WithItem,
@@ -199,6 +214,16 @@ def convert_simple_stmt_suite(config: ParserConfig, children: Sequence[Any]) ->
         + "| raise_stmt | yield_stmt | import_stmt | global_stmt | nonlocal_stmt"
         + "| assert_stmt"
     ),
+    version="<=3.11",
+)
+@with_production(
+    "small_stmt",
+    (
+        "expr_stmt | del_stmt | pass_stmt | break_stmt | continue_stmt | return_stmt"
+        + "| raise_stmt | yield_stmt | import_stmt | global_stmt | nonlocal_stmt"
+        + "| assert_stmt | type_stmt"
+    ),
+    version=">=3.12",
 )
 def convert_small_stmt(config: ParserConfig, children: Sequence[Any]) -> Any:
     # Doesn't construct SmallStatement, because we don't know about semicolons yet.
@@ -723,9 +748,122 @@ def convert_assert_stmt(config: ParserConfig, children: Sequence[Any]) -> Any:
     return WithLeadingWhitespace(assert_node, assert_token.whitespace_before)
 
 
+@with_production("type_stmt", "TYPE NAME [type_params] '=' test", version=">=3.12")
+def convert_type_stmt(config: ParserConfig, children: Sequence[Any]) -> Any:
+    type_tok, name_tok, *type_params, equal_tok, value = children
+    whitespace_after_type = parse_simple_whitespace(config, type_tok.whitespace_after)
+    whitespace_after_name = parse_simple_whitespace(config, name_tok.whitespace_after)
+    if type_params:
+        (type_parameters,) = type_params
+        whitespace_after_type_parameters = parse_simple_whitespace(
+            config, equal_tok.whitespace_before
+        )
+    else:
+        type_parameters = None
+        whitespace_after_type_parameters = SimpleWhitespace("")
+    return WithLeadingWhitespace(
+        TypeAlias(
+            whitespace_after_type=whitespace_after_type,
+            name=Name(name_tok.string),
+            whitespace_after_name=whitespace_after_name,
+            type_parameters=type_parameters,
+            whitespace_after_type_parameters=whitespace_after_type_parameters,
+            whitespace_after_equals=parse_simple_whitespace(
+                config, equal_tok.whitespace_after
+            ),
+            value=value.value,
+        ),
+        type_tok.whitespace_before,
+    )
+
+
+@with_production(
+    "type_params", "'[' type_param (',' type_param)* [','] ']'", version=">=3.12"
+)
+def convert_type_params(config: ParserConfig, children: Sequence[Any]) -> Any:
+    lbracket_tok, *body, rbracket_tok = children
+    lbracket = LeftSquareBracket(
+        whitespace_after=parse_parenthesizable_whitespace(
+            config, lbracket_tok.whitespace_after
+        )
+    )
+
+    params: List[TypeParam] = []
+    for param, comma_tok in grouper(body, 2):
+        if comma_tok is None:
+            comma = MaybeSentinel.DEFAULT
+        else:
+            comma = Comma(
+                whitespace_before=parse_parenthesizable_whitespace(
+                    config, comma_tok.whitespace_before
+                ),
+                # A trailing comma's whitespace is owned by the closing bracket.
+                whitespace_after=(
+                    parse_parenthesizable_whitespace(config, comma_tok.whitespace_after)
+                    if comma_tok is not body[-1]
+                    else SimpleWhitespace("")
+                ),
+            )
+        params.append(TypeParam(param=param, comma=comma))
+
+    rbracket = RightSquareBracket(
+        whitespace_before=parse_parenthesizable_whitespace(
+            config, rbracket_tok.whitespace_before
+        )
+    )
+    return TypeParameters(params=params, lbracket=lbracket, rbracket=rbracket)
+
+
+@with_production(
+    "type_param", "NAME [':' test] | '*' NAME | '**' NAME", version=">=3.12"
+)
+def convert_type_param(config: ParserConfig, children: Sequence[Any]) -> Any:
+    first, *rest = children
+    if first.string == "*":
+        (name_tok,) = rest
+        return TypeVarTuple(
+            whitespace_after_star=parse_parenthesizable_whitespace(
+                config, first.whitespace_after
+            ),
+            name=Name(name_tok.string),
+        )
+    if first.string == "**":
+        (name_tok,) = rest
+        return ParamSpec(
+            whitespace_after_star=parse_parenthesizable_whitespace(
+                config, first.whitespace_after
+            ),
+            name=Name(name_tok.string),
+        )
+    if rest:
+        colon_tok, bound = rest
+        return TypeVar(
+            name=Name(first.string),
+            colon=Colon(
+                whitespace_before=parse_parenthesizable_whitespace(
+                    config, colon_tok.whitespace_before
+                ),
+                whitespace_after=parse_parenthesizable_whitespace(
+                    config, colon_tok.whitespace_after
+                ),
+            ),
+            bound=bound.value,
+        )
+    return TypeVar(name=Name(first.string))
+
+
 @with_production(
     "compound_stmt",
     ("if_stmt | while_stmt | asyncable_stmt | try_stmt | classdef | decorated"),
+    version="<=3.9",
+)
+@with_production(
+    "compound_stmt",
+    (
+        "if_stmt | while_stmt | asyncable_stmt | try_stmt | classdef | decorated"
+        + " | match_stmt"
+    ),
+    version=">=3.10",
 )
 def convert_compound_stmt(config: ParserConfig, children: Sequence[Any]) -> Any:
     (stmt,) = children
@@ -877,6 +1015,7 @@ def convert_for_stmt(config: ParserConfig, children: Sequence[Any]) -> Any:
 def convert_try_stmt(config: ParserConfig, children: Sequence[Any]) -> Any:
     trytoken, try_colon_token, try_suite, *rest = children
     handlers: List[ExceptHandler] = []
+    star_handlers: List[ExceptStarHandler] = []
     orelse: Optional[Else] = None
     finalbody: Optional[Finally] = None
 
@@ -905,6 +1044,27 @@ def convert_try_stmt(config: ParserConfig, children: Sequence[Any]) -> Any:
             else:
                 raise Exception("Logic error!")
         elif isinstance(clause, ExceptClausePartial):
+            whitespace_after_star = clause.whitespace_after_star
+            if whitespace_after_star is not None:
+                clause_type = clause.type
+                if clause_type is None:
+                    raise PartialParserSyntaxError(
+                        "expected exception type after except*"
+                    )
+                star_handlers.append(
+                    ExceptStarHandler(
+                        body=suite,
+                        type=clause_type,
+                        name=clause.name,
+                        leading_lines=clause.leading_lines,
+                        whitespace_after_except=clause.whitespace_after_except,
+                        whitespace_after_star=whitespace_after_star,
+                        whitespace_before_colon=parse_simple_whitespace(
+                            config, colon_token.whitespace_before
+                        ),
+                    )
+                )
+                continue
             handlers.append(
                 ExceptHandler(
                     body=suite,
@@ -920,6 +1080,22 @@ def convert_try_stmt(config: ParserConfig, children: Sequence[Any]) -> Any:
         else:
             raise Exception("Logic error!")
 
+    if star_handlers:
+        if handlers:
+            raise PartialParserSyntaxError(
+                "cannot have both 'except' and 'except*' on the same 'try'"
+            )
+        return TryStar(
+            leading_lines=parse_empty_lines(config, trytoken.whitespace_before),
+            whitespace_before_colon=parse_simple_whitespace(
+                config, try_colon_token.whitespace_before
+            ),
+            body=try_suite,
+            handlers=tuple(star_handlers),
+            orelse=orelse,
+            finalbody=finalbody,
+        )
+
     return Try(
         leading_lines=parse_empty_lines(config, trytoken.whitespace_before),
         whitespace_before_colon=parse_simple_whitespace(
@@ -932,8 +1108,23 @@ def convert_try_stmt(config: ParserConfig, children: Sequence[Any]) -> Any:
     )
 
 
-@with_production("except_clause", "'except' [test ['as' NAME]]")
+@with_production("except_clause", "'except' [test ['as' NAME]]", version="<=3.10")
+@with_production("except_clause", "'except' ['*'] [test ['as' NAME]]", version=">=3.11")
 def convert_except_clause(config: ParserConfig, children: Sequence[Any]) -> Any:
+    whitespace_after_star: Optional[SimpleWhitespace] = None
+    if (
+        len(children) > 1
+        and isinstance(children[1], Token)
+        and children[1].string == "*"
+    ):
+        # This is an except* clause (PEP 654). Remove the star, so the remaining
+        # children can be handled identically to a regular except clause.
+        except_token, star_token, *rest = children
+        whitespace_after_star = parse_simple_whitespace(
+            config, star_token.whitespace_after
+        )
+        children = [except_token, *rest]
+
     if len(children) == 1:
         (except_token,) = children
         whitespace_after_except = SimpleWhitespace("")
@@ -967,6 +1158,7 @@ def convert_except_clause(config: ParserConfig, children: Sequence[Any]) -> Any:
         whitespace_after_except=whitespace_after_except,
         type=test,
         name=name,
+        whitespace_after_star=whitespace_after_star,
     )
 
 
@@ -1060,9 +1252,32 @@ def convert_asyncable_funcdef(config: ParserConfig, children: Sequence[Any]) ->
     )
 
 
-@with_production("funcdef", "'def' NAME parameters [funcdef_annotation] ':' suite")
+@with_production(
+    "funcdef", "'def' NAME parameters [funcdef_annotation] ':' suite", version="<=3.11"
+)
+@with_production(
+    "funcdef",
+    "'def' NAME [type_params] parameters [funcdef_annotation] ':' suite",
+    version=">=3.12",
+)
 def convert_funcdef(config: ParserConfig, children: Sequence[Any]) -> Any:
-    defnode, namenode, param_partial, *annotation, colon, suite = children
+    defnode, namenode, *rest, colon, suite = children
+    if isinstance(rest[0], TypeParameters):
+        type_parameters, param_partial, *annotation = rest
+    else:
+        type_parameters = None
+        param_partial, *annotation = rest
+
+    # Parse the whitespace in the order that it appears in the source, since the
+    # whitespace after the name is shared with the token that follows it.
+    whitespace_after_def = parse_simple_whitespace(config, defnode.whitespace_after)
+    whitespace_after_name = parse_simple_whitespace(config, namenode.whitespace_after)
+    if type_parameters is not None:
+        whitespace_after_type_parameters = parse_simple_whitespace(
+            config, param_partial.whitespace_before
+        )
+    else:
+        whitespace_after_type_parameters = SimpleWhitespace("")
 
     # If the trailing paremeter doesn't have a comma, then it owns the trailing
     # whitespace before the rpar. Otherwise, the comma owns it (and will have
@@ -1106,13 +1321,11 @@ def convert_funcdef(config: ParserConfig, children: Sequence[Any]) -> Any:
 
     return WithLeadingWhitespace(
         FunctionDef(
-            whitespace_after_def=parse_simple_whitespace(
-                config, defnode.whitespace_after
-            ),
+            whitespace_after_def=whitespace_after_def,
             name=Name(namenode.string),
-            whitespace_after_name=parse_simple_whitespace(
-                config, namenode.whitespace_after
-            ),
+            whitespace_after_name=whitespace_after_name,
+            type_parameters=type_parameters,
+            whitespace_after_type_parameters=whitespace_after_type_parameters,
             whitespace_before_params=param_partial.lpar.whitespace_after,
             params=parameters,
             returns=None if not annotation else annotation[0],
@@ -1140,6 +1353,7 @@ def convert_parameters(config: ParserConfig, children: Sequence[Any]) -> Any:
                 config, rpar.whitespace_before
             )
         ),
+        whitespace_before=lpar.whitespace_before,
     )
 
 
@@ -1157,9 +1371,20 @@ def convert_funcdef_annotation(config: ParserConfig, children: Sequence[Any]) ->
     )
 
 
-@with_production("classdef", "'class' NAME ['(' [arglist] ')'] ':' suite")
+@with_production(
+    "classdef", "'class' NAME ['(' [arglist] ')'] ':' suite", version="<=3.11"
+)
+@with_production(
+    "classdef",
+    "'class' NAME [type_params] ['(' [arglist] ')'] ':' suite",
+    version=">=3.12",
+)
 def convert_classdef(config: ParserConfig, children: Sequence[Any]) -> Any:
     classdef, name, *arglist, colon, suite = children
+    if arglist and isinstance(arglist[0], TypeParameters):
+        type_parameters, *arglist = arglist
+    else:
+        type_parameters = None
 
     # First, parse out the comments and empty lines before the statement.
     leading_lines = parse_empty_lines(config, classdef.whitespace_before)
@@ -1168,6 +1393,14 @@ def convert_classdef(config: ParserConfig, children: Sequence[Any]) -> Any:
     whitespace_after_class = parse_simple_whitespace(config, classdef.whitespace_after)
     namenode = Name(name.string)
     whitespace_after_name = parse_simple_whitespace(config, name.whitespace_after)
+    if type_parameters is not None:
+        # The whitespace after the type parameters is owned by whichever token
+        # follows them, either the open paren or the colon.
+        whitespace_after_type_parameters = parse_simple_whitespace(
+            config, (arglist[0] if arglist else colon).whitespace_before
+        )
+    else:
+        whitespace_after_type_parameters = SimpleWhitespace("")
 
     # Now, construct the classdef node itself
     if not arglist:
@@ -1178,6 +1411,8 @@ def convert_classdef(config: ParserConfig, children: Sequence[Any]) -> Any:
             whitespace_after_class=whitespace_after_class,
             name=namenode,
             whitespace_after_name=whitespace_after_name,
+            type_parameters=type_parameters,
+            whitespace_after_type_parameters=whitespace_after_type_parameters,
             body=suite,
         )
     else:
@@ -1207,6 +1442,8 @@ def convert_classdef(config: ParserConfig, children: Sequence[Any]) -> Any:
             whitespace_after_class=whitespace_after_class,
             name=namenode,
             whitespace_after_name=whitespace_after_name,
+            type_parameters=type_parameters,
+            whitespace_after_type_parameters=whitespace_after_type_parameters,
             lpar=LeftParen(
                 whitespace_after=parse_parenthesizable_whitespace(
                     config, lpar.whitespace_after
@@ -1226,10 +1463,17 @@ def convert_classdef(config: ParserConfig, children: Sequence[Any]) -> Any:
         )
 
 
-@with_production("decorator", "'@' dotted_name [ '(' [arglist] ')' ] NEWLINE")
+@with_production(
+    "decorator", "'@' dotted_name [ '(' [arglist] ')' ] NEWLINE", version="<=3.8"
+)
+@with_production("decorator", "'@' namedexpr_test NEWLINE", version=">=3.9")
 def convert_decorator(config: ParserConfig, children: Sequence[Any]) -> Any:
     atsign, name, *arglist, newline = children
-    if not arglist:
+    if isinstance(name, WithLeadingWhitespace):
+        # Since PEP 614, this can be an arbitrary expression. The whitespace before
+        # it is owned by the atsign.
+        decoratornode = name.value
+    elif not arglist:
         # This is either a name or an attribute node, so just extract it.
         decoratornode = name
     else:
@@ -1322,6 +1566,74 @@ def convert_asyncable_stmt(config: ParserConfig, children: Sequence[Any]) -> Any
         raise Exception("Logic error!")
 
 
+@with_production(
+    "match_stmt",
+    "MATCH subject_expr ':' NEWLINE INDENT case_block+ DEDENT",
+    version=">=3.10",
+)
+def convert_match_stmt(config: ParserConfig, children: Sequence[Any]) -> Any:
+    match_tok, subject, colon_tok, newline, indent, *cases, dedent = children
+    if isinstance(subject.value, StarredElement):
+        raise PartialParserSyntaxError("cannot use starred expression here")
+    return Match(
+        leading_lines=parse_empty_lines(config, match_tok.whitespace_before),
+        whitespace_after_match=parse_simple_whitespace(
+            config, match_tok.whitespace_after
+        ),
+        subject=subject.value,
+        whitespace_before_colon=parse_simple_whitespace(
+            config, colon_tok.whitespace_before
+        ),
+        whitespace_after_colon=newline,
+        indent=(
+            None
+            if indent.relative_indent == config.default_indent
+            else indent.relative_indent
+        ),
+        cases=cases,
+        # See the comment in `convert_indented_suite` for why the indent is overridden.
+        footer=parse_empty_lines(
+            config,
+            dedent.whitespace_after,
+            override_absolute_indent=indent.whitespace_before.absolute_indent,
+        ),
+    )
+
+
+@with_production(
+    "case_block", "CASE patterns ['if' namedexpr_test] ':' suite", version=">=3.10"
+)
+def convert_case_block(config: ParserConfig, children: Sequence[Any]) -> Any:
+    case_tok, pattern, *guard, colon_tok, suite = children
+    if isinstance(pattern, MatchStar):
+        raise PartialParserSyntaxError("cannot use starred pattern here")
+
+    leading_lines = parse_empty_lines(config, case_tok.whitespace_before)
+    whitespace_after_case = parse_simple_whitespace(config, case_tok.whitespace_after)
+    if guard:
+        if_tok, guard_expr = guard
+        whitespace_before_if = parse_simple_whitespace(config, if_tok.whitespace_before)
+        whitespace_after_if = parse_simple_whitespace(config, if_tok.whitespace_after)
+        guard_node = guard_expr.value
+    else:
+        whitespace_before_if = SimpleWhitespace(" ")
+        whitespace_after_if = SimpleWhitespace(" ")
+        guard_node = None
+
+    return MatchCase(
+        leading_lines=leading_lines,
+        whitespace_after_case=whitespace_after_case,
+        pattern=pattern,
+        whitespace_before_if=whitespace_before_if,
+        whitespace_after_if=whitespace_after_if,
+        guard=guard_node,
+        whitespace_before_colon=parse_simple_whitespace(
+            config, colon_tok.whitespace_before
+        ),
+        body=suite,
+    )
+
+
 @with_production("suite", "simple_stmt_suite | indented_suite")
 def convert_suite(config: ParserConfig, children: Sequence[Any]) -> Any:
     (suite,) = children
@@ -80,3 +80,15 @@ def convert_ASYNC(config: ParserConfig, token: Token) -> Any:
 
 def convert_AWAIT(config: ParserConfig, token: Token) -> Any:
     return token
+
+
+def convert_MATCH(config: ParserConfig, token: Token) -> Any:
+    return token
+
+
+def convert_CASE(config: ParserConfig, token: Token) -> Any:
+    return token
+
+
+def convert_TYPE(config: ParserConfig, token: Token) -> Any:
+    return token
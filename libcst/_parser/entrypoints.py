@@ -10,19 +10,31 @@ parser. A parser entrypoint should take the source code and some configuration
 information
 """
 
-from typing import Union
+import re
+from typing import Any, List, Optional, Pattern, Sequence, Tuple, Union
 
+from libcst._exceptions import ParserSyntaxError
 from libcst._nodes.base import CSTNode
 from libcst._nodes.expression import BaseExpression
 from libcst._nodes.module import Module
-from libcst._nodes.statement import BaseCompoundStatement, SimpleStatementLine
+from libcst._nodes.statement import (
+    BaseCompoundStatement,
+    BaseStatement,
+    SimpleStatementLine,
+)
+from libcst._parser.base_parser import BaseParser
 from libcst._parser.detect_config import detect_config
 from libcst._parser.grammar import get_grammar, validate_grammar
+from libcst._parser.parso.python.token import TokenType
+from libcst._parser.parso.utils import split_lines
 from libcst._parser.python_parser import PythonCSTParser
 from libcst._parser.types.config import PartialParserConfig
+from libcst._parser.types.token import Token
 
 
 _DEFAULT_PARTIAL_PARSER_CONFIG: PartialParserConfig = PartialParserConfig()
+# Clauses that continue the compound statement before them, rather than starting one.
+_CONTINUATION_RE: Pattern[str] = re.compile(r"(?:elif|else|except|finally)\b")
 
 
 def _parse(
@@ -80,6 +92,144 @@ def parse_module(
     return result
 
 
+class _SyntaxCheckParser(BaseParser[Token, TokenType, None]):
+    """
+    A parser that only runs the grammar, without converting anything into nodes.
+    """
+
+    def convert_nonterminal(self, nonterminal: str, children: Sequence[Any]) -> None:
+        return None
+
+    def convert_terminal(self, token: Token) -> None:
+        return None
+
+
+def check_syntax_only(
+    source: Union[str, bytes],
+    config: PartialParserConfig = _DEFAULT_PARTIAL_PARSER_CONFIG,
+) -> None:
+    """
+    Checks whether an entire python module is syntactically valid, without building
+    a tree for it. Raises a :class:`~libcst.ParserSyntaxError` if it isn't. This
+    skips the conversion of the parse into nodes, along with parsing whitespace,
+    so it's faster than :func:`~libcst.parse_module` when the tree isn't needed,
+    for example to quickly validate code in a linter.
+
+    Some invalid code is only detected while converting the parse into nodes, such
+    as a parameter without a default value following one with a default value. Such
+    code passes this check, but makes :func:`~libcst.parse_module` raise an error.
+    """
+    detection_result = detect_config(
+        source,
+        partial=config,
+        detect_trailing_newline=True,
+        detect_default_newline=True,
+    )
+    validate_grammar()
+    parser = _SyntaxCheckParser(
+        tokens=detection_result.tokens,
+        lines=detection_result.config.lines,
+        pgen_grammar=get_grammar(config.parsed_python_version),
+        start_nonterminal="file_input",
+    )
+    parser.parse()
+
+
+def _is_top_level_line(line: str) -> bool:
+    # A cheap approximation of "this line could start a top-level statement". Lines
+    # inside of brackets or multi-line strings may also match, so callers must verify
+    # any boundary they pick by actually parsing up to it.
+    return (
+        bool(line.strip())
+        and not line[0].isspace()
+        and not line.startswith("#")
+        and _CONTINUATION_RE.match(line) is None
+    )
+
+
+def parse_module_tolerant(
+    source: str, config: PartialParserConfig = _DEFAULT_PARTIAL_PARSER_CONFIG
+) -> Tuple[Optional[Module], Sequence[ParserSyntaxError]]:
+    """
+    Like :func:`~libcst.parse_module`, but doesn't give up on the first syntax error.
+    Useful for tools that need to work with incomplete or in-progress code.
+
+    Recovery happens at the granularity of top-level statements: whenever a syntax
+    error is encountered, the top-level statement containing it is dropped, and
+    parsing resumes at the next line that could start a new top-level statement.
+
+    Returns a tuple of the recovered module and every syntax error that was
+    encountered, in source order. If there were no errors, the module is identical to
+    the one returned by :func:`~libcst.parse_module`. Otherwise the module only
+    contains the statements that could be parsed, so it won't roundtrip back to the
+    original source. The module is ``None`` if no statements could be recovered.
+    """
+    lines = split_lines(source, keepends=True)
+    errors: List[ParserSyntaxError] = []
+    body: List[BaseStatement] = []
+    # The first module we manage to parse provides the header and the detected config
+    # for the result, while the last one (if it parsed to the end of the source)
+    # provides the footer.
+    head: Optional[Module] = None
+    tail: Optional[Module] = None
+
+    start = 0
+    while start < len(lines):
+        try:
+            tail = parse_module("".join(lines[start:]), config)
+        except ParserSyntaxError as ex:
+            error_idx = start + ex.raw_line - 1
+            errors.append(
+                ParserSyntaxError(
+                    ex.message,
+                    lines=lines,
+                    raw_line=error_idx + 1,
+                    raw_column=ex.raw_column,
+                )
+            )
+
+            # Keep everything before the statement containing the error. The closest
+            # candidate boundary isn't always a real one (e.g. it could be inside of a
+            # multi-line string), so keep walking backwards until the prefix parses.
+            for end in range(min(error_idx, len(lines) - 1), start - 1, -1):
+                if end != start and not _is_top_level_line(lines[end]):
+                    continue
+                try:
+                    prefix = parse_module("".join(lines[start:end]), config)
+                except ParserSyntaxError:
+                    continue
+                head = head or prefix
+                body.extend(prefix.body)
+                break
+
+            # Skip ahead to the next line that could start a top-level statement.
+            start = error_idx + 1
+            while start < len(lines) and not _is_top_level_line(lines[start]):
+                start += 1
+        else:
+            head = head or tail
+            body.extend(tail.body)
+            break
+
+    if not errors:
+        return (tail, errors)
+    if head is None or not body:
+        return (None, errors)
+    if tail is None:
+        return (
+            head.with_changes(body=body, footer=(), has_trailing_newline=True),
+            errors,
+        )
+    return (
+        head.with_changes(
+            body=body,
+            footer=tail.footer,
+            has_trailing_newline=tail.has_trailing_newline,
+        ),
+        errors,
+    )
+
+
 def parse_statement(
     source: str, config: PartialParserConfig = _DEFAULT_PARTIAL_PARSER_CONFIG
 ) -> Union[SimpleStatementLine, BaseCompoundStatement]:
@@ -68,6 +68,22 @@ from libcst._parser.conversions.params import (
     convert_fpdef_star,
     convert_fpdef_starstar,
 )
+from libcst._parser.conversions.patterns import (
+    convert_closed_pattern,
+    convert_group_or_tuple_pattern,
+    convert_list_pattern,
+    convert_literal_pattern,
+    convert_mapping_item,
+    convert_mapping_pattern,
+    convert_maybe_star_pattern,
+    convert_name_or_class_pattern,
+    convert_or_pattern,
+    convert_pattern,
+    convert_pattern_argument,
+    convert_pattern_arguments,
+    convert_patterns,
+    convert_star_pattern,
+)
 from libcst._parser.conversions.statement import (
     convert_annassign,
     convert_assert_stmt,
@@ -76,6 +92,7 @@ from libcst._parser.conversions.statement import (
     convert_asyncable_stmt,
     convert_augassign,
     convert_break_stmt,
+    convert_case_block,
     convert_classdef,
     convert_compound_stmt,
     convert_continue_stmt,
@@ -102,6 +119,7 @@ from libcst._parser.conversions.statement import (
     convert_import_relative,
     convert_import_stmt,
     convert_indented_suite,
+    convert_match_stmt,
     convert_nonlocal_stmt,
     convert_parameters,
     convert_pass_stmt,
@@ -115,6 +133,9 @@ from libcst._parser.conversions.statement import (
     convert_stmt_input,
     convert_suite,
     convert_try_stmt,
+    convert_type_param,
+    convert_type_params,
+    convert_type_stmt,
     convert_while_stmt,
     convert_with_item,
     convert_with_stmt,
@@ -122,17 +143,20 @@ from libcst._parser.conversions.statement import (
 from libcst._parser.conversions.terminals import (
     convert_ASYNC,
     convert_AWAIT,
+    convert_CASE,
     convert_DEDENT,
     convert_ENDMARKER,
     convert_FSTRING_END,
     convert_FSTRING_START,
     convert_FSTRING_STRING,
     convert_INDENT,
+    convert_MATCH,
     convert_NAME,
     convert_NEWLINE,
     convert_NUMBER,
     convert_OP,
     convert_STRING,
+    convert_TYPE,
 )
 from libcst._parser.parso.pgen2.generator import Grammar, generate_grammar
 from libcst._parser.parso.python.token import PythonTokenTypes, TokenType
@@ -157,6 +181,9 @@ _TERMINAL_CONVERSIONS_SEQUENCE: Tuple[TerminalConversion, ...] = (
     convert_FSTRING_STRING,
     convert_ASYNC,
     convert_AWAIT,
+    convert_MATCH,
+    convert_CASE,
+    convert_TYPE,
 )
 
 # Try to match the order of https://docs.python.org/3/reference/grammar.html
@@ -191,6 +218,9 @@ _NONTERMINAL_CONVERSIONS_SEQUENCE: Tuple[NonterminalConversion, ...] = (
     convert_global_stmt,
     convert_nonlocal_stmt,
     convert_assert_stmt,
+    convert_type_stmt,
+    convert_type_params,
+    convert_type_param,
     convert_compound_stmt,
     convert_if_stmt,
     convert_if_stmt_elif,
@@ -208,6 +238,22 @@ _NONTERMINAL_CONVERSIONS_SEQUENCE: Tuple[NonterminalConversion, ...] = (
     convert_decorators,
     convert_decorated,
     convert_asyncable_stmt,
+    convert_match_stmt,
+    convert_case_block,
+    convert_patterns,
+    convert_maybe_star_pattern,
+    convert_star_pattern,
+    convert_pattern,
+    convert_or_pattern,
+    convert_closed_pattern,
+    convert_literal_pattern,
+    convert_name_or_class_pattern,
+    convert_pattern_arguments,
+    convert_pattern_argument,
+    convert_group_or_tuple_pattern,
+    convert_list_pattern,
+    convert_mapping_pattern,
+    convert_mapping_item,
     convert_parameters,
     convert_argslist,
     convert_fpdef_slash,
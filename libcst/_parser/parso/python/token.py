@@ -12,6 +12,7 @@
 # The following changes were made:
 # - Explicit TokenType references instead of dynamic creation.
 # - Use dataclasses instead of raw classes.
+# - Added MATCH, CASE and TYPE soft keyword token types.
 
 # pyre-strict
 from dataclasses import dataclass
@@ -41,6 +42,9 @@ class PythonTokenTypes:
     ERROR_DEDENT: TokenType = TokenType("ERROR_DEDENT")
     ASYNC: TokenType = TokenType("ASYNC")
     AWAIT: TokenType = TokenType("AWAIT")
+    MATCH: TokenType = TokenType("MATCH")
+    CASE: TokenType = TokenType("CASE")
+    TYPE: TokenType = TokenType("TYPE")
     FSTRING_STRING: TokenType = TokenType("FSTRING_STRING")
     FSTRING_START: TokenType = TokenType("FSTRING_START")
     FSTRING_END: TokenType = TokenType("FSTRING_END")
@@ -0,0 +1,49 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+
+# pyre-strict
+from typing import Union
+
+import libcst as cst
+from libcst import check_syntax_only
+from libcst.testing.utils import UnitTest, data_provider
+
+
+class CheckSyntaxOnlyTest(UnitTest):
+    @data_provider(
+        {
+            "empty": {"code": ""},
+            "simple": {"code": "x = 1\n"},
+            "no_trailing_newline": {"code": "x = 1"},
+            "compound": {"code": "class A:\n    def f(self, *, a=1):\n        pass\n"},
+            "bytes": {"code": b"# -*- coding: latin-1 -*-\nx = '\xe9'\n"},
+        }
+    )
+    def test_valid(self, code: Union[str, bytes]) -> None:
+        check_syntax_only(code)
+
+    @data_provider(
+        {
+            "bad_params": {"code": "def foo(:\n    pass\n", "line": 1, "column": 8},
+            "incomplete": {"code": "x = (\n", "line": 2, "column": 0},
+            "bad_indent": {"code": "x = 1\n  y = 2\n", "line": 2, "column": 2},
+        }
+    )
+    def test_invalid(self, code: str, line: int, column: int) -> None:
+        with self.assertRaises(cst.ParserSyntaxError) as context:
+            check_syntax_only(code)
+        self.assertEqual(
+            (context.exception.raw_line, context.exception.raw_column), (line, column)
+        )
+        # The error should be the same as the one parse_module raises.
+        with self.assertRaises(cst.ParserSyntaxError) as parse_context:
+            cst.parse_module(code)
+        self.assertEqual(str(context.exception), str(parse_context.exception))
+
+    def test_python_version(self) -> None:
+        code = "if (x := 1):\n    pass\n"
+        check_syntax_only(code, cst.PartialParserConfig(python_version="3.8"))
+        with self.assertRaises(cst.ParserSyntaxError):
+            check_syntax_only(code, cst.PartialParserConfig(python_version="3.7"))
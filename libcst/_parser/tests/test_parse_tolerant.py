@@ -0,0 +1,91 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+
+# pyre-strict
+from textwrap import dedent
+from typing import Optional, Sequence, Tuple
+
+import libcst as cst
+from libcst import parse_module, parse_module_tolerant
+from libcst.testing.utils import UnitTest, data_provider
+
+
+class ParseTolerantTest(UnitTest):
+    @data_provider(
+        {
+            "empty": {"code": ""},
+            "simple": {"code": "x = 1\n"},
+            "no_trailing_newline": {"code": "x = 1"},
+            "header_footer": {"code": "# header\n\nx = 1\n\n# footer\n"},
+        }
+    )
+    def test_valid(self, code: str) -> None:
+        module, errors = parse_module_tolerant(code)
+        self.assertEqual(errors, [])
+        self.assertIsNotNone(module)
+        self.assertTrue(module.deep_equals(parse_module(code)))
+
+    @data_provider(
+        {
+            "middle_statement": {
+                "code": "x = 1\ndef foo(:\n    pass\ny = 2\n",
+                "expected_code": "x = 1\ny = 2\n",
+                "expected_errors": [(2, 9)],
+            },
+            "last_statement": {
+                "code": "x = 1\ny = (\n",
+                "expected_code": "x = 1\n",
+                "expected_errors": [(3, 1)],
+            },
+            "multiple_errors": {
+                "code": dedent(
+                    """
+                    # header
+                    @decorator
+                    def foo(
+                        a,
+                    )
+                        pass
+                    x = (
+                    1)
+                    class Foo:
+                        x = $
+                    y = 2
+                    # footer
+                    """
+                ),
+                "expected_code": "\n# header\nx = (\n1)\ny = 2\n# footer\n",
+                "expected_errors": [(6, 2), (11, 9)],
+            },
+            "boundary_in_string": {
+                "code": "s = '''\nfoo(\n'''\nx = )\n",
+                "expected_code": "s = '''\nfoo(\n'''\n",
+                "expected_errors": [(4, 5)],
+            },
+            "continuation_clause": {
+                "code": "if x:\n    pass\nelse\n    pass\nz = 1\n",
+                "expected_code": "z = 1\n",
+                "expected_errors": [(3, 5)],
+            },
+            "nothing_recovered": {
+                "code": "1 +\n",
+                "expected_code": None,
+                "expected_errors": [(1, 4)],
+            },
+        }
+    )
+    def test_invalid(
+        self,
+        code: str,
+        expected_code: Optional[str],
+        expected_errors: Sequence[Tuple[int, int]],
+    ) -> None:
+        module, errors = parse_module_tolerant(code)
+        self.assertEqual(None if module is None else module.code, expected_code)
+        self.assertEqual(
+            [(err.editor_line, err.editor_column) for err in errors], expected_errors
+        )
+        for err in errors:
+            self.assertIsInstance(err, cst.ParserSyntaxError)
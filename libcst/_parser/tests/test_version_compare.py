@@ -32,6 +32,11 @@ class VersionCompareTest(UnitTest):
             (">3.6,<3.8", PythonVersionInfo(3, 6), False),
             (">3.6,<3.8", PythonVersionInfo(3, 7), True),
             (">3.6,<3.8", PythonVersionInfo(3, 8), False),
+            # Multi-digit minor versions
+            (">=3.11", PythonVersionInfo(3, 9), False),
+            (">=3.11", PythonVersionInfo(3, 11), True),
+            ("<=3.9", PythonVersionInfo(3, 10), False),
+            ("<=3.10", PythonVersionInfo(3, 9), True),
         )
     )
     def test_tokenize(
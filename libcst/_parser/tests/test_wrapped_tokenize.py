@@ -3,7 +3,7 @@
 # This source code is licensed under the MIT license found in the
 # LICENSE file in the root directory of this source tree.
 
-from typing import Sequence
+from typing import Sequence, Tuple
 
 from libcst._exceptions import ParserSyntaxError
 from libcst._parser.parso.python.token import PythonTokenTypes
@@ -16,6 +16,9 @@ from libcst.testing.utils import UnitTest, data_provider
 _PY38 = parse_version_string("3.8.0")
 _PY37 = parse_version_string("3.7.0")
 _PY36 = parse_version_string("3.6.0")
+
+# The type name, string, start position and end position of a string token.
+_StringToken = Tuple[str, str, Tuple[int, int], Tuple[int, int]]
 _PY35 = parse_version_string("3.5.0")
 
 
@@ -1904,3 +1907,44 @@ class WrappedTokenizeTest(UnitTest):
             with self.assertRaisesRegex(ParserSyntaxError, "Inconsistent indentation"):
                 # create some inconsistent indents to generate an ERROR_DEDENT token
                 tuple(tokenize("    a\n  b", version))
+
+    @data_provider(
+        {
+            "triple_quoted": {
+                "code": 'x = """a\nb\n"""\n',
+                "expected": [("STRING", '"""a\nb\n"""', (1, 4), (3, 3))],
+            },
+            "escaped_quotes": {
+                "code": "'''a\\'''\n\\''''\n",
+                "expected": [("STRING", "'''a\\'''\n\\''''", (1, 0), (2, 5))],
+            },
+            "raw": {
+                "code": 'r"""\\d\n\\"x"""\n',
+                "expected": [("STRING", 'r"""\\d\n\\"x"""', (1, 0), (2, 6))],
+            },
+            "fstring": {
+                "code": 'f"""a\n{b}\nc"""\n',
+                "expected": [
+                    ("FSTRING_START", 'f"""', (1, 0), (1, 4)),
+                    ("FSTRING_STRING", "a\n", (1, 4), (2, 0)),
+                    ("FSTRING_STRING", "\nc", (2, 3), (3, 1)),
+                    ("FSTRING_END", '"""', (3, 1), (3, 4)),
+                ],
+            },
+            "docstring": {
+                "code": 'def f():\n    """Doc.\n\n    More.\n    """\n',
+                "expected": [
+                    ("STRING", '"""Doc.\n\n    More.\n    """', (2, 4), (5, 7))
+                ],
+            },
+        }
+    )
+    def test_multiline_strings(
+        self, code: str, expected: Sequence[_StringToken]
+    ) -> None:
+        tokens = [
+            (token.type.name, token.string, token.start_pos, token.end_pos)
+            for token in tokenize(code, _PY38)
+            if "STRING" in token.type.name
+        ]
+        self.assertEqual(tokens, expected)
@@ -18,6 +18,9 @@ from libcst._parser.parso.utils import PythonVersionInfo, parse_version_string
 
 
 _INDENT_RE: Pattern[str] = re.compile(r"[ \t]+")
+# The newest version whose grammar is fully supported. Later versions can be parsed
+# when they're asked for explicitly, but aren't picked automatically.
+_NEWEST_COMPLETE_VERSION = PythonVersionInfo(3, 9)
 
 
 class BaseWhitespaceParserConfig(abc.ABC):
@@ -83,7 +86,13 @@ class PartialParserConfig:
     #: run LibCST. For example, you can parse code as 3.7 with a CPython 3.6
     #: interpreter.
     #:
-    #: Currently, only Python 3.5, 3.6, 3.7 and 3.8 syntax is supported.
+    #: Currently, only Python 3.5, 3.6, 3.7, 3.8, 3.9, 3.10, 3.11 and 3.12 syntax is
+    #: supported. Support for 3.10 and later is incomplete: parenthesized context
+    #: managers such as ``with (a as b, c as d):`` and star-unpacking such as
+    #: ``Generic[*Ts]`` or ``for x in *a, *b:`` raise a
+    #: :class:`~libcst.ParserSyntaxError`. By default, this is the version of the
+    #: running interpreter, capped at 3.9, so the newer grammars are only used when
+    #: they're asked for explicitly.
     python_version: Union[str, AutoConfig] = AutoConfig.token
 
     #: A named tuple with the ``major`` and ``minor`` Python version numbers. This is
@@ -111,9 +120,12 @@ class PartialParserConfig:
         # We use object.__setattr__ because the dataclass is frozen. See:
         # https://docs.python.org/3/library/dataclasses.html#frozen-instances
         # This should be safe behavior inside of `__post_init__`.
-        parsed_python_version = parse_version_string(
-            None if isinstance(raw_python_version, AutoConfig) else raw_python_version
-        )
+        if isinstance(raw_python_version, AutoConfig):
+            parsed_python_version = min(
+                parse_version_string(), _NEWEST_COMPLETE_VERSION
+            )
+        else:
+            parsed_python_version = parse_version_string(raw_python_version)
 
         # Once we add support for more versions of Python, we can change this to detect
         # the supported version range.
@@ -122,11 +134,15 @@ class PartialParserConfig:
             PythonVersionInfo(3, 6),
             PythonVersionInfo(3, 7),
             PythonVersionInfo(3, 8),
+            PythonVersionInfo(3, 9),
+            PythonVersionInfo(3, 10),
+            PythonVersionInfo(3, 11),
+            PythonVersionInfo(3, 12),
         ):
             raise ValueError(
                 "LibCST can only parse code using one of the following versions of "
-                + "Python's grammar: 3.5, 3.6, 3.7, 3.8. More versions may be "
-                + "supported by future releases."
+                + "Python's grammar: 3.5, 3.6, 3.7, 3.8, 3.9, 3.10, 3.11, 3.12. More "
+                + "versions may be supported by future releases."
             )
 
         object.__setattr__(self, "parsed_python_version", parsed_python_version)
@@ -118,6 +118,7 @@ class FuncdefPartial:
     lpar: LeftParen
     params: Parameters
     rpar: RightParen
+    whitespace_before: WhitespaceState
 
 
 @add_slots
@@ -160,3 +161,5 @@ class ExceptClausePartial:
     whitespace_after_except: SimpleWhitespace
     type: Optional[BaseExpression] = None
     name: Optional[AsName] = None
+    # Only set for ``except*`` clauses.
+    whitespace_after_star: Optional[SimpleWhitespace] = None
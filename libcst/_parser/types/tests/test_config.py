@@ -4,8 +4,11 @@
 # LICENSE file in the root directory of this source tree.
 
 # pyre-strict
+import sys
 from typing import Callable
+from unittest.mock import patch
 
+from libcst._parser.parso.utils import PythonVersionInfo
 from libcst._parser.types.config import PartialParserConfig
 from libcst.testing.utils import UnitTest, data_provider
 
@@ -55,3 +58,21 @@ class TestConfig(UnitTest):
     ) -> None:
         with self.assertRaisesRegex(ValueError, expected_re):
             factory()
+
+    @data_provider(
+        (
+            ((3, 7, 0, "final", 0), PythonVersionInfo(3, 7)),
+            ((3, 9, 1, "final", 0), PythonVersionInfo(3, 9)),
+            ((3, 11, 4, "final", 0), PythonVersionInfo(3, 9)),
+        )
+    )
+    def test_auto_python_version(
+        self, version_info: object, expected: PythonVersionInfo
+    ) -> None:
+        with patch.object(sys, "version_info", version_info):
+            config = PartialParserConfig()
+        self.assertEqual(config.parsed_python_version, expected)
+
+    def test_explicit_python_version(self) -> None:
+        config = PartialParserConfig(python_version="3.11")
+        self.assertEqual(config.parsed_python_version, PythonVersionInfo(3, 11))
@@ -16,6 +16,10 @@ performs a small number of transformations to the token stream:
 - `prefix` is removed, since we don't use it anywhere.
 - `ERRORTOKEN` and `ERROR_DEDENT` have been removed, because we don't intend to support
   error recovery. If we encounter token errors, we'll raise a ParserSyntaxError instead.
+- On Python 3.10+, the `match` and `case` soft keywords are emitted as `MATCH` and
+  `CASE` tokens when they begin a match statement or a case block, since our LL(1)
+  parser can't disambiguate them from regular names. Similarly, on Python 3.12+, the
+  `type` soft keyword is emitted as a `TYPE` token when it begins a type alias.
 
 If performance becomes a concern, we can rewrite this later as a fork of the original
 tokenize module, instead of as a wrapper.
@@ -23,7 +27,8 @@ tokenize module, instead of as a wrapper.
 
 from dataclasses import dataclass, field
 from enum import Enum
-from typing import Generator, List, Optional, Sequence
+from keyword import iskeyword
+from typing import Generator, Iterable, List, Optional, Sequence
 
 from libcst._add_slots import add_slots
 from libcst._exceptions import ParserSyntaxError
@@ -49,6 +54,12 @@ _FSTRING_END: TokenType = PythonTokenTypes.FSTRING_END
 
 _OP: TokenType = PythonTokenTypes.OP
 
+_NAME: TokenType = PythonTokenTypes.NAME
+_NEWLINE: TokenType = PythonTokenTypes.NEWLINE
+_MATCH: TokenType = PythonTokenTypes.MATCH
+_CASE: TokenType = PythonTokenTypes.CASE
+_TYPE: TokenType = PythonTokenTypes.TYPE
+
 
 class _ParenthesisOrFStringStackEntry(Enum):
     PARENTHESIS = 0
@@ -89,7 +100,10 @@ def tokenize_lines(
     lines: Sequence[str], version_info: PythonVersionInfo
 ) -> Generator[Token, None, None]:
     state = _TokenizeState(lines)
-    orig_tokens_iter = iter(orig_tokenize_lines(lines, version_info))
+    orig_tokens: Iterable[OrigToken] = orig_tokenize_lines(lines, version_info)
+    if version_info >= PythonVersionInfo(3, 10):
+        orig_tokens = _convert_soft_keywords(orig_tokens, version_info)
+    orig_tokens_iter = iter(orig_tokens)
 
     # Iterate over the tokens and pass them to _convert_token, providing a one-token
     # lookahead, to enable proper indent handling.
@@ -104,6 +118,86 @@ def tokenize_lines(
         yield _convert_token(state, curr_token, None)
 
 
+def _is_statement_end(tok: OrigToken) -> bool:
+    tok_type = tok.type
+    return tok_type is _NEWLINE or tok_type is _INDENT or tok_type is _DEDENT
+
+
+def _convert_type_soft_keyword(
+    orig_tokens: Iterable[OrigToken],
+) -> Generator[OrigToken, None, None]:
+    # A `type` followed by a name can only start a type alias, because two adjacent
+    # names are never a valid expression. This holds anywhere, so a type alias after
+    # a colon or a semicolon is found without tracking where statements start.
+    orig_tokens_iter = iter(orig_tokens)
+    for tok in orig_tokens_iter:
+        if tok.type is _NAME and tok.string == "type":
+            next_tok = next(orig_tokens_iter, None)
+            if next_tok is None:
+                yield tok
+                return
+            if next_tok.type is _NAME and not iskeyword(next_tok.string):
+                tok = tok._replace(type=_TYPE)
+            yield tok
+            yield next_tok
+        else:
+            yield tok
+
+
+def _convert_soft_keywords(
+    orig_tokens: Iterable[OrigToken], version_info: PythonVersionInfo
+) -> Generator[OrigToken, None, None]:
+    # A logical line starting with `match` and ending with a colon can only be a match
+    # statement, because no simple statement may end with a colon. Once we've seen a
+    # match statement, every statement in its body must be a case block.
+    #
+    # Type aliases are found first, so lines that are buffered while looking for a
+    # match statement already have them converted.
+    if version_info >= PythonVersionInfo(3, 12):
+        orig_tokens = _convert_type_soft_keyword(orig_tokens)
+    orig_tokens_iter = iter(orig_tokens)
+    match_body_depths: List[int] = []
+    depth = 0
+    is_match_body_pending = False
+    is_statement_start = True
+    for tok in orig_tokens_iter:
+        tok_type = tok.type
+        if tok_type is _INDENT:
+            depth += 1
+            if is_match_body_pending:
+                match_body_depths.append(depth)
+        elif tok_type is _DEDENT:
+            depth -= 1
+            while match_body_depths and match_body_depths[-1] > depth:
+                match_body_depths.pop()
+        elif is_statement_start and tok_type is _NAME:
+            if tok.string == "case":
+                if match_body_depths and match_body_depths[-1] == depth:
+                    tok = tok._replace(type=_CASE)
+            elif tok.string == "match":
+                line = [tok]
+                for next_tok in orig_tokens_iter:
+                    line.append(next_tok)
+                    if next_tok.type is _NEWLINE or next_tok.type is _ENDMARKER:
+                        break
+                if (
+                    len(line) >= 4
+                    and line[-1].type is _NEWLINE
+                    and line[-2].type is _OP
+                    and line[-2].string == ":"
+                ):
+                    line[0] = tok._replace(type=_MATCH)
+                    is_match_body_pending = True
+                else:
+                    is_match_body_pending = False
+                yield from line
+                is_statement_start = True
+                continue
+        is_match_body_pending = False
+        is_statement_start = _is_statement_end(tok)
+        yield tok
+
+
 def _convert_token(  # noqa: C901: too complex
     state: _TokenizeState, curr_token: OrigToken, next_token: Optional[OrigToken]
 ) -> Token:
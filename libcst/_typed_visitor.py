@@ -86,6 +86,7 @@ if TYPE_CHECKING:
         Del,
         Else,
         ExceptHandler,
+        ExceptStarHandler,
         Expr,
         Finally,
         For,
@@ -96,14 +97,38 @@ if TYPE_CHECKING:
         ImportAlias,
         ImportFrom,
         IndentedBlock,
+        Match,
+        MatchAs,
+        MatchCase,
+        MatchClass,
+        MatchKeywordElement,
+        MatchList,
+        MatchMapping,
+        MatchMappingElement,
+        MatchOr,
+        MatchOrElement,
+        MatchPattern,
+        MatchSequence,
+        MatchSequenceElement,
+        MatchSingleton,
+        MatchStar,
+        MatchTuple,
+        MatchValue,
         NameItem,
         Nonlocal,
+        ParamSpec,
         Pass,
         Raise,
         Return,
         SimpleStatementLine,
         SimpleStatementSuite,
         Try,
+        TryStar,
+        TypeAlias,
+        TypeParam,
+        TypeParameters,
+        TypeVar,
+        TypeVarTuple,
         While,
         With,
         WithItem,
@@ -1034,6 +1059,22 @@ class CSTTypedBaseFunctions:
     def leave_ClassDef_whitespace_before_colon(self, node: "ClassDef") -> None:
         pass
 
+    @mark_no_op
+    def visit_ClassDef_type_parameters(self, node: "ClassDef") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ClassDef_type_parameters(self, node: "ClassDef") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ClassDef_whitespace_after_type_parameters(self, node: "ClassDef") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ClassDef_whitespace_after_type_parameters(self, node: "ClassDef") -> None:
+        pass
+
     @mark_no_op
     def visit_Colon(self, node: "Colon") -> Optional[bool]:
         pass
@@ -1778,6 +1819,78 @@ class CSTTypedBaseFunctions:
     ) -> None:
         pass
 
+    @mark_no_op
+    def visit_ExceptStarHandler(self, node: "ExceptStarHandler") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_ExceptStarHandler_body(self, node: "ExceptStarHandler") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ExceptStarHandler_body(self, node: "ExceptStarHandler") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ExceptStarHandler_type(self, node: "ExceptStarHandler") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ExceptStarHandler_type(self, node: "ExceptStarHandler") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ExceptStarHandler_name(self, node: "ExceptStarHandler") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ExceptStarHandler_name(self, node: "ExceptStarHandler") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ExceptStarHandler_leading_lines(self, node: "ExceptStarHandler") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ExceptStarHandler_leading_lines(self, node: "ExceptStarHandler") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ExceptStarHandler_whitespace_after_except(
+        self, node: "ExceptStarHandler"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_ExceptStarHandler_whitespace_after_except(
+        self, node: "ExceptStarHandler"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def visit_ExceptStarHandler_whitespace_after_star(
+        self, node: "ExceptStarHandler"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_ExceptStarHandler_whitespace_after_star(
+        self, node: "ExceptStarHandler"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def visit_ExceptStarHandler_whitespace_before_colon(
+        self, node: "ExceptStarHandler"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_ExceptStarHandler_whitespace_before_colon(
+        self, node: "ExceptStarHandler"
+    ) -> None:
+        pass
+
     @mark_no_op
     def visit_Expr(self, node: "Expr") -> Optional[bool]:
         pass
@@ -2248,6 +2361,26 @@ class CSTTypedBaseFunctions:
     def leave_FunctionDef_whitespace_before_colon(self, node: "FunctionDef") -> None:
         pass
 
+    @mark_no_op
+    def visit_FunctionDef_type_parameters(self, node: "FunctionDef") -> None:
+        pass
+
+    @mark_no_op
+    def leave_FunctionDef_type_parameters(self, node: "FunctionDef") -> None:
+        pass
+
+    @mark_no_op
+    def visit_FunctionDef_whitespace_after_type_parameters(
+        self, node: "FunctionDef"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_FunctionDef_whitespace_after_type_parameters(
+        self, node: "FunctionDef"
+    ) -> None:
+        pass
+
     @mark_no_op
     def visit_GeneratorExp(self, node: "GeneratorExp") -> Optional[bool]:
         pass
@@ -3061,617 +3194,1267 @@ class CSTTypedBaseFunctions:
         pass
 
     @mark_no_op
-    def visit_MatrixMultiply(self, node: "MatrixMultiply") -> Optional[bool]:
+    def visit_Match(self, node: "Match") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_MatrixMultiply_whitespace_before(self, node: "MatrixMultiply") -> None:
+    def visit_Match_subject(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def leave_MatrixMultiply_whitespace_before(self, node: "MatrixMultiply") -> None:
+    def leave_Match_subject(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def visit_MatrixMultiply_whitespace_after(self, node: "MatrixMultiply") -> None:
+    def visit_Match_cases(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def leave_MatrixMultiply_whitespace_after(self, node: "MatrixMultiply") -> None:
+    def leave_Match_cases(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def visit_MatrixMultiplyAssign(
-        self, node: "MatrixMultiplyAssign"
-    ) -> Optional[bool]:
+    def visit_Match_leading_lines(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def visit_MatrixMultiplyAssign_whitespace_before(
-        self, node: "MatrixMultiplyAssign"
-    ) -> None:
+    def leave_Match_leading_lines(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def leave_MatrixMultiplyAssign_whitespace_before(
-        self, node: "MatrixMultiplyAssign"
-    ) -> None:
+    def visit_Match_whitespace_after_match(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def visit_MatrixMultiplyAssign_whitespace_after(
-        self, node: "MatrixMultiplyAssign"
-    ) -> None:
+    def leave_Match_whitespace_after_match(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def leave_MatrixMultiplyAssign_whitespace_after(
-        self, node: "MatrixMultiplyAssign"
-    ) -> None:
+    def visit_Match_whitespace_before_colon(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def visit_Minus(self, node: "Minus") -> Optional[bool]:
+    def leave_Match_whitespace_before_colon(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def visit_Minus_whitespace_after(self, node: "Minus") -> None:
+    def visit_Match_whitespace_after_colon(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def leave_Minus_whitespace_after(self, node: "Minus") -> None:
+    def leave_Match_whitespace_after_colon(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def visit_Module(self, node: "Module") -> Optional[bool]:
+    def visit_Match_indent(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def visit_Module_body(self, node: "Module") -> None:
+    def leave_Match_indent(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def leave_Module_body(self, node: "Module") -> None:
+    def visit_Match_footer(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def visit_Module_header(self, node: "Module") -> None:
+    def leave_Match_footer(self, node: "Match") -> None:
         pass
 
     @mark_no_op
-    def leave_Module_header(self, node: "Module") -> None:
+    def visit_MatchAs(self, node: "MatchAs") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_Module_footer(self, node: "Module") -> None:
+    def visit_MatchAs_pattern(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def leave_Module_footer(self, node: "Module") -> None:
+    def leave_MatchAs_pattern(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def visit_Module_encoding(self, node: "Module") -> None:
+    def visit_MatchAs_name(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def leave_Module_encoding(self, node: "Module") -> None:
+    def leave_MatchAs_name(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def visit_Module_default_indent(self, node: "Module") -> None:
+    def visit_MatchAs_whitespace_before_as(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def leave_Module_default_indent(self, node: "Module") -> None:
+    def leave_MatchAs_whitespace_before_as(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def visit_Module_default_newline(self, node: "Module") -> None:
+    def visit_MatchAs_whitespace_after_as(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def leave_Module_default_newline(self, node: "Module") -> None:
+    def leave_MatchAs_whitespace_after_as(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def visit_Module_has_trailing_newline(self, node: "Module") -> None:
+    def visit_MatchAs_lpar(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def leave_Module_has_trailing_newline(self, node: "Module") -> None:
+    def leave_MatchAs_lpar(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def visit_Modulo(self, node: "Modulo") -> Optional[bool]:
+    def visit_MatchAs_rpar(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def visit_Modulo_whitespace_before(self, node: "Modulo") -> None:
+    def leave_MatchAs_rpar(self, node: "MatchAs") -> None:
         pass
 
     @mark_no_op
-    def leave_Modulo_whitespace_before(self, node: "Modulo") -> None:
+    def visit_MatchCase(self, node: "MatchCase") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_Modulo_whitespace_after(self, node: "Modulo") -> None:
+    def visit_MatchCase_pattern(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def leave_Modulo_whitespace_after(self, node: "Modulo") -> None:
+    def leave_MatchCase_pattern(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def visit_ModuloAssign(self, node: "ModuloAssign") -> Optional[bool]:
+    def visit_MatchCase_body(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def visit_ModuloAssign_whitespace_before(self, node: "ModuloAssign") -> None:
+    def leave_MatchCase_body(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def leave_ModuloAssign_whitespace_before(self, node: "ModuloAssign") -> None:
+    def visit_MatchCase_guard(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def visit_ModuloAssign_whitespace_after(self, node: "ModuloAssign") -> None:
+    def leave_MatchCase_guard(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def leave_ModuloAssign_whitespace_after(self, node: "ModuloAssign") -> None:
+    def visit_MatchCase_leading_lines(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def visit_Multiply(self, node: "Multiply") -> Optional[bool]:
+    def leave_MatchCase_leading_lines(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def visit_Multiply_whitespace_before(self, node: "Multiply") -> None:
+    def visit_MatchCase_whitespace_after_case(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def leave_Multiply_whitespace_before(self, node: "Multiply") -> None:
+    def leave_MatchCase_whitespace_after_case(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def visit_Multiply_whitespace_after(self, node: "Multiply") -> None:
+    def visit_MatchCase_whitespace_before_if(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def leave_Multiply_whitespace_after(self, node: "Multiply") -> None:
+    def leave_MatchCase_whitespace_before_if(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def visit_MultiplyAssign(self, node: "MultiplyAssign") -> Optional[bool]:
+    def visit_MatchCase_whitespace_after_if(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def visit_MultiplyAssign_whitespace_before(self, node: "MultiplyAssign") -> None:
+    def leave_MatchCase_whitespace_after_if(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def leave_MultiplyAssign_whitespace_before(self, node: "MultiplyAssign") -> None:
+    def visit_MatchCase_whitespace_before_colon(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def visit_MultiplyAssign_whitespace_after(self, node: "MultiplyAssign") -> None:
+    def leave_MatchCase_whitespace_before_colon(self, node: "MatchCase") -> None:
         pass
 
     @mark_no_op
-    def leave_MultiplyAssign_whitespace_after(self, node: "MultiplyAssign") -> None:
+    def visit_MatchClass(self, node: "MatchClass") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_Name(self, node: "Name") -> Optional[bool]:
+    def visit_MatchClass_cls(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def visit_Name_value(self, node: "Name") -> None:
+    def leave_MatchClass_cls(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def leave_Name_value(self, node: "Name") -> None:
+    def visit_MatchClass_patterns(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def visit_Name_lpar(self, node: "Name") -> None:
+    def leave_MatchClass_patterns(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def leave_Name_lpar(self, node: "Name") -> None:
+    def visit_MatchClass_kwds(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def visit_Name_rpar(self, node: "Name") -> None:
+    def leave_MatchClass_kwds(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def leave_Name_rpar(self, node: "Name") -> None:
+    def visit_MatchClass_whitespace_after_cls(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def visit_NameItem(self, node: "NameItem") -> Optional[bool]:
+    def leave_MatchClass_whitespace_after_cls(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def visit_NameItem_name(self, node: "NameItem") -> None:
+    def visit_MatchClass_whitespace_before_patterns(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def leave_NameItem_name(self, node: "NameItem") -> None:
+    def leave_MatchClass_whitespace_before_patterns(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def visit_NameItem_comma(self, node: "NameItem") -> None:
+    def visit_MatchClass_whitespace_after_kwds(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def leave_NameItem_comma(self, node: "NameItem") -> None:
+    def leave_MatchClass_whitespace_after_kwds(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def visit_NamedExpr(self, node: "NamedExpr") -> Optional[bool]:
+    def visit_MatchClass_lpar(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def visit_NamedExpr_target(self, node: "NamedExpr") -> None:
+    def leave_MatchClass_lpar(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def leave_NamedExpr_target(self, node: "NamedExpr") -> None:
+    def visit_MatchClass_rpar(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def visit_NamedExpr_value(self, node: "NamedExpr") -> None:
+    def leave_MatchClass_rpar(self, node: "MatchClass") -> None:
         pass
 
     @mark_no_op
-    def leave_NamedExpr_value(self, node: "NamedExpr") -> None:
+    def visit_MatchKeywordElement(self, node: "MatchKeywordElement") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_NamedExpr_lpar(self, node: "NamedExpr") -> None:
+    def visit_MatchKeywordElement_key(self, node: "MatchKeywordElement") -> None:
         pass
 
     @mark_no_op
-    def leave_NamedExpr_lpar(self, node: "NamedExpr") -> None:
+    def leave_MatchKeywordElement_key(self, node: "MatchKeywordElement") -> None:
         pass
 
     @mark_no_op
-    def visit_NamedExpr_rpar(self, node: "NamedExpr") -> None:
+    def visit_MatchKeywordElement_pattern(self, node: "MatchKeywordElement") -> None:
         pass
 
     @mark_no_op
-    def leave_NamedExpr_rpar(self, node: "NamedExpr") -> None:
+    def leave_MatchKeywordElement_pattern(self, node: "MatchKeywordElement") -> None:
         pass
 
     @mark_no_op
-    def visit_NamedExpr_whitespace_before_walrus(self, node: "NamedExpr") -> None:
+    def visit_MatchKeywordElement_comma(self, node: "MatchKeywordElement") -> None:
         pass
 
     @mark_no_op
-    def leave_NamedExpr_whitespace_before_walrus(self, node: "NamedExpr") -> None:
+    def leave_MatchKeywordElement_comma(self, node: "MatchKeywordElement") -> None:
         pass
 
     @mark_no_op
-    def visit_NamedExpr_whitespace_after_walrus(self, node: "NamedExpr") -> None:
+    def visit_MatchKeywordElement_whitespace_before_equal(
+        self, node: "MatchKeywordElement"
+    ) -> None:
         pass
 
     @mark_no_op
-    def leave_NamedExpr_whitespace_after_walrus(self, node: "NamedExpr") -> None:
+    def leave_MatchKeywordElement_whitespace_before_equal(
+        self, node: "MatchKeywordElement"
+    ) -> None:
         pass
 
     @mark_no_op
-    def visit_Newline(self, node: "Newline") -> Optional[bool]:
+    def visit_MatchKeywordElement_whitespace_after_equal(
+        self, node: "MatchKeywordElement"
+    ) -> None:
         pass
 
     @mark_no_op
-    def visit_Newline_value(self, node: "Newline") -> None:
+    def leave_MatchKeywordElement_whitespace_after_equal(
+        self, node: "MatchKeywordElement"
+    ) -> None:
         pass
 
     @mark_no_op
-    def leave_Newline_value(self, node: "Newline") -> None:
+    def visit_MatchList(self, node: "MatchList") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_Nonlocal(self, node: "Nonlocal") -> Optional[bool]:
+    def visit_MatchList_patterns(self, node: "MatchList") -> None:
         pass
 
     @mark_no_op
-    def visit_Nonlocal_names(self, node: "Nonlocal") -> None:
+    def leave_MatchList_patterns(self, node: "MatchList") -> None:
         pass
 
     @mark_no_op
-    def leave_Nonlocal_names(self, node: "Nonlocal") -> None:
+    def visit_MatchList_lbracket(self, node: "MatchList") -> None:
         pass
 
     @mark_no_op
-    def visit_Nonlocal_whitespace_after_nonlocal(self, node: "Nonlocal") -> None:
+    def leave_MatchList_lbracket(self, node: "MatchList") -> None:
         pass
 
     @mark_no_op
-    def leave_Nonlocal_whitespace_after_nonlocal(self, node: "Nonlocal") -> None:
+    def visit_MatchList_rbracket(self, node: "MatchList") -> None:
         pass
 
     @mark_no_op
-    def visit_Nonlocal_semicolon(self, node: "Nonlocal") -> None:
+    def leave_MatchList_rbracket(self, node: "MatchList") -> None:
         pass
 
     @mark_no_op
-    def leave_Nonlocal_semicolon(self, node: "Nonlocal") -> None:
+    def visit_MatchList_lpar(self, node: "MatchList") -> None:
         pass
 
     @mark_no_op
-    def visit_Not(self, node: "Not") -> Optional[bool]:
+    def leave_MatchList_lpar(self, node: "MatchList") -> None:
         pass
 
     @mark_no_op
-    def visit_Not_whitespace_after(self, node: "Not") -> None:
+    def visit_MatchList_rpar(self, node: "MatchList") -> None:
         pass
 
     @mark_no_op
-    def leave_Not_whitespace_after(self, node: "Not") -> None:
+    def leave_MatchList_rpar(self, node: "MatchList") -> None:
         pass
 
     @mark_no_op
-    def visit_NotEqual(self, node: "NotEqual") -> Optional[bool]:
+    def visit_MatchMapping(self, node: "MatchMapping") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_NotEqual_value(self, node: "NotEqual") -> None:
+    def visit_MatchMapping_elements(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def leave_NotEqual_value(self, node: "NotEqual") -> None:
+    def leave_MatchMapping_elements(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def visit_NotEqual_whitespace_before(self, node: "NotEqual") -> None:
+    def visit_MatchMapping_lbrace(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def leave_NotEqual_whitespace_before(self, node: "NotEqual") -> None:
+    def leave_MatchMapping_lbrace(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def visit_NotEqual_whitespace_after(self, node: "NotEqual") -> None:
+    def visit_MatchMapping_rbrace(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def leave_NotEqual_whitespace_after(self, node: "NotEqual") -> None:
+    def leave_MatchMapping_rbrace(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def visit_NotIn(self, node: "NotIn") -> Optional[bool]:
+    def visit_MatchMapping_rest(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def visit_NotIn_whitespace_before(self, node: "NotIn") -> None:
+    def leave_MatchMapping_rest(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def leave_NotIn_whitespace_before(self, node: "NotIn") -> None:
+    def visit_MatchMapping_whitespace_before_rest(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def visit_NotIn_whitespace_between(self, node: "NotIn") -> None:
+    def leave_MatchMapping_whitespace_before_rest(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def leave_NotIn_whitespace_between(self, node: "NotIn") -> None:
+    def visit_MatchMapping_trailing_comma(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def visit_NotIn_whitespace_after(self, node: "NotIn") -> None:
+    def leave_MatchMapping_trailing_comma(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def leave_NotIn_whitespace_after(self, node: "NotIn") -> None:
+    def visit_MatchMapping_lpar(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def visit_Or(self, node: "Or") -> Optional[bool]:
+    def leave_MatchMapping_lpar(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def visit_Or_whitespace_before(self, node: "Or") -> None:
+    def visit_MatchMapping_rpar(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def leave_Or_whitespace_before(self, node: "Or") -> None:
+    def leave_MatchMapping_rpar(self, node: "MatchMapping") -> None:
         pass
 
     @mark_no_op
-    def visit_Or_whitespace_after(self, node: "Or") -> None:
+    def visit_MatchMappingElement(self, node: "MatchMappingElement") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def leave_Or_whitespace_after(self, node: "Or") -> None:
+    def visit_MatchMappingElement_key(self, node: "MatchMappingElement") -> None:
         pass
 
     @mark_no_op
-    def visit_Param(self, node: "Param") -> Optional[bool]:
+    def leave_MatchMappingElement_key(self, node: "MatchMappingElement") -> None:
         pass
 
     @mark_no_op
-    def visit_Param_name(self, node: "Param") -> None:
+    def visit_MatchMappingElement_pattern(self, node: "MatchMappingElement") -> None:
         pass
 
     @mark_no_op
-    def leave_Param_name(self, node: "Param") -> None:
+    def leave_MatchMappingElement_pattern(self, node: "MatchMappingElement") -> None:
         pass
 
     @mark_no_op
-    def visit_Param_annotation(self, node: "Param") -> None:
+    def visit_MatchMappingElement_comma(self, node: "MatchMappingElement") -> None:
         pass
 
     @mark_no_op
-    def leave_Param_annotation(self, node: "Param") -> None:
+    def leave_MatchMappingElement_comma(self, node: "MatchMappingElement") -> None:
         pass
 
     @mark_no_op
-    def visit_Param_equal(self, node: "Param") -> None:
+    def visit_MatchMappingElement_whitespace_before_colon(
+        self, node: "MatchMappingElement"
+    ) -> None:
         pass
 
     @mark_no_op
-    def leave_Param_equal(self, node: "Param") -> None:
+    def leave_MatchMappingElement_whitespace_before_colon(
+        self, node: "MatchMappingElement"
+    ) -> None:
         pass
 
     @mark_no_op
-    def visit_Param_default(self, node: "Param") -> None:
+    def visit_MatchMappingElement_whitespace_after_colon(
+        self, node: "MatchMappingElement"
+    ) -> None:
         pass
 
     @mark_no_op
-    def leave_Param_default(self, node: "Param") -> None:
+    def leave_MatchMappingElement_whitespace_after_colon(
+        self, node: "MatchMappingElement"
+    ) -> None:
         pass
 
     @mark_no_op
-    def visit_Param_comma(self, node: "Param") -> None:
+    def visit_MatchOr(self, node: "MatchOr") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def leave_Param_comma(self, node: "Param") -> None:
+    def visit_MatchOr_patterns(self, node: "MatchOr") -> None:
         pass
 
     @mark_no_op
-    def visit_Param_star(self, node: "Param") -> None:
+    def leave_MatchOr_patterns(self, node: "MatchOr") -> None:
         pass
 
     @mark_no_op
-    def leave_Param_star(self, node: "Param") -> None:
+    def visit_MatchOr_lpar(self, node: "MatchOr") -> None:
         pass
 
     @mark_no_op
-    def visit_Param_whitespace_after_star(self, node: "Param") -> None:
+    def leave_MatchOr_lpar(self, node: "MatchOr") -> None:
         pass
 
     @mark_no_op
-    def leave_Param_whitespace_after_star(self, node: "Param") -> None:
+    def visit_MatchOr_rpar(self, node: "MatchOr") -> None:
         pass
 
     @mark_no_op
-    def visit_Param_whitespace_after_param(self, node: "Param") -> None:
+    def leave_MatchOr_rpar(self, node: "MatchOr") -> None:
         pass
 
     @mark_no_op
-    def leave_Param_whitespace_after_param(self, node: "Param") -> None:
+    def visit_MatchOrElement(self, node: "MatchOrElement") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_ParamSlash(self, node: "ParamSlash") -> Optional[bool]:
+    def visit_MatchOrElement_pattern(self, node: "MatchOrElement") -> None:
         pass
 
     @mark_no_op
-    def visit_ParamSlash_comma(self, node: "ParamSlash") -> None:
+    def leave_MatchOrElement_pattern(self, node: "MatchOrElement") -> None:
         pass
 
     @mark_no_op
-    def leave_ParamSlash_comma(self, node: "ParamSlash") -> None:
+    def visit_MatchOrElement_separator(self, node: "MatchOrElement") -> None:
         pass
 
     @mark_no_op
-    def visit_ParamStar(self, node: "ParamStar") -> Optional[bool]:
+    def leave_MatchOrElement_separator(self, node: "MatchOrElement") -> None:
         pass
 
     @mark_no_op
-    def visit_ParamStar_comma(self, node: "ParamStar") -> None:
+    def visit_MatchPattern(self, node: "MatchPattern") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def leave_ParamStar_comma(self, node: "ParamStar") -> None:
+    def visit_MatchSequence(self, node: "MatchSequence") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_Parameters(self, node: "Parameters") -> Optional[bool]:
+    def visit_MatchSequenceElement(
+        self, node: "MatchSequenceElement"
+    ) -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_Parameters_params(self, node: "Parameters") -> None:
+    def visit_MatchSequenceElement_value(self, node: "MatchSequenceElement") -> None:
         pass
 
     @mark_no_op
-    def leave_Parameters_params(self, node: "Parameters") -> None:
+    def leave_MatchSequenceElement_value(self, node: "MatchSequenceElement") -> None:
         pass
 
     @mark_no_op
-    def visit_Parameters_star_arg(self, node: "Parameters") -> None:
+    def visit_MatchSequenceElement_comma(self, node: "MatchSequenceElement") -> None:
         pass
 
     @mark_no_op
-    def leave_Parameters_star_arg(self, node: "Parameters") -> None:
+    def leave_MatchSequenceElement_comma(self, node: "MatchSequenceElement") -> None:
         pass
 
     @mark_no_op
-    def visit_Parameters_kwonly_params(self, node: "Parameters") -> None:
+    def visit_MatchSingleton(self, node: "MatchSingleton") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def leave_Parameters_kwonly_params(self, node: "Parameters") -> None:
+    def visit_MatchSingleton_value(self, node: "MatchSingleton") -> None:
         pass
 
     @mark_no_op
-    def visit_Parameters_star_kwarg(self, node: "Parameters") -> None:
+    def leave_MatchSingleton_value(self, node: "MatchSingleton") -> None:
         pass
 
     @mark_no_op
-    def leave_Parameters_star_kwarg(self, node: "Parameters") -> None:
+    def visit_MatchStar(self, node: "MatchStar") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_Parameters_posonly_params(self, node: "Parameters") -> None:
+    def visit_MatchStar_name(self, node: "MatchStar") -> None:
         pass
 
     @mark_no_op
-    def leave_Parameters_posonly_params(self, node: "Parameters") -> None:
+    def leave_MatchStar_name(self, node: "MatchStar") -> None:
         pass
 
     @mark_no_op
-    def visit_Parameters_posonly_ind(self, node: "Parameters") -> None:
+    def visit_MatchStar_comma(self, node: "MatchStar") -> None:
         pass
 
     @mark_no_op
-    def leave_Parameters_posonly_ind(self, node: "Parameters") -> None:
+    def leave_MatchStar_comma(self, node: "MatchStar") -> None:
         pass
 
     @mark_no_op
-    def visit_ParenthesizedWhitespace(
-        self, node: "ParenthesizedWhitespace"
-    ) -> Optional[bool]:
+    def visit_MatchStar_whitespace_before_name(self, node: "MatchStar") -> None:
         pass
 
     @mark_no_op
-    def visit_ParenthesizedWhitespace_first_line(
-        self, node: "ParenthesizedWhitespace"
-    ) -> None:
+    def leave_MatchStar_whitespace_before_name(self, node: "MatchStar") -> None:
         pass
 
     @mark_no_op
-    def leave_ParenthesizedWhitespace_first_line(
-        self, node: "ParenthesizedWhitespace"
-    ) -> None:
+    def visit_MatchTuple(self, node: "MatchTuple") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_ParenthesizedWhitespace_empty_lines(
-        self, node: "ParenthesizedWhitespace"
-    ) -> None:
+    def visit_MatchTuple_patterns(self, node: "MatchTuple") -> None:
         pass
 
     @mark_no_op
-    def leave_ParenthesizedWhitespace_empty_lines(
-        self, node: "ParenthesizedWhitespace"
-    ) -> None:
+    def leave_MatchTuple_patterns(self, node: "MatchTuple") -> None:
         pass
 
     @mark_no_op
-    def visit_ParenthesizedWhitespace_indent(
-        self, node: "ParenthesizedWhitespace"
-    ) -> None:
+    def visit_MatchTuple_lpar(self, node: "MatchTuple") -> None:
         pass
 
     @mark_no_op
-    def leave_ParenthesizedWhitespace_indent(
+    def leave_MatchTuple_lpar(self, node: "MatchTuple") -> None:
+        pass
+
+    @mark_no_op
+    def visit_MatchTuple_rpar(self, node: "MatchTuple") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchTuple_rpar(self, node: "MatchTuple") -> None:
+        pass
+
+    @mark_no_op
+    def visit_MatchValue(self, node: "MatchValue") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_MatchValue_value(self, node: "MatchValue") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchValue_value(self, node: "MatchValue") -> None:
+        pass
+
+    @mark_no_op
+    def visit_MatrixMultiply(self, node: "MatrixMultiply") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_MatrixMultiply_whitespace_before(self, node: "MatrixMultiply") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatrixMultiply_whitespace_before(self, node: "MatrixMultiply") -> None:
+        pass
+
+    @mark_no_op
+    def visit_MatrixMultiply_whitespace_after(self, node: "MatrixMultiply") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatrixMultiply_whitespace_after(self, node: "MatrixMultiply") -> None:
+        pass
+
+    @mark_no_op
+    def visit_MatrixMultiplyAssign(
+        self, node: "MatrixMultiplyAssign"
+    ) -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_MatrixMultiplyAssign_whitespace_before(
+        self, node: "MatrixMultiplyAssign"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatrixMultiplyAssign_whitespace_before(
+        self, node: "MatrixMultiplyAssign"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def visit_MatrixMultiplyAssign_whitespace_after(
+        self, node: "MatrixMultiplyAssign"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatrixMultiplyAssign_whitespace_after(
+        self, node: "MatrixMultiplyAssign"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def visit_Minus(self, node: "Minus") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Minus_whitespace_after(self, node: "Minus") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Minus_whitespace_after(self, node: "Minus") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Module(self, node: "Module") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Module_body(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Module_body(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Module_header(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Module_header(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Module_footer(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Module_footer(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Module_encoding(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Module_encoding(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Module_default_indent(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Module_default_indent(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Module_default_newline(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Module_default_newline(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Module_has_trailing_newline(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Module_has_trailing_newline(self, node: "Module") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Modulo(self, node: "Modulo") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Modulo_whitespace_before(self, node: "Modulo") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Modulo_whitespace_before(self, node: "Modulo") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Modulo_whitespace_after(self, node: "Modulo") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Modulo_whitespace_after(self, node: "Modulo") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ModuloAssign(self, node: "ModuloAssign") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_ModuloAssign_whitespace_before(self, node: "ModuloAssign") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ModuloAssign_whitespace_before(self, node: "ModuloAssign") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ModuloAssign_whitespace_after(self, node: "ModuloAssign") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ModuloAssign_whitespace_after(self, node: "ModuloAssign") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Multiply(self, node: "Multiply") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Multiply_whitespace_before(self, node: "Multiply") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Multiply_whitespace_before(self, node: "Multiply") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Multiply_whitespace_after(self, node: "Multiply") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Multiply_whitespace_after(self, node: "Multiply") -> None:
+        pass
+
+    @mark_no_op
+    def visit_MultiplyAssign(self, node: "MultiplyAssign") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_MultiplyAssign_whitespace_before(self, node: "MultiplyAssign") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MultiplyAssign_whitespace_before(self, node: "MultiplyAssign") -> None:
+        pass
+
+    @mark_no_op
+    def visit_MultiplyAssign_whitespace_after(self, node: "MultiplyAssign") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MultiplyAssign_whitespace_after(self, node: "MultiplyAssign") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Name(self, node: "Name") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Name_value(self, node: "Name") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Name_value(self, node: "Name") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Name_lpar(self, node: "Name") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Name_lpar(self, node: "Name") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Name_rpar(self, node: "Name") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Name_rpar(self, node: "Name") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NameItem(self, node: "NameItem") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_NameItem_name(self, node: "NameItem") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NameItem_name(self, node: "NameItem") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NameItem_comma(self, node: "NameItem") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NameItem_comma(self, node: "NameItem") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NamedExpr(self, node: "NamedExpr") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_NamedExpr_target(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NamedExpr_target(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NamedExpr_value(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NamedExpr_value(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NamedExpr_lpar(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NamedExpr_lpar(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NamedExpr_rpar(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NamedExpr_rpar(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NamedExpr_whitespace_before_walrus(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NamedExpr_whitespace_before_walrus(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NamedExpr_whitespace_after_walrus(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NamedExpr_whitespace_after_walrus(self, node: "NamedExpr") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Newline(self, node: "Newline") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Newline_value(self, node: "Newline") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Newline_value(self, node: "Newline") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Nonlocal(self, node: "Nonlocal") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Nonlocal_names(self, node: "Nonlocal") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Nonlocal_names(self, node: "Nonlocal") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Nonlocal_whitespace_after_nonlocal(self, node: "Nonlocal") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Nonlocal_whitespace_after_nonlocal(self, node: "Nonlocal") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Nonlocal_semicolon(self, node: "Nonlocal") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Nonlocal_semicolon(self, node: "Nonlocal") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Not(self, node: "Not") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Not_whitespace_after(self, node: "Not") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Not_whitespace_after(self, node: "Not") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NotEqual(self, node: "NotEqual") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_NotEqual_value(self, node: "NotEqual") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NotEqual_value(self, node: "NotEqual") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NotEqual_whitespace_before(self, node: "NotEqual") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NotEqual_whitespace_before(self, node: "NotEqual") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NotEqual_whitespace_after(self, node: "NotEqual") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NotEqual_whitespace_after(self, node: "NotEqual") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NotIn(self, node: "NotIn") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_NotIn_whitespace_before(self, node: "NotIn") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NotIn_whitespace_before(self, node: "NotIn") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NotIn_whitespace_between(self, node: "NotIn") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NotIn_whitespace_between(self, node: "NotIn") -> None:
+        pass
+
+    @mark_no_op
+    def visit_NotIn_whitespace_after(self, node: "NotIn") -> None:
+        pass
+
+    @mark_no_op
+    def leave_NotIn_whitespace_after(self, node: "NotIn") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Or(self, node: "Or") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Or_whitespace_before(self, node: "Or") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Or_whitespace_before(self, node: "Or") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Or_whitespace_after(self, node: "Or") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Or_whitespace_after(self, node: "Or") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Param(self, node: "Param") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Param_name(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Param_name(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Param_annotation(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Param_annotation(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Param_equal(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Param_equal(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Param_default(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Param_default(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Param_comma(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Param_comma(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Param_star(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Param_star(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Param_whitespace_after_star(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Param_whitespace_after_star(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Param_whitespace_after_param(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Param_whitespace_after_param(self, node: "Param") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ParamSlash(self, node: "ParamSlash") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_ParamSlash_comma(self, node: "ParamSlash") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ParamSlash_comma(self, node: "ParamSlash") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ParamSpec(self, node: "ParamSpec") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_ParamSpec_name(self, node: "ParamSpec") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ParamSpec_name(self, node: "ParamSpec") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ParamSpec_whitespace_after_star(self, node: "ParamSpec") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ParamSpec_whitespace_after_star(self, node: "ParamSpec") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ParamStar(self, node: "ParamStar") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_ParamStar_comma(self, node: "ParamStar") -> None:
+        pass
+
+    @mark_no_op
+    def leave_ParamStar_comma(self, node: "ParamStar") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Parameters(self, node: "Parameters") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Parameters_params(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Parameters_params(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Parameters_star_arg(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Parameters_star_arg(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Parameters_kwonly_params(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Parameters_kwonly_params(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Parameters_star_kwarg(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Parameters_star_kwarg(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Parameters_posonly_params(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Parameters_posonly_params(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Parameters_posonly_ind(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Parameters_posonly_ind(self, node: "Parameters") -> None:
+        pass
+
+    @mark_no_op
+    def visit_ParenthesizedWhitespace(
+        self, node: "ParenthesizedWhitespace"
+    ) -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_ParenthesizedWhitespace_first_line(
+        self, node: "ParenthesizedWhitespace"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_ParenthesizedWhitespace_first_line(
+        self, node: "ParenthesizedWhitespace"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def visit_ParenthesizedWhitespace_empty_lines(
+        self, node: "ParenthesizedWhitespace"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_ParenthesizedWhitespace_empty_lines(
+        self, node: "ParenthesizedWhitespace"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def visit_ParenthesizedWhitespace_indent(
+        self, node: "ParenthesizedWhitespace"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_ParenthesizedWhitespace_indent(
         self, node: "ParenthesizedWhitespace"
     ) -> None:
         pass
@@ -4187,299 +4970,519 @@ class CSTTypedBaseFunctions:
         pass
 
     @mark_no_op
-    def visit_StarredDictElement_comma(self, node: "StarredDictElement") -> None:
+    def visit_StarredDictElement_comma(self, node: "StarredDictElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_StarredDictElement_comma(self, node: "StarredDictElement") -> None:
+        pass
+
+    @mark_no_op
+    def visit_StarredDictElement_whitespace_before_value(
+        self, node: "StarredDictElement"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_StarredDictElement_whitespace_before_value(
+        self, node: "StarredDictElement"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def visit_StarredElement(self, node: "StarredElement") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_StarredElement_value(self, node: "StarredElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_StarredElement_value(self, node: "StarredElement") -> None:
+        pass
+
+    @mark_no_op
+    def visit_StarredElement_comma(self, node: "StarredElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_StarredElement_comma(self, node: "StarredElement") -> None:
+        pass
+
+    @mark_no_op
+    def visit_StarredElement_lpar(self, node: "StarredElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_StarredElement_lpar(self, node: "StarredElement") -> None:
+        pass
+
+    @mark_no_op
+    def visit_StarredElement_rpar(self, node: "StarredElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_StarredElement_rpar(self, node: "StarredElement") -> None:
+        pass
+
+    @mark_no_op
+    def visit_StarredElement_whitespace_before_value(
+        self, node: "StarredElement"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def leave_StarredElement_whitespace_before_value(
+        self, node: "StarredElement"
+    ) -> None:
+        pass
+
+    @mark_no_op
+    def visit_Subscript(self, node: "Subscript") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Subscript_value(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Subscript_value(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Subscript_slice(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Subscript_slice(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Subscript_lbracket(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Subscript_lbracket(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Subscript_rbracket(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Subscript_rbracket(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Subscript_lpar(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Subscript_lpar(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Subscript_rpar(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Subscript_rpar(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Subscript_whitespace_after_value(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Subscript_whitespace_after_value(self, node: "Subscript") -> None:
+        pass
+
+    @mark_no_op
+    def visit_SubscriptElement(self, node: "SubscriptElement") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_SubscriptElement_slice(self, node: "SubscriptElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_SubscriptElement_slice(self, node: "SubscriptElement") -> None:
+        pass
+
+    @mark_no_op
+    def visit_SubscriptElement_comma(self, node: "SubscriptElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_SubscriptElement_comma(self, node: "SubscriptElement") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Subtract(self, node: "Subtract") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Subtract_whitespace_before(self, node: "Subtract") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Subtract_whitespace_before(self, node: "Subtract") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Subtract_whitespace_after(self, node: "Subtract") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Subtract_whitespace_after(self, node: "Subtract") -> None:
+        pass
+
+    @mark_no_op
+    def visit_SubtractAssign(self, node: "SubtractAssign") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_SubtractAssign_whitespace_before(self, node: "SubtractAssign") -> None:
+        pass
+
+    @mark_no_op
+    def leave_SubtractAssign_whitespace_before(self, node: "SubtractAssign") -> None:
+        pass
+
+    @mark_no_op
+    def visit_SubtractAssign_whitespace_after(self, node: "SubtractAssign") -> None:
+        pass
+
+    @mark_no_op
+    def leave_SubtractAssign_whitespace_after(self, node: "SubtractAssign") -> None:
+        pass
+
+    @mark_no_op
+    def visit_TrailingWhitespace(self, node: "TrailingWhitespace") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_TrailingWhitespace_whitespace(self, node: "TrailingWhitespace") -> None:
+        pass
+
+    @mark_no_op
+    def leave_TrailingWhitespace_whitespace(self, node: "TrailingWhitespace") -> None:
+        pass
+
+    @mark_no_op
+    def visit_TrailingWhitespace_comment(self, node: "TrailingWhitespace") -> None:
+        pass
+
+    @mark_no_op
+    def leave_TrailingWhitespace_comment(self, node: "TrailingWhitespace") -> None:
+        pass
+
+    @mark_no_op
+    def visit_TrailingWhitespace_newline(self, node: "TrailingWhitespace") -> None:
+        pass
+
+    @mark_no_op
+    def leave_TrailingWhitespace_newline(self, node: "TrailingWhitespace") -> None:
+        pass
+
+    @mark_no_op
+    def visit_Try(self, node: "Try") -> Optional[bool]:
+        pass
+
+    @mark_no_op
+    def visit_Try_body(self, node: "Try") -> None:
+        pass
+
+    @mark_no_op
+    def leave_Try_body(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def leave_StarredDictElement_comma(self, node: "StarredDictElement") -> None:
+    def visit_Try_handlers(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def visit_StarredDictElement_whitespace_before_value(
-        self, node: "StarredDictElement"
-    ) -> None:
+    def leave_Try_handlers(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def leave_StarredDictElement_whitespace_before_value(
-        self, node: "StarredDictElement"
-    ) -> None:
+    def visit_Try_orelse(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def visit_StarredElement(self, node: "StarredElement") -> Optional[bool]:
+    def leave_Try_orelse(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def visit_StarredElement_value(self, node: "StarredElement") -> None:
+    def visit_Try_finalbody(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def leave_StarredElement_value(self, node: "StarredElement") -> None:
+    def leave_Try_finalbody(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def visit_StarredElement_comma(self, node: "StarredElement") -> None:
+    def visit_Try_leading_lines(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def leave_StarredElement_comma(self, node: "StarredElement") -> None:
+    def leave_Try_leading_lines(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def visit_StarredElement_lpar(self, node: "StarredElement") -> None:
+    def visit_Try_whitespace_before_colon(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def leave_StarredElement_lpar(self, node: "StarredElement") -> None:
+    def leave_Try_whitespace_before_colon(self, node: "Try") -> None:
         pass
 
     @mark_no_op
-    def visit_StarredElement_rpar(self, node: "StarredElement") -> None:
+    def visit_TryStar(self, node: "TryStar") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def leave_StarredElement_rpar(self, node: "StarredElement") -> None:
+    def visit_TryStar_body(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def visit_StarredElement_whitespace_before_value(
-        self, node: "StarredElement"
-    ) -> None:
+    def leave_TryStar_body(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def leave_StarredElement_whitespace_before_value(
-        self, node: "StarredElement"
-    ) -> None:
+    def visit_TryStar_handlers(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def visit_Subscript(self, node: "Subscript") -> Optional[bool]:
+    def leave_TryStar_handlers(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def visit_Subscript_value(self, node: "Subscript") -> None:
+    def visit_TryStar_orelse(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def leave_Subscript_value(self, node: "Subscript") -> None:
+    def leave_TryStar_orelse(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def visit_Subscript_slice(self, node: "Subscript") -> None:
+    def visit_TryStar_finalbody(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def leave_Subscript_slice(self, node: "Subscript") -> None:
+    def leave_TryStar_finalbody(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def visit_Subscript_lbracket(self, node: "Subscript") -> None:
+    def visit_TryStar_leading_lines(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def leave_Subscript_lbracket(self, node: "Subscript") -> None:
+    def leave_TryStar_leading_lines(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def visit_Subscript_rbracket(self, node: "Subscript") -> None:
+    def visit_TryStar_whitespace_before_colon(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def leave_Subscript_rbracket(self, node: "Subscript") -> None:
+    def leave_TryStar_whitespace_before_colon(self, node: "TryStar") -> None:
         pass
 
     @mark_no_op
-    def visit_Subscript_lpar(self, node: "Subscript") -> None:
+    def visit_Tuple(self, node: "Tuple") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def leave_Subscript_lpar(self, node: "Subscript") -> None:
+    def visit_Tuple_elements(self, node: "Tuple") -> None:
         pass
 
     @mark_no_op
-    def visit_Subscript_rpar(self, node: "Subscript") -> None:
+    def leave_Tuple_elements(self, node: "Tuple") -> None:
         pass
 
     @mark_no_op
-    def leave_Subscript_rpar(self, node: "Subscript") -> None:
+    def visit_Tuple_lpar(self, node: "Tuple") -> None:
         pass
 
     @mark_no_op
-    def visit_Subscript_whitespace_after_value(self, node: "Subscript") -> None:
+    def leave_Tuple_lpar(self, node: "Tuple") -> None:
         pass
 
     @mark_no_op
-    def leave_Subscript_whitespace_after_value(self, node: "Subscript") -> None:
+    def visit_Tuple_rpar(self, node: "Tuple") -> None:
         pass
 
     @mark_no_op
-    def visit_SubscriptElement(self, node: "SubscriptElement") -> Optional[bool]:
+    def leave_Tuple_rpar(self, node: "Tuple") -> None:
         pass
 
     @mark_no_op
-    def visit_SubscriptElement_slice(self, node: "SubscriptElement") -> None:
+    def visit_TypeAlias(self, node: "TypeAlias") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def leave_SubscriptElement_slice(self, node: "SubscriptElement") -> None:
+    def visit_TypeAlias_name(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def visit_SubscriptElement_comma(self, node: "SubscriptElement") -> None:
+    def leave_TypeAlias_name(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def leave_SubscriptElement_comma(self, node: "SubscriptElement") -> None:
+    def visit_TypeAlias_value(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def visit_Subtract(self, node: "Subtract") -> Optional[bool]:
+    def leave_TypeAlias_value(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def visit_Subtract_whitespace_before(self, node: "Subtract") -> None:
+    def visit_TypeAlias_type_parameters(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def leave_Subtract_whitespace_before(self, node: "Subtract") -> None:
+    def leave_TypeAlias_type_parameters(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def visit_Subtract_whitespace_after(self, node: "Subtract") -> None:
+    def visit_TypeAlias_whitespace_after_type(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def leave_Subtract_whitespace_after(self, node: "Subtract") -> None:
+    def leave_TypeAlias_whitespace_after_type(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def visit_SubtractAssign(self, node: "SubtractAssign") -> Optional[bool]:
+    def visit_TypeAlias_whitespace_after_name(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def visit_SubtractAssign_whitespace_before(self, node: "SubtractAssign") -> None:
+    def leave_TypeAlias_whitespace_after_name(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def leave_SubtractAssign_whitespace_before(self, node: "SubtractAssign") -> None:
+    def visit_TypeAlias_whitespace_after_type_parameters(
+        self, node: "TypeAlias"
+    ) -> None:
         pass
 
     @mark_no_op
-    def visit_SubtractAssign_whitespace_after(self, node: "SubtractAssign") -> None:
+    def leave_TypeAlias_whitespace_after_type_parameters(
+        self, node: "TypeAlias"
+    ) -> None:
         pass
 
     @mark_no_op
-    def leave_SubtractAssign_whitespace_after(self, node: "SubtractAssign") -> None:
+    def visit_TypeAlias_whitespace_after_equals(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def visit_TrailingWhitespace(self, node: "TrailingWhitespace") -> Optional[bool]:
+    def leave_TypeAlias_whitespace_after_equals(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def visit_TrailingWhitespace_whitespace(self, node: "TrailingWhitespace") -> None:
+    def visit_TypeAlias_semicolon(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def leave_TrailingWhitespace_whitespace(self, node: "TrailingWhitespace") -> None:
+    def leave_TypeAlias_semicolon(self, node: "TypeAlias") -> None:
         pass
 
     @mark_no_op
-    def visit_TrailingWhitespace_comment(self, node: "TrailingWhitespace") -> None:
+    def visit_TypeParam(self, node: "TypeParam") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def leave_TrailingWhitespace_comment(self, node: "TrailingWhitespace") -> None:
+    def visit_TypeParam_param(self, node: "TypeParam") -> None:
         pass
 
     @mark_no_op
-    def visit_TrailingWhitespace_newline(self, node: "TrailingWhitespace") -> None:
+    def leave_TypeParam_param(self, node: "TypeParam") -> None:
         pass
 
     @mark_no_op
-    def leave_TrailingWhitespace_newline(self, node: "TrailingWhitespace") -> None:
+    def visit_TypeParam_comma(self, node: "TypeParam") -> None:
         pass
 
     @mark_no_op
-    def visit_Try(self, node: "Try") -> Optional[bool]:
+    def leave_TypeParam_comma(self, node: "TypeParam") -> None:
         pass
 
     @mark_no_op
-    def visit_Try_body(self, node: "Try") -> None:
+    def visit_TypeParameters(self, node: "TypeParameters") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def leave_Try_body(self, node: "Try") -> None:
+    def visit_TypeParameters_params(self, node: "TypeParameters") -> None:
         pass
 
     @mark_no_op
-    def visit_Try_handlers(self, node: "Try") -> None:
+    def leave_TypeParameters_params(self, node: "TypeParameters") -> None:
         pass
 
     @mark_no_op
-    def leave_Try_handlers(self, node: "Try") -> None:
+    def visit_TypeParameters_lbracket(self, node: "TypeParameters") -> None:
         pass
 
     @mark_no_op
-    def visit_Try_orelse(self, node: "Try") -> None:
+    def leave_TypeParameters_lbracket(self, node: "TypeParameters") -> None:
         pass
 
     @mark_no_op
-    def leave_Try_orelse(self, node: "Try") -> None:
+    def visit_TypeParameters_rbracket(self, node: "TypeParameters") -> None:
         pass
 
     @mark_no_op
-    def visit_Try_finalbody(self, node: "Try") -> None:
+    def leave_TypeParameters_rbracket(self, node: "TypeParameters") -> None:
         pass
 
     @mark_no_op
-    def leave_Try_finalbody(self, node: "Try") -> None:
+    def visit_TypeVar(self, node: "TypeVar") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_Try_leading_lines(self, node: "Try") -> None:
+    def visit_TypeVar_name(self, node: "TypeVar") -> None:
         pass
 
     @mark_no_op
-    def leave_Try_leading_lines(self, node: "Try") -> None:
+    def leave_TypeVar_name(self, node: "TypeVar") -> None:
         pass
 
     @mark_no_op
-    def visit_Try_whitespace_before_colon(self, node: "Try") -> None:
+    def visit_TypeVar_bound(self, node: "TypeVar") -> None:
         pass
 
     @mark_no_op
-    def leave_Try_whitespace_before_colon(self, node: "Try") -> None:
+    def leave_TypeVar_bound(self, node: "TypeVar") -> None:
         pass
 
     @mark_no_op
-    def visit_Tuple(self, node: "Tuple") -> Optional[bool]:
+    def visit_TypeVar_colon(self, node: "TypeVar") -> None:
         pass
 
     @mark_no_op
-    def visit_Tuple_elements(self, node: "Tuple") -> None:
+    def leave_TypeVar_colon(self, node: "TypeVar") -> None:
         pass
 
     @mark_no_op
-    def leave_Tuple_elements(self, node: "Tuple") -> None:
+    def visit_TypeVarTuple(self, node: "TypeVarTuple") -> Optional[bool]:
         pass
 
     @mark_no_op
-    def visit_Tuple_lpar(self, node: "Tuple") -> None:
+    def visit_TypeVarTuple_name(self, node: "TypeVarTuple") -> None:
         pass
 
     @mark_no_op
-    def leave_Tuple_lpar(self, node: "Tuple") -> None:
+    def leave_TypeVarTuple_name(self, node: "TypeVarTuple") -> None:
         pass
 
     @mark_no_op
-    def visit_Tuple_rpar(self, node: "Tuple") -> None:
+    def visit_TypeVarTuple_whitespace_after_star(self, node: "TypeVarTuple") -> None:
         pass
 
     @mark_no_op
-    def leave_Tuple_rpar(self, node: "Tuple") -> None:
+    def leave_TypeVarTuple_whitespace_after_star(self, node: "TypeVarTuple") -> None:
         pass
 
     @mark_no_op
@@ -4888,6 +5891,10 @@ class CSTTypedVisitorFunctions(CSTTypedBaseFunctions):
     def leave_ExceptHandler(self, original_node: "ExceptHandler") -> None:
         pass
 
+    @mark_no_op
+    def leave_ExceptStarHandler(self, original_node: "ExceptStarHandler") -> None:
+        pass
+
     @mark_no_op
     def leave_Expr(self, original_node: "Expr") -> None:
         pass
@@ -5042,6 +6049,74 @@ class CSTTypedVisitorFunctions(CSTTypedBaseFunctions):
     def leave_ListComp(self, original_node: "ListComp") -> None:
         pass
 
+    @mark_no_op
+    def leave_Match(self, original_node: "Match") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchAs(self, original_node: "MatchAs") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchCase(self, original_node: "MatchCase") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchClass(self, original_node: "MatchClass") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchKeywordElement(self, original_node: "MatchKeywordElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchList(self, original_node: "MatchList") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchMapping(self, original_node: "MatchMapping") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchMappingElement(self, original_node: "MatchMappingElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchOr(self, original_node: "MatchOr") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchOrElement(self, original_node: "MatchOrElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchPattern(self, original_node: "MatchPattern") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchSequence(self, original_node: "MatchSequence") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchSequenceElement(self, original_node: "MatchSequenceElement") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchSingleton(self, original_node: "MatchSingleton") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchStar(self, original_node: "MatchStar") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchTuple(self, original_node: "MatchTuple") -> None:
+        pass
+
+    @mark_no_op
+    def leave_MatchValue(self, original_node: "MatchValue") -> None:
+        pass
+
     @mark_no_op
     def leave_MatrixMultiply(self, original_node: "MatrixMultiply") -> None:
         pass
@@ -5118,6 +6193,10 @@ class CSTTypedVisitorFunctions(CSTTypedBaseFunctions):
     def leave_ParamSlash(self, original_node: "ParamSlash") -> None:
         pass
 
+    @mark_no_op
+    def leave_ParamSpec(self, original_node: "ParamSpec") -> None:
+        pass
+
     @mark_no_op
     def leave_ParamStar(self, original_node: "ParamStar") -> None:
         pass
@@ -5240,10 +6319,34 @@ class CSTTypedVisitorFunctions(CSTTypedBaseFunctions):
     def leave_Try(self, original_node: "Try") -> None:
         pass
 
+    @mark_no_op
+    def leave_TryStar(self, original_node: "TryStar") -> None:
+        pass
+
     @mark_no_op
     def leave_Tuple(self, original_node: "Tuple") -> None:
         pass
 
+    @mark_no_op
+    def leave_TypeAlias(self, original_node: "TypeAlias") -> None:
+        pass
+
+    @mark_no_op
+    def leave_TypeParam(self, original_node: "TypeParam") -> None:
+        pass
+
+    @mark_no_op
+    def leave_TypeParameters(self, original_node: "TypeParameters") -> None:
+        pass
+
+    @mark_no_op
+    def leave_TypeVar(self, original_node: "TypeVar") -> None:
+        pass
+
+    @mark_no_op
+    def leave_TypeVarTuple(self, original_node: "TypeVarTuple") -> None:
+        pass
+
     @mark_no_op
     def leave_UnaryOperation(self, original_node: "UnaryOperation") -> None:
         pass
@@ -5379,7 +6482,7 @@ class CSTTypedTransformerFunctions(CSTTypedBaseFunctions):
     @mark_no_op
     def leave_BitOr(
         self, original_node: "BitOr", updated_node: "BitOr"
-    ) -> "BaseBinaryOp":
+    ) -> Union["BaseBinaryOp", MaybeSentinel]:
         return updated_node
 
     @mark_no_op
@@ -5558,6 +6661,12 @@ class CSTTypedTransformerFunctions(CSTTypedBaseFunctions):
     ) -> Union["ExceptHandler", RemovalSentinel]:
         return updated_node
 
+    @mark_no_op
+    def leave_ExceptStarHandler(
+        self, original_node: "ExceptStarHandler", updated_node: "ExceptStarHandler"
+    ) -> Union["ExceptStarHandler", RemovalSentinel]:
+        return updated_node
+
     @mark_no_op
     def leave_Expr(
         self, original_node: "Expr", updated_node: "Expr"
@@ -5780,6 +6889,110 @@ class CSTTypedTransformerFunctions(CSTTypedBaseFunctions):
     ) -> "BaseExpression":
         return updated_node
 
+    @mark_no_op
+    def leave_Match(
+        self, original_node: "Match", updated_node: "Match"
+    ) -> Union["BaseStatement", RemovalSentinel]:
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchAs(
+        self, original_node: "MatchAs", updated_node: "MatchAs"
+    ) -> "MatchPattern":
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchCase(
+        self, original_node: "MatchCase", updated_node: "MatchCase"
+    ) -> Union["MatchCase", RemovalSentinel]:
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchClass(
+        self, original_node: "MatchClass", updated_node: "MatchClass"
+    ) -> "MatchPattern":
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchKeywordElement(
+        self, original_node: "MatchKeywordElement", updated_node: "MatchKeywordElement"
+    ) -> Union["MatchKeywordElement", RemovalSentinel]:
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchList(
+        self, original_node: "MatchList", updated_node: "MatchList"
+    ) -> "MatchPattern":
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchMapping(
+        self, original_node: "MatchMapping", updated_node: "MatchMapping"
+    ) -> "MatchPattern":
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchMappingElement(
+        self, original_node: "MatchMappingElement", updated_node: "MatchMappingElement"
+    ) -> Union["MatchMappingElement", RemovalSentinel]:
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchOr(
+        self, original_node: "MatchOr", updated_node: "MatchOr"
+    ) -> "MatchPattern":
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchOrElement(
+        self, original_node: "MatchOrElement", updated_node: "MatchOrElement"
+    ) -> Union["MatchOrElement", RemovalSentinel]:
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchPattern(
+        self, original_node: "MatchPattern", updated_node: "MatchPattern"
+    ) -> "MatchPattern":
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchSequence(
+        self, original_node: "MatchSequence", updated_node: "MatchSequence"
+    ) -> "MatchPattern":
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchSequenceElement(
+        self,
+        original_node: "MatchSequenceElement",
+        updated_node: "MatchSequenceElement",
+    ) -> Union["MatchSequenceElement", RemovalSentinel]:
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchSingleton(
+        self, original_node: "MatchSingleton", updated_node: "MatchSingleton"
+    ) -> "MatchPattern":
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchStar(
+        self, original_node: "MatchStar", updated_node: "MatchStar"
+    ) -> "MatchStar":
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchTuple(
+        self, original_node: "MatchTuple", updated_node: "MatchTuple"
+    ) -> "MatchPattern":
+        return updated_node
+
+    @mark_no_op
+    def leave_MatchValue(
+        self, original_node: "MatchValue", updated_node: "MatchValue"
+    ) -> "MatchPattern":
+        return updated_node
+
     @mark_no_op
     def leave_MatrixMultiply(
         self, original_node: "MatrixMultiply", updated_node: "MatrixMultiply"
@@ -5890,6 +7103,12 @@ class CSTTypedTransformerFunctions(CSTTypedBaseFunctions):
     ) -> Union["ParamSlash", MaybeSentinel]:
         return updated_node
 
+    @mark_no_op
+    def leave_ParamSpec(
+        self, original_node: "ParamSpec", updated_node: "ParamSpec"
+    ) -> "ParamSpec":
+        return updated_node
+
     @mark_no_op
     def leave_ParamStar(
         self, original_node: "ParamStar", updated_node: "ParamStar"
@@ -6068,12 +7287,48 @@ class CSTTypedTransformerFunctions(CSTTypedBaseFunctions):
     ) -> Union["BaseStatement", RemovalSentinel]:
         return updated_node
 
+    @mark_no_op
+    def leave_TryStar(
+        self, original_node: "TryStar", updated_node: "TryStar"
+    ) -> Union["BaseStatement", RemovalSentinel]:
+        return updated_node
+
     @mark_no_op
     def leave_Tuple(
         self, original_node: "Tuple", updated_node: "Tuple"
     ) -> "BaseExpression":
         return updated_node
 
+    @mark_no_op
+    def leave_TypeAlias(
+        self, original_node: "TypeAlias", updated_node: "TypeAlias"
+    ) -> Union["BaseSmallStatement", RemovalSentinel]:
+        return updated_node
+
+    @mark_no_op
+    def leave_TypeParam(
+        self, original_node: "TypeParam", updated_node: "TypeParam"
+    ) -> Union["TypeParam", RemovalSentinel]:
+        return updated_node
+
+    @mark_no_op
+    def leave_TypeParameters(
+        self, original_node: "TypeParameters", updated_node: "TypeParameters"
+    ) -> "TypeParameters":
+        return updated_node
+
+    @mark_no_op
+    def leave_TypeVar(
+        self, original_node: "TypeVar", updated_node: "TypeVar"
+    ) -> "TypeVar":
+        return updated_node
+
+    @mark_no_op
+    def leave_TypeVarTuple(
+        self, original_node: "TypeVarTuple", updated_node: "TypeVarTuple"
+    ) -> "TypeVarTuple":
+        return updated_node
+
     @mark_no_op
     def leave_UnaryOperation(
         self, original_node: "UnaryOperation", updated_node: "UnaryOperation"
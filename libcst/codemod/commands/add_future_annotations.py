@@ -0,0 +1,108 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import List, Optional
+
+import libcst
+from libcst.codemod import VisitorBasedCodemodCommand
+from libcst.codemod.visitors import AddImportsVisitor
+from libcst.helpers import get_full_name_for_node, resolve_annotation
+
+
+def _unquote(expression: libcst.BaseExpression) -> Optional[libcst.BaseExpression]:
+    """
+    Returns the expression inside of a string literal, or ``None`` if ``expression``
+    isn't a string or doesn't contain a valid expression.
+    """
+    if not isinstance(expression, (libcst.SimpleString, libcst.ConcatenatedString)):
+        return None
+    try:
+        resolved = resolve_annotation(libcst.Annotation(annotation=expression))
+    except libcst.ParserSyntaxError:
+        return None
+    if resolved is expression:
+        return None
+    # Parsed annotations are cached, so each use needs its own copy.
+    return resolved.deep_clone()
+
+
+class _UnquoteSubscriptsTransformer(libcst.CSTTransformer):
+    """
+    Removes quotes from strings used as type arguments, such as the ``"Foo"`` in
+    ``List["Foo"]``.
+    """
+
+    def visit_Subscript(self, node: libcst.Subscript) -> bool:
+        # The arguments of ``Literal`` are values, not types.
+        name = get_full_name_for_node(node.value)
+        return name is None or name.split(".")[-1] != "Literal"
+
+    def leave_Subscript(
+        self, original_node: libcst.Subscript, updated_node: libcst.Subscript
+    ) -> libcst.Subscript:
+        name = get_full_name_for_node(updated_node.value)
+        short_name = name.split(".")[-1] if name is not None else None
+        if short_name == "Literal":
+            return updated_node
+        elements: List[libcst.SubscriptElement] = []
+        for i, element in enumerate(updated_node.slice):
+            index = element.slice
+            # Only the first argument of ``Annotated`` is a type.
+            is_type = short_name != "Annotated" or i == 0
+            if is_type and isinstance(index, libcst.Index):
+                value = _unquote(index.value)
+                if value is not None:
+                    index = index.with_changes(value=value)
+                    element = element.with_changes(slice=index)
+            elements.append(element)
+        return updated_node.with_changes(slice=elements)
+
+
+class AddFutureAnnotationsCommand(VisitorBasedCodemodCommand):
+    """
+    Adds ``from __future__ import annotations`` to a module and removes the quotes
+    from string annotations of function parameters and return values, including
+    strings used as type arguments such as the ``"Foo"`` in ``List["Foo"]``. Once
+    the import is in place, annotations aren't evaluated when the function is
+    defined, so forward references don't need to be quoted anymore.
+
+    Strings inside of ``Literal[...]`` and the metadata of ``Annotated[...]`` are
+    values rather than types, so they're kept. Strings that don't contain a valid
+    expression are kept too.
+    """
+
+    DESCRIPTION: str = (
+        "Adds 'from __future__ import annotations' and unquotes function annotations."
+    )
+
+    def visit_Module(self, node: libcst.Module) -> None:
+        AddImportsVisitor.add_needed_import(self.context, "__future__", "annotations")
+
+    @staticmethod
+    def _unquote_annotation(annotation: libcst.Annotation) -> libcst.Annotation:
+        expression = _unquote(annotation.annotation)
+        if expression is None:
+            expression = annotation.annotation
+        expression = expression.visit(_UnquoteSubscriptsTransformer())
+        return annotation.with_changes(annotation=expression)
+
+    def leave_Param(
+        self, original_node: libcst.Param, updated_node: libcst.Param
+    ) -> libcst.Param:
+        annotation = updated_node.annotation
+        if annotation is None:
+            return updated_node
+        return updated_node.with_changes(
+            annotation=self._unquote_annotation(annotation)
+        )
+
+    def leave_FunctionDef(
+        self, original_node: libcst.FunctionDef, updated_node: libcst.FunctionDef
+    ) -> libcst.FunctionDef:
+        returns = updated_node.returns
+        if returns is None:
+            return updated_node
+        return updated_node.with_changes(returns=self._unquote_annotation(returns))
@@ -5,7 +5,8 @@
 #
 # pyre-strict
 import re
-from typing import Callable, cast
+from dataclasses import dataclass
+from typing import Dict, List, Optional, Sequence, Union, cast
 
 import libcst as cst
 import libcst.matchers as m
@@ -15,28 +16,94 @@ from libcst.codemod import VisitorBasedCodemodCommand
 USE_FSTRING_SIMPLE_EXPRESSION_MAX_LENGTH = 30
 
 
+# A conversion specifier such as ``%s``, ``%(name)r`` or ``%-8.2f``.
+_SPECIFIER_RE = re.compile(
+    r"%(?:\((?P<key>[^()]*)\))?(?P<flags>[-+ 0]*)(?P<width>\d*)"
+    r"(?:\.(?P<precision>\d+))?(?P<type>.?)"
+)
+
+
+@dataclass(frozen=True)
+class _Specifier:
+    key: Optional[str]
+    #: The conversion to apply to the value, such as ``"r"`` for ``%r``.
+    conversion: Optional[str]
+    format_spec: str
+    #: Whether the value needs to be passed through ``int()`` first, like ``%d`` does.
+    integer: bool
+
+
 def _match_simple_string(node: cst.CSTNode) -> bool:
-    if isinstance(node, cst.SimpleString) and not node.prefix.lower().startswith("b"):
+    if isinstance(node, cst.SimpleString) and not node.is_bytes:
         # SimpleString can be a bytes and fstring don't support bytes
-        return re.fullmatch("[^%]*(%s[^%]*)+", node.raw_value) is not None
+        return "%" in node.raw_value
     return False
 
 
-def _gen_match_simple_expression(module: cst.Module) -> Callable[[cst.CSTNode], bool]:
-    def _match_simple_expression(node: cst.CSTNode) -> bool:
-        # either each element in Tuple is simple expression or the entire expression is simple.
-        if (
-            isinstance(node, cst.Tuple)
-            and all(
-                len(module.code_for_node(elm.value))
-                < USE_FSTRING_SIMPLE_EXPRESSION_MAX_LENGTH
-                for elm in node.elements
-            )
-        ) or len(module.code_for_node(node)) < USE_FSTRING_SIMPLE_EXPRESSION_MAX_LENGTH:
-            return True
-        return False
+def _numeric_spec(flags: str, width: str) -> str:
+    align = "<" if "-" in flags else ""
+    sign = "+" if "+" in flags else " " if " " in flags else ""
+    # Like with the % operator, zero padding is ignored for left-aligned values.
+    zero = "0" if "0" in flags and not align else ""
+    return f"{align}{sign}{zero}{width}"
+
+
+def _parse_specifier(match: "re.Match[str]") -> Optional[_Specifier]:
+    key = match.group("key")
+    flags = match.group("flags")
+    width = match.group("width")
+    precision = match.group("precision")
+    type_ = match.group("type")
+    if type_ in ("s", "r"):
+        if flags not in ("", "-"):
+            return None
+        align = ("<" if flags else ">") if width else ""
+        spec = align + width + (f".{precision}" if precision is not None else "")
+        if type_ == "s" and not spec:
+            return _Specifier(key, None, "", False)
+        return _Specifier(key, type_, spec, False)
+    if type_ in ("d", "i"):
+        if precision is not None:
+            return None
+        spec = _numeric_spec(flags, width)
+        return _Specifier(key, None, f"{spec}d" if spec else "", True)
+    if type_ == "f":
+        spec = _numeric_spec(flags, width)
+        if precision is not None:
+            spec += f".{precision}"
+        return _Specifier(key, None, f"{spec}f", False)
+    return None
+
+
+def _parse_format(value: str) -> Optional[List[Union[str, _Specifier]]]:
+    """
+    Splits a %-format string into text and conversion specifiers, or returns ``None``
+    if it uses anything that doesn't have an f-string equivalent.
+    """
+    parts: List[Union[str, _Specifier]] = []
+    text = ""
+    position = 0
+    for match in _SPECIFIER_RE.finditer(value):
+        text += value[position : match.start()]
+        position = match.end()
+        if match.group() == "%%":
+            text += "%"
+            continue
+        specifier = _parse_specifier(match)
+        if specifier is None:
+            return None
+        if text:
+            parts.append(text)
+            text = ""
+        parts.append(specifier)
+    text += value[position:]
+    if text:
+        parts.append(text)
+    return parts
+
 
-    return _match_simple_expression
+def _is_simple_expression(module: cst.Module, node: cst.BaseExpression) -> bool:
+    return len(module.code_for_node(node)) < USE_FSTRING_SIMPLE_EXPRESSION_MAX_LENGTH
 
 
 class EscapeStringQuote(cst.CSTTransformer):
@@ -66,8 +133,81 @@ class EscapeStringQuote(cst.CSTTransformer):
 
 
 class ConvertPercentFormatStringCommand(VisitorBasedCodemodCommand):
+    """
+    Converts ``"..." % args`` expressions to f-strings. The ``%s``, ``%r``, ``%d``,
+    ``%i`` and ``%f`` conversions are supported, along with their width, precision
+    and flags, and ``%%``. The arguments can be a tuple, a single value, or a dict
+    literal with string keys for conversions such as ``%(name)s``.
+
+    The expression is left alone if the format string uses any other conversion, if
+    the number of arguments doesn't match, or if an argument is too long or can't be
+    put inside of an f-string. A dict argument is also left alone if one of its
+    values isn't used, or if a value that isn't a plain name is used more than
+    once, since that would change how often it's evaluated.
+    """
+
     DESCRIPTION: str = "Converts simple % style string format to f-string."
 
+    def _values(
+        self, specifiers: Sequence[_Specifier], expr: cst.BaseExpression
+    ) -> Optional[List[cst.BaseExpression]]:
+        """
+        Returns the value to use for each of the specifiers, or ``None`` if they
+        can't be matched up with the arguments.
+        """
+        keys = [specifier.key for specifier in specifiers]
+        if all(key is None for key in keys):
+            if not isinstance(expr, cst.Tuple):
+                return [expr] if len(specifiers) == 1 else None
+            if len(expr.elements) != len(specifiers):
+                return None
+            values: List[cst.BaseExpression] = []
+            for element in expr.elements:
+                if not isinstance(element, cst.Element):
+                    return None
+                values.append(element.value)
+            return values
+        if any(key is None for key in keys) or not isinstance(expr, cst.Dict):
+            return None
+        mapping: Dict[str, cst.BaseExpression] = {}
+        for element in expr.elements:
+            if not isinstance(element, cst.DictElement) or not isinstance(
+                element.key, cst.SimpleString
+            ):
+                return None
+            key = element.key.evaluated_value
+            if not isinstance(key, str) or key in mapping:
+                return None
+            mapping[key] = element.value
+        if set(mapping) != set(keys):
+            return None
+        for key, value in mapping.items():
+            if keys.count(key) > 1 and not isinstance(value, cst.Name):
+                return None
+        return [mapping[cast(str, key)] for key in keys]
+
+    def _expression(
+        self, value: cst.BaseExpression, quote: str
+    ) -> Optional[cst.BaseExpression]:
+        """
+        Returns ``value`` in a form that can be put inside of an f-string with the
+        given quotes, or ``None`` if that isn't possible.
+        """
+        if not _is_simple_expression(self.module, value):
+            return None
+        if not value.lpar and isinstance(value, (cst.Lambda, cst.NamedExpr, cst.Yield)):
+            return None
+        try:
+            expression = cast(cst.BaseExpression, value.visit(EscapeStringQuote(quote)))
+        except Exception:
+            return None
+        code = self.module.code_for_node(expression)
+        if code.startswith("{") or any(
+            char in code for char in (quote[0], "\\", "#", "\n", "\r")
+        ):
+            return None
+        return expression
+
     def leave_BinaryOperation(
         self, original_node: cst.BinaryOperation, updated_node: cst.BinaryOperation
     ) -> cst.BaseExpression:
@@ -77,50 +217,46 @@ class ConvertPercentFormatStringCommand(VisitorBasedCodemodCommand):
             m.BinaryOperation(
                 left=m.MatchIfTrue(_match_simple_string),
                 operator=m.Modulo(),
-                right=m.SaveMatchedNode(
-                    m.MatchIfTrue(_gen_match_simple_expression(self.module)), expr_key,
-                ),
+                right=m.SaveMatchedNode(m.DoNotCare(), expr_key),
             ),
         )
+        if not extracts:
+            return original_node
+
+        expr = cast(cst.BaseExpression, extracts[expr_key])
+        simple_string = cst.ensure_type(original_node.left, cst.SimpleString)
+        innards = simple_string.raw_value.replace("{", "{{").replace("}", "}}")
+        tokens = _parse_format(innards)
+        if tokens is None:
+            return original_node
+        specifiers = [token for token in tokens if isinstance(token, _Specifier)]
+        if not specifiers:
+            return original_node
+        values = self._values(specifiers, expr)
+        if values is None:
+            return original_node
 
-        if extracts:
-            expr = extracts[expr_key]
-            parts = []
-            simple_string = cst.ensure_type(original_node.left, cst.SimpleString)
-            innards = simple_string.raw_value.replace("{", "{{").replace("}", "}}")
-            tokens = innards.split("%s")
-            token = tokens[0]
-            if len(token) > 0:
+        parts: List[cst.BaseFormattedStringContent] = []
+        remaining = iter(values)
+        for token in tokens:
+            if isinstance(token, str):
                 parts.append(cst.FormattedStringText(value=token))
-            expressions = (
-                [elm.value for elm in expr.elements]
-                if isinstance(expr, cst.Tuple)
-                else [expr]
+                continue
+            expression = self._expression(next(remaining), simple_string.quote)
+            if expression is None:
+                return original_node
+            if token.integer:
+                expression = cst.Call(func=cst.Name("int"), args=[cst.Arg(expression)])
+            parts.append(
+                cst.FormattedStringExpression(
+                    expression=expression,
+                    conversion=token.conversion,
+                    format_spec=[cst.FormattedStringText(value=token.format_spec)]
+                    if token.format_spec
+                    else None,
+                )
             )
-            escape_transformer = EscapeStringQuote(simple_string.quote)
-            i = 1
-            while i < len(tokens):
-                if i - 1 >= len(expressions):
-                    # the %-string doesn't come with same number of elements in tuple
-                    return original_node
-                try:
-                    parts.append(
-                        cst.FormattedStringExpression(
-                            expression=cast(
-                                cst.BaseExpression,
-                                expressions[i - 1].visit(escape_transformer),
-                            )
-                        )
-                    )
-                except Exception:
-                    return original_node
-                token = tokens[i]
-                if len(token) > 0:
-                    parts.append(cst.FormattedStringText(value=token))
-                i += 1
-            start = f"f{simple_string.prefix}{simple_string.quote}"
-            return cst.FormattedString(
-                parts=parts, start=start, end=simple_string.quote
-            )
-
-        return original_node
+        # There's no such thing as a unicode f-string.
+        prefix = simple_string.prefix.replace("u", "")
+        start = f"f{prefix}{simple_string.quote}"
+        return cst.FormattedString(parts=parts, start=start, end=simple_string.quote)
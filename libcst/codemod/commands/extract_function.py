@@ -0,0 +1,322 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import argparse
+from typing import Dict, List, Sequence, Set, Tuple, Union
+
+import libcst
+from libcst._nodes.whitespace import NEWLINE_RE
+from libcst.codemod import CodemodCommand, CodemodContext, SkipFile
+from libcst.helpers import iter_nodes
+from libcst.metadata import (
+    Assignment,
+    BaseAssignment,
+    ClassScope,
+    CodePosition,
+    GlobalScope,
+    PositionProvider,
+    ScopeProvider,
+)
+
+
+_BlockT = Union[libcst.Module, libcst.IndentedBlock]
+
+
+class ExtractFunctionCommand(CodemodCommand):
+    """
+    Moves the statements covered by a byte range into a new module-level function,
+    and replaces them with a call to that function. The new function is placed right
+    above the top-level statement that contained the selection.
+
+    Any local variable that is read by the extracted statements but assigned outside
+    of them becomes a parameter of the new function, and so does any variable that
+    the extracted statements might read before assigning it, such as the target of
+    an augmented assignment or a variable that is only assigned under a condition.
+    Any variable assigned by the extracted statements that is read afterwards is
+    returned from the new function and assigned back at the call site. At the module
+    or class level, every variable they assign is returned and assigned back, so it
+    stays a global or class attribute. Module-level variables and builtins are
+    accessed directly.
+
+    The selection must cover one or more complete statements of a single block.
+    Statements that would change meaning when moved into a function (``return``,
+    ``yield``, ``await``, ``global``, ``nonlocal`` and ``break`` or ``continue`` that
+    target a loop outside of the selection) can't be extracted. In either case the
+    file is skipped.
+    """
+
+    DESCRIPTION: str = "Extracts a range of statements into a new function."
+
+    METADATA_DEPENDENCIES = (PositionProvider, ScopeProvider)
+
+    @staticmethod
+    def add_args(parser: argparse.ArgumentParser) -> None:
+        parser.add_argument(
+            "--start-offset",
+            dest="start_offset",
+            metavar="OFFSET",
+            help="Byte offset where the statements to extract start.",
+            type=int,
+            required=True,
+        )
+        parser.add_argument(
+            "--end-offset",
+            dest="end_offset",
+            metavar="OFFSET",
+            help="Byte offset (exclusive) where the statements to extract end.",
+            type=int,
+            required=True,
+        )
+        parser.add_argument(
+            "--name",
+            dest="name",
+            metavar="NAME",
+            help="Name of the new function.",
+            type=str,
+            required=True,
+        )
+
+    def __init__(
+        self, context: CodemodContext, start_offset: int, end_offset: int, name: str
+    ) -> None:
+        super().__init__(context)
+        self.start_offset = start_offset
+        self.end_offset = end_offset
+        self.name = name
+
+    def _position_for_offset(self, offset: int) -> CodePosition:
+        source = self.module.bytes
+        if offset < 0 or offset > len(source):
+            raise SkipFile(f"Offset {offset} is outside of the module.")
+        segments = NEWLINE_RE.split(source[:offset].decode(self.module.encoding))
+        return CodePosition(len(segments), len(segments[-1]))
+
+    def _span(self, node: libcst.CSTNode) -> Tuple[Tuple[int, int], Tuple[int, int]]:
+        code_range = self.get_metadata(PositionProvider, node)
+        return (
+            (code_range.start.line, code_range.start.column),
+            (code_range.end.line, code_range.end.column),
+        )
+
+    def _find_selection(self, tree: libcst.Module) -> Tuple[_BlockT, int, int, int]:
+        """
+        Returns the block containing the selected statements, the start and end index
+        of the selected statements in that block, and the index of the top-level
+        statement containing them.
+        """
+        start = self._position_for_offset(self.start_offset)
+        end = self._position_for_offset(self.end_offset)
+        selection = ((start.line, start.column), (end.line, end.column))
+
+        for block in iter_nodes(tree):
+            if not isinstance(block, (libcst.Module, libcst.IndentedBlock)):
+                continue
+            selected: List[int] = []
+            partial = False
+            for i, stmt in enumerate(block.body):
+                stmt_start, stmt_end = self._span(stmt)
+                if selection[0] <= stmt_start and stmt_end <= selection[1]:
+                    selected.append(i)
+                elif stmt_start < selection[1] and selection[0] < stmt_end:
+                    partial = True
+            if not selected:
+                # The selection may still be inside of a partially covered statement.
+                continue
+            if partial:
+                raise SkipFile("Selection only covers part of a statement.")
+            first, last = selected[0], selected[-1] + 1
+            first_start, _ = self._span(block.body[first])
+            for top_index, top in enumerate(tree.body):
+                top_start, top_end = self._span(top)
+                if top_start <= first_start < top_end:
+                    return (block, first, last, top_index)
+            raise Exception("Logic error, statement is not inside of the module!")
+        raise SkipFile("Selection doesn't cover any complete statements.")
+
+    def _check_extractable(self, node: libcst.CSTNode, in_loop: bool) -> None:
+        if isinstance(node, (libcst.FunctionDef, libcst.Lambda, libcst.ClassDef)):
+            # Anything inside of these is evaluated in its own scope.
+            return
+        if isinstance(
+            node,
+            (libcst.Return, libcst.Yield, libcst.Await, libcst.Global, libcst.Nonlocal),
+        ):
+            raise SkipFile(
+                f"Can't extract a '{type(node).__name__}' into a new function."
+            )
+        if isinstance(node, (libcst.For, libcst.With)) and node.asynchronous:
+            raise SkipFile("Can't extract asynchronous statements into a new function.")
+        if isinstance(node, (libcst.Break, libcst.Continue)) and not in_loop:
+            raise SkipFile(
+                f"Can't extract a '{type(node).__name__}' without its enclosing loop."
+            )
+        for child in node.children:
+            self._check_extractable(
+                child, in_loop or isinstance(node, (libcst.For, libcst.While))
+            )
+
+    @staticmethod
+    def _is_binding(assignment: BaseAssignment) -> bool:
+        # Keyword arguments are recorded as assignments by the scope provider, but
+        # they don't bind anything in the scope they appear in.
+        return isinstance(assignment, Assignment) and not isinstance(
+            assignment.node, libcst.Arg
+        )
+
+    def _analyze(
+        self, statements: Sequence[libcst.BaseStatement]
+    ) -> Tuple[List[str], List[str]]:
+        """
+        Returns the names that need to be passed into the new function and the names
+        it needs to return, both in order of first appearance.
+        """
+        selected_nodes: Set[libcst.CSTNode] = set()
+        for stmt in statements:
+            selected_nodes.update(iter_nodes(stmt))
+        order = {node: i for i, node in enumerate(iter_nodes(self.module))}
+
+        scope = self.get_metadata(ScopeProvider, statements[0])
+        params: Dict[str, int] = {}
+        returns: Dict[str, int] = {}
+
+        def add(names: Dict[str, int], name: str, node: libcst.CSTNode) -> None:
+            position = order[node]
+            names[name] = min(names.get(name, position), position)
+
+        current = scope
+        while current is not None and not isinstance(current, GlobalScope):
+            for assignment in current.assignments:
+                if not self._is_binding(assignment):
+                    continue
+                if assignment.node in selected_nodes:
+                    continue
+                for access in assignment.references:
+                    if access.node in selected_nodes:
+                        add(params, assignment.name, access.node)
+            current = current.parent
+
+        if scope is not None:
+            # Names bound at the module or class level are attributes of the module
+            # or class, so they have to stay bound there even if nothing reads them
+            # later.
+            rebind_all = isinstance(scope, (GlobalScope, ClassScope))
+            bound_outside = {
+                assignment.name
+                for assignment in scope.assignments
+                if self._is_binding(assignment)
+                and assignment.node not in selected_nodes
+            }
+            # Augmented assignments read their target before writing it.
+            augmented = {
+                node.target.value
+                for node in selected_nodes
+                if isinstance(node, libcst.AugAssign)
+                and isinstance(node.target, libcst.Name)
+                and self.get_metadata(ScopeProvider, node) is scope
+            }
+            for assignment in scope.assignments:
+                if not self._is_binding(assignment):
+                    continue
+                if assignment.node not in selected_nodes:
+                    continue
+                name = assignment.name
+                if rebind_all or any(
+                    access.node not in selected_nodes
+                    for access in assignment.references
+                ):
+                    add(returns, name, assignment.node)
+                # The selection might not assign the name, for example if it's
+                # assigned under a condition, so the value from before has to be
+                # passed in.
+                if name in bound_outside and (name in returns or name in augmented):
+                    add(params, name, assignment.node)
+
+        return (
+            sorted(params, key=lambda name: params[name]),
+            sorted(returns, key=lambda name: returns[name]),
+        )
+
+    @staticmethod
+    def _names(names: Sequence[str]) -> libcst.BaseExpression:
+        if len(names) == 1:
+            return libcst.Name(names[0])
+        return libcst.Tuple(
+            [libcst.Element(libcst.Name(name)) for name in names], lpar=[], rpar=[]
+        )
+
+    def transform_module_impl(self, tree: libcst.Module) -> libcst.Module:
+        global_scope = self.get_metadata(ScopeProvider, tree)
+        if global_scope is not None and any(
+            isinstance(assignment, Assignment) for assignment in global_scope[self.name]
+        ):
+            raise SkipFile(f"'{self.name}' is already defined in this module.")
+
+        block, first, last, top_index = self._find_selection(tree)
+        statements = block.body[first:last]
+        for stmt in statements:
+            self._check_extractable(stmt, in_loop=False)
+        params, returns = self._analyze(statements)
+
+        # Build the new function, leaving any comments above the extracted statements
+        # at the call site.
+        body: List[libcst.BaseStatement] = [
+            statements[0].with_changes(leading_lines=()),
+            *statements[1:],
+        ]
+        if returns:
+            body.append(
+                libcst.SimpleStatementLine([libcst.Return(self._names(returns))])
+            )
+        function = libcst.FunctionDef(
+            name=libcst.Name(self.name),
+            params=libcst.Parameters([libcst.Param(libcst.Name(p)) for p in params]),
+            body=libcst.IndentedBlock(body),
+        )
+
+        call = libcst.Call(
+            libcst.Name(self.name), [libcst.Arg(libcst.Name(p)) for p in params]
+        )
+        call_statement = libcst.SimpleStatementLine(
+            [
+                libcst.Assign([libcst.AssignTarget(self._names(returns))], call)
+                if returns
+                else libcst.Expr(call)
+            ],
+            leading_lines=statements[0].leading_lines,
+        )
+        new_block = block.with_changes(
+            body=[*block.body[:first], call_statement, *block.body[last:]]
+        )
+        if isinstance(block, libcst.Module):
+            tree = new_block
+        else:
+            tree = tree.deep_replace(block, new_block)
+
+        # Insert the function above the top-level statement containing the selection,
+        # separated from its neighbors by two blank lines. Comments stay attached to
+        # the statement they were originally above.
+        top = tree.body[top_index]
+        blank_count = 0
+        while (
+            blank_count < len(top.leading_lines)
+            and top.leading_lines[blank_count].comment is None
+        ):
+            blank_count += 1
+        blank_lines = [libcst.EmptyLine(indent=False), libcst.EmptyLine(indent=False)]
+        function = function.with_changes(
+            leading_lines=blank_lines if top_index > 0 else ()
+        )
+        top = top.with_changes(
+            leading_lines=[*blank_lines, *top.leading_lines[blank_count:]]
+        )
+        return tree.with_changes(
+            body=[
+                *tree.body[:top_index],
+                function,
+                top,
+                *tree.body[top_index + 1 :],
+            ]
+        )
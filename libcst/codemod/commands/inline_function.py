@@ -0,0 +1,421 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import argparse
+from typing import Dict, List, Mapping, Optional, Sequence, Set
+
+import libcst
+from libcst.codemod import CodemodContext, SkipFile, VisitorBasedCodemodCommand
+from libcst.helpers import iter_nodes
+from libcst.metadata import Assignment, ParentNodeProvider, ScopeProvider
+
+
+# Expressions that can be substituted anywhere without changing how the surrounding
+# expression is parsed.
+_ATOMS = (
+    libcst.Name,
+    libcst.BaseNumber,
+    libcst.BaseString,
+    libcst.Ellipsis,
+    libcst.Call,
+    libcst.Attribute,
+    libcst.Subscript,
+    libcst.List,
+    libcst.Set,
+    libcst.Dict,
+    libcst.ListComp,
+    libcst.SetComp,
+    libcst.DictComp,
+    libcst.GeneratorExp,
+)
+
+# Expressions that can be evaluated ahead of time without changing their meaning.
+_PURE = (libcst.Name, libcst.BaseNumber, libcst.SimpleString, libcst.Ellipsis)
+
+# Nodes whose children are evaluated conditionally, repeatedly or in another scope,
+# so arguments to calls inside of them can't be hoisted into temporaries.
+_NO_HOIST = (
+    libcst.Lambda,
+    libcst.IfExp,
+    libcst.BooleanOperation,
+    libcst.ListComp,
+    libcst.SetComp,
+    libcst.DictComp,
+    libcst.GeneratorExp,
+)
+
+
+class _SubstituteNames(libcst.CSTTransformer):
+    def __init__(
+        self, replacements: Mapping[libcst.Name, libcst.BaseExpression]
+    ) -> None:
+        super().__init__()
+        self.replacements = replacements
+
+    def leave_Name(
+        self, original_node: libcst.Name, updated_node: libcst.Name
+    ) -> libcst.BaseExpression:
+        return self.replacements.get(original_node, updated_node)
+
+
+class InlineFunctionCommand(VisitorBasedCodemodCommand):
+    """
+    Replaces calls to a module-level function with the expression that the function
+    returns, substituting its parameters with the arguments passed at each call site.
+    The function definition itself is left in place.
+
+    Only functions whose body consists of a single ``return`` statement (optionally
+    preceded by a docstring) can be inlined, since the result must be an expression.
+    Functions with multiple ``return`` statements, decorators, ``*args`` or
+    ``**kwargs`` are skipped.
+
+    Arguments that are names or literals are substituted directly. Any other argument
+    may have side effects, so it's bound to a temporary variable right before the
+    statement containing the call. That keeps it evaluated exactly once, but it is
+    evaluated before the rest of that statement. Call sites where this isn't
+    possible, where the arguments don't match the parameters, or where one of the
+    names used by the function is shadowed are left alone.
+    """
+
+    DESCRIPTION: str = "Inlines calls to a single-expression function."
+
+    METADATA_DEPENDENCIES = (ParentNodeProvider, ScopeProvider)
+
+    @staticmethod
+    def add_args(parser: argparse.ArgumentParser) -> None:
+        parser.add_argument(
+            "--name",
+            dest="name",
+            metavar="NAME",
+            help="Name of the module-level function to inline.",
+            type=str,
+            required=True,
+        )
+
+    def __init__(self, context: CodemodContext, name: str) -> None:
+        super().__init__(context)
+        self.name = name
+        self.function: Optional[libcst.FunctionDef] = None
+        self.value: Optional[libcst.BaseExpression] = None
+        # Maps each parameter name to the Name nodes in ``self.value`` that refer to
+        # that parameter.
+        self.references: Dict[str, List[libcst.Name]] = {}
+        # Names used in ``self.value`` that refer to module-level symbols or builtins
+        # (along with the name of the function itself), and names that are bound
+        # inside of ``self.value``.
+        self.free_names: Set[str] = set()
+        self.bound_names: Set[str] = set()
+        self.used_names: Set[str] = set()
+        self.in_function = False
+        # Temporaries that need to be assigned before a statement, keyed by the
+        # original statement and then by the updated one once it has been visited.
+        self.pending: Dict[libcst.SimpleStatementLine, List[libcst.Assign]] = {}
+        self.hoisted: Dict[
+            libcst.SimpleStatementLine, List[libcst.SimpleStatementLine]
+        ] = {}
+
+    def _count_returns(self, node: libcst.CSTNode) -> int:
+        if isinstance(node, (libcst.FunctionDef, libcst.Lambda, libcst.ClassDef)):
+            return 0
+        if isinstance(node, (libcst.Yield, libcst.Await)):
+            raise SkipFile(f"'{self.name}' isn't a regular function.")
+        return int(isinstance(node, libcst.Return)) + sum(
+            self._count_returns(child) for child in node.children
+        )
+
+    def _return_value(self, function: libcst.FunctionDef) -> libcst.BaseExpression:
+        if function.decorators or function.asynchronous is not None:
+            raise SkipFile(f"'{self.name}' can't be inlined.")
+        params = function.params
+        if isinstance(params.star_arg, libcst.Param) or params.star_kwarg is not None:
+            raise SkipFile(f"'{self.name}' has variadic parameters.")
+        if sum(self._count_returns(stmt) for stmt in function.body.body) > 1:
+            raise SkipFile(f"'{self.name}' has multiple return statements.")
+
+        body = list(function.body.body)
+        if body and function.get_docstring() is not None:
+            body = body[1:]
+        if len(body) == 1:
+            stmt = body[0]
+            if isinstance(stmt, libcst.SimpleStatementLine) and len(stmt.body) == 1:
+                small_stmt = stmt.body[0]
+                if isinstance(small_stmt, libcst.Return):
+                    value = small_stmt.value
+                    if value is not None:
+                        return value
+        raise SkipFile(f"'{self.name}' doesn't consist of a single return statement.")
+
+    @staticmethod
+    def _all_params(function: libcst.FunctionDef) -> List[libcst.Param]:
+        params = function.params
+        return [*params.posonly_params, *params.params, *params.kwonly_params]
+
+    def visit_Module(self, node: libcst.Module) -> None:
+        functions = [
+            stmt
+            for stmt in node.body
+            if isinstance(stmt, libcst.FunctionDef) and stmt.name.value == self.name
+        ]
+        if len(functions) != 1:
+            raise SkipFile(f"Expected a single module-level function '{self.name}'.")
+        function = functions[0]
+        value = self._return_value(function)
+        for param in self._all_params(function):
+            default = param.default
+            if default is None:
+                continue
+            if not isinstance(default, _PURE) or (
+                # Other names might have been rebound since the function was defined.
+                isinstance(default, libcst.Name)
+                and default.value not in ("None", "True", "False")
+            ):
+                raise SkipFile(f"'{self.name}' has a non-literal default value.")
+
+        scope = self.get_metadata(ScopeProvider, function.body.body[-1])
+        expression_names = {
+            name for name in iter_nodes(value) if isinstance(name, libcst.Name)
+        }
+        references: Dict[libcst.Name, str] = {}
+        for param in self._all_params(function):
+            param_name = param.name.value
+            self.references[param_name] = []
+            for assignment in scope[param_name] if scope is not None else ():
+                if not isinstance(assignment, Assignment) or assignment.scope != scope:
+                    continue
+                for access in assignment.references:
+                    if access.node in expression_names:
+                        self.references[param_name].append(access.node)
+                        references[access.node] = param_name
+
+        for child in iter_nodes(value):
+            if isinstance(child, libcst.Attribute):
+                expression_names.discard(child.attr)
+            elif isinstance(child, libcst.Arg) and child.keyword is not None:
+                expression_names.discard(child.keyword)
+        global_scope = self.get_metadata(ScopeProvider, node)
+        for name in expression_names:
+            if name in references:
+                continue
+            name_scope = self.get_metadata(ScopeProvider, name, None)
+            assignments = name_scope[name.value] if name_scope is not None else ()
+            if assignments and all(
+                isinstance(assignment, Assignment)
+                and assignment.scope != global_scope
+                for assignment in assignments
+            ):
+                # Bound by a lambda or comprehension inside of the return value.
+                self.bound_names.add(name.value)
+            else:
+                self.free_names.add(name.value)
+        self.free_names.add(self.name)
+        self.used_names = {
+            name.value for name in iter_nodes(node) if isinstance(name, libcst.Name)
+        }
+        self.function = function
+        self.value = value
+
+    def visit_FunctionDef(self, node: libcst.FunctionDef) -> None:
+        if node is self.function:
+            self.in_function = True
+
+    def leave_FunctionDef(
+        self, original_node: libcst.FunctionDef, updated_node: libcst.FunctionDef
+    ) -> libcst.FunctionDef:
+        if original_node is self.function:
+            self.in_function = False
+        return updated_node
+
+    def _match_arguments(
+        self, function: libcst.FunctionDef, args: Sequence[libcst.Arg]
+    ) -> Optional[Dict[str, libcst.BaseExpression]]:
+        params = function.params
+        positional = [*params.posonly_params, *params.params]
+        keyword = {param.name.value: param for param in params.kwonly_params}
+        keyword.update({param.name.value: param for param in params.params})
+
+        matched: Dict[str, libcst.BaseExpression] = {}
+        for i, arg in enumerate(args):
+            if arg.star:
+                return None
+            arg_keyword = arg.keyword
+            if arg_keyword is None:
+                if i >= len(positional):
+                    return None
+                name = positional[i].name.value
+            else:
+                name = arg_keyword.value
+                if name not in keyword:
+                    return None
+            if name in matched:
+                return None
+            matched[name] = arg.value
+        for param in self._all_params(function):
+            name = param.name.value
+            if name not in matched:
+                default = param.default
+                if default is None:
+                    return None
+                matched[name] = default
+        return matched
+
+    def _statement_for(
+        self, node: libcst.CSTNode
+    ) -> Optional[libcst.SimpleStatementLine]:
+        current = self.get_metadata(ParentNodeProvider, node)
+        while current is not None:
+            if isinstance(current, libcst.SimpleStatementLine):
+                return current
+            if isinstance(current, (libcst.BaseSuite, libcst.BaseCompoundStatement)):
+                return None
+            if isinstance(current, _NO_HOIST):
+                return None
+            current = self.get_metadata(ParentNodeProvider, current)
+        return None
+
+    def _temporary_name(self, param: str) -> str:
+        name = f"_{param}"
+        suffix = 1
+        while name in self.used_names:
+            suffix += 1
+            name = f"_{param}{suffix}"
+        self.used_names.add(name)
+        return name
+
+    def _is_shadowed(self, node: libcst.CSTNode) -> bool:
+        scope = self.get_metadata(ScopeProvider, node)
+        global_scope = self.get_metadata(ScopeProvider, self.module)
+        if scope is None or global_scope is None:
+            return True
+        return any(
+            set(scope[name]) != set(global_scope[name]) for name in self.free_names
+        )
+
+    def _needs_parentheses(
+        self, node: libcst.CSTNode, value: libcst.BaseExpression
+    ) -> bool:
+        if value.lpar or isinstance(value, _ATOMS):
+            return False
+        parent = self.get_metadata(ParentNodeProvider, node)
+        if isinstance(
+            parent,
+            (
+                libcst.Assign,
+                libcst.AnnAssign,
+                libcst.AugAssign,
+                libcst.Return,
+                libcst.Expr,
+            ),
+        ):
+            return False
+        if isinstance(parent, (libcst.Arg, libcst.Element)):
+            return isinstance(value, libcst.Tuple)
+        return True
+
+    def leave_Call(
+        self, original_node: libcst.Call, updated_node: libcst.Call
+    ) -> libcst.BaseExpression:
+        function = self.function
+        value = self.value
+        func = original_node.func
+        if function is None or value is None or self.in_function:
+            return updated_node
+        if not isinstance(func, libcst.Name) or func.value != self.name:
+            return updated_node
+        if self._is_shadowed(original_node):
+            return updated_node
+        arguments = self._match_arguments(function, updated_node.args)
+        if arguments is None:
+            return updated_node
+
+        replacements: Dict[libcst.Name, libcst.BaseExpression] = {}
+        temporaries: List[libcst.Assign] = []
+        for param in self._all_params(function):
+            name = param.name.value
+            argument = arguments[name]
+            if isinstance(argument, _PURE) and not (
+                # A name might be captured by a lambda or comprehension in the
+                # function's return value.
+                isinstance(argument, libcst.Name)
+                and argument.value in self.bound_names
+            ):
+                substitute = argument
+            else:
+                temporary = self._temporary_name(name)
+                temporaries.append(
+                    libcst.Assign(
+                        [libcst.AssignTarget(libcst.Name(temporary))], argument
+                    )
+                )
+                substitute = libcst.Name(temporary)
+            for reference in self.references[name]:
+                replacements[reference] = substitute
+
+        if temporaries:
+            statement = self._statement_for(original_node)
+            if statement is None:
+                # Undo the names reserved for the temporaries we won't be using.
+                for temporary in temporaries:
+                    target = temporary.targets[0].target
+                    if isinstance(target, libcst.Name):
+                        self.used_names.discard(target.value)
+                return updated_node
+            self.pending.setdefault(statement, []).extend(temporaries)
+
+        inlined = value.visit(_SubstituteNames(replacements))
+        if not isinstance(inlined, libcst.BaseExpression):
+            raise Exception("Logic error, substitution didn't produce an expression!")
+        if self._needs_parentheses(original_node, inlined):
+            inlined = inlined.with_changes(
+                lpar=[libcst.LeftParen()], rpar=[libcst.RightParen()]
+            )
+        return inlined.with_changes(
+            lpar=[*updated_node.lpar, *inlined.lpar],
+            rpar=[*inlined.rpar, *updated_node.rpar],
+        )
+
+    def leave_SimpleStatementLine(
+        self,
+        original_node: libcst.SimpleStatementLine,
+        updated_node: libcst.SimpleStatementLine,
+    ) -> libcst.SimpleStatementLine:
+        temporaries = self.pending.pop(original_node, None)
+        if temporaries is None:
+            return updated_node
+        # The temporaries take over the statement's leading lines, so that comments
+        # above the statement end up above them instead.
+        updated_node = updated_node.with_changes(leading_lines=())
+        self.hoisted[updated_node] = [
+            libcst.SimpleStatementLine(
+                [temporary],
+                leading_lines=original_node.leading_lines if i == 0 else (),
+            )
+            for i, temporary in enumerate(temporaries)
+        ]
+        return updated_node
+
+    def _insert_temporaries(
+        self, body: Sequence[libcst.BaseStatement]
+    ) -> List[libcst.BaseStatement]:
+        new_body: List[libcst.BaseStatement] = []
+        for stmt in body:
+            if isinstance(stmt, libcst.SimpleStatementLine):
+                new_body.extend(self.hoisted.pop(stmt, ()))
+            new_body.append(stmt)
+        return new_body
+
+    def leave_IndentedBlock(
+        self, original_node: libcst.IndentedBlock, updated_node: libcst.IndentedBlock
+    ) -> libcst.IndentedBlock:
+        return updated_node.with_changes(
+            body=self._insert_temporaries(updated_node.body)
+        )
+
+    def leave_Module(
+        self, original_node: libcst.Module, updated_node: libcst.Module
+    ) -> libcst.Module:
+        return updated_node.with_changes(
+            body=self._insert_temporaries(updated_node.body)
+        )
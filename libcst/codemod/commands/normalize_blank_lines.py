@@ -0,0 +1,107 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import argparse
+from typing import List, Sequence, Union
+
+import libcst
+from libcst.codemod import CodemodContext, VisitorBasedCodemodCommand
+
+
+_DefinitionT = Union[libcst.FunctionDef, libcst.ClassDef]
+
+
+class NormalizeBlankLinesCommand(VisitorBasedCodemodCommand):
+    """
+    Adjusts the number of blank lines in front of top-level function and class
+    definitions, as well as definitions directly inside of a class body (methods and
+    nested classes). Only the blank lines above the first leading comment are
+    adjusted, so comments stay attached to the definition they document. The first
+    definition in a module or class body and anything nested inside of a function are
+    left alone.
+    """
+
+    DESCRIPTION: str = "Normalizes the number of blank lines before definitions."
+
+    @staticmethod
+    def add_args(parser: argparse.ArgumentParser) -> None:
+        parser.add_argument(
+            "--top-level",
+            dest="top_level",
+            metavar="COUNT",
+            help="Number of blank lines before top-level definitions.",
+            type=int,
+            default=2,
+        )
+        parser.add_argument(
+            "--method",
+            dest="method",
+            metavar="COUNT",
+            help="Number of blank lines before definitions inside of a class body.",
+            type=int,
+            default=1,
+        )
+
+    def __init__(
+        self, context: CodemodContext, top_level: int = 2, method: int = 1
+    ) -> None:
+        super().__init__(context)
+        self.top_level = top_level
+        self.method = method
+        self.function_depth = 0
+
+    @staticmethod
+    def _normalize_leading_lines(node: _DefinitionT, count: int) -> _DefinitionT:
+        leading_lines = list(node.leading_lines)
+        blank_count = 0
+        while (
+            blank_count < len(leading_lines)
+            and leading_lines[blank_count].comment is None
+        ):
+            blank_count += 1
+        blank_lines: List[libcst.EmptyLine] = leading_lines[:blank_count][:count]
+        blank_lines.extend(
+            libcst.EmptyLine(indent=False) for _ in range(count - len(blank_lines))
+        )
+        return node.with_changes(
+            leading_lines=[*blank_lines, *leading_lines[blank_count:]]
+        )
+
+    def _normalize_body(
+        self, body: Sequence[libcst.BaseStatement], count: int
+    ) -> List[libcst.BaseStatement]:
+        return [
+            self._normalize_leading_lines(stmt, count)
+            if i > 0 and isinstance(stmt, (libcst.FunctionDef, libcst.ClassDef))
+            else stmt
+            for i, stmt in enumerate(body)
+        ]
+
+    def visit_FunctionDef(self, node: libcst.FunctionDef) -> None:
+        self.function_depth += 1
+
+    def leave_FunctionDef(
+        self, original_node: libcst.FunctionDef, updated_node: libcst.FunctionDef
+    ) -> libcst.FunctionDef:
+        self.function_depth -= 1
+        return updated_node
+
+    def leave_ClassDef(
+        self, original_node: libcst.ClassDef, updated_node: libcst.ClassDef
+    ) -> libcst.ClassDef:
+        body = updated_node.body
+        if self.function_depth > 0 or not isinstance(body, libcst.IndentedBlock):
+            return updated_node
+        return updated_node.with_changes(
+            body=body.with_changes(body=self._normalize_body(body.body, self.method))
+        )
+
+    def leave_Module(
+        self, original_node: libcst.Module, updated_node: libcst.Module
+    ) -> libcst.Module:
+        return updated_node.with_changes(
+            body=self._normalize_body(updated_node.body, self.top_level)
+        )
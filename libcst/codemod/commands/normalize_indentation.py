@@ -0,0 +1,42 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import libcst
+from libcst.codemod import VisitorBasedCodemodCommand
+
+
+class NormalizeIndentationCommand(VisitorBasedCodemodCommand):
+    """
+    Re-indents every block with four spaces per level. Comments on their own line
+    follow the indentation of the block they're in, unless they start at the
+    beginning of the line. Continuation lines inside of brackets keep their original
+    whitespace, since it isn't relative to the indentation of the block.
+    """
+
+    DESCRIPTION: str = "Re-indents every block with four spaces."
+
+    def leave_IndentedBlock(
+        self, original_node: libcst.IndentedBlock, updated_node: libcst.IndentedBlock
+    ) -> libcst.IndentedBlock:
+        # Blocks without an explicit indent use the module's default indent.
+        return updated_node.with_changes(indent=None)
+
+    def leave_EmptyLine(
+        self, original_node: libcst.EmptyLine, updated_node: libcst.EmptyLine
+    ) -> libcst.EmptyLine:
+        if updated_node.comment is None or updated_node.indent:
+            return updated_node
+        if updated_node.whitespace.empty:
+            return updated_node
+        # The comment didn't line up with its block before, so line it up now.
+        return updated_node.with_changes(
+            indent=True, whitespace=libcst.SimpleWhitespace("")
+        )
+
+    def leave_Module(
+        self, original_node: libcst.Module, updated_node: libcst.Module
+    ) -> libcst.Module:
+        return updated_node.with_changes(default_indent=" " * 4)
@@ -0,0 +1,163 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import argparse
+from typing import List, Optional
+
+import libcst
+from libcst.codemod import CodemodContext, VisitorBasedCodemodCommand
+
+
+_QUOTES = {"double": '"', "single": "'"}
+
+
+def _convert_body(body: str, old: str, new: str, raw: bool) -> Optional[str]:
+    """
+    Returns the body of a single-quoted string re-escaped so that it can be wrapped
+    in ``new`` instead of ``old``, or ``None`` if that isn't possible.
+    """
+    result: List[str] = []
+    i = 0
+    while i < len(body):
+        char = body[i]
+        if char == "\\" and i + 1 < len(body):
+            escaped = body[i + 1]
+            # Backslashes are kept in raw strings, so they can't be removed.
+            result.append(old if escaped == old and not raw else char + escaped)
+            i += 2
+            continue
+        if char == new:
+            if raw:
+                # There's no way to escape a quote in a raw string.
+                return None
+            result.append("\\" + new)
+        else:
+            result.append(char)
+        i += 1
+    return "".join(result)
+
+
+def _convert_triple_body(body: str, new: str) -> Optional[str]:
+    """
+    Returns the body of a triple-quoted string so that it can be wrapped in ``new``
+    three times, or ``None`` if the body would end the string early.
+    """
+    if new * 3 in body or body.endswith(new):
+        return None
+    return body
+
+
+class NormalizeQuotesCommand(VisitorBasedCodemodCommand):
+    """
+    Rewrites string literals and f-strings to use the same kind of quotes, adding
+    and removing backslashes in front of quotes as needed. A string is left alone if
+    switching would need more backslashes than it already has, so ``'say "hi"'``
+    keeps its single quotes, while ``'it\\'s'`` becomes ``"it's"``.
+
+    Triple-quoted strings don't need any escapes, so they're only left alone if they
+    contain three of the new quotes in a row or end with one. F-strings are left
+    alone if any of their expressions contain the new quote, since quotes can't be
+    escaped inside of those. Strings and f-strings inside of an f-string's
+    expressions are always left alone, since they can't use the same quotes as the
+    f-string they're in. Raw strings can't escape quotes at all, so they're left
+    alone if they contain the new quote.
+    """
+
+    DESCRIPTION: str = "Rewrites strings to use the same kind of quotes."
+
+    @staticmethod
+    def add_args(parser: argparse.ArgumentParser) -> None:
+        parser.add_argument(
+            "--style",
+            dest="style",
+            help="Kind of quotes to use, or 'preserve' to leave strings alone.",
+            type=str,
+            choices=["double", "single", "preserve"],
+            default="double",
+        )
+
+    def __init__(self, context: CodemodContext, style: str = "double") -> None:
+        super().__init__(context)
+        self.quote: Optional[str] = _QUOTES.get(style)
+        self.expression_depth = 0
+
+    def _convert(self, body: str, quote: str, raw: bool) -> Optional[str]:
+        new = self.quote
+        if new is None or quote[0] == new:
+            return None
+        if len(quote) == 3:
+            return _convert_triple_body(body, new)
+        converted = _convert_body(body, quote, new, raw)
+        if converted is None or converted.count("\\") > body.count("\\"):
+            return None
+        return converted
+
+    def visit_FormattedStringExpression(
+        self, node: libcst.FormattedStringExpression
+    ) -> None:
+        self.expression_depth += 1
+
+    def leave_FormattedStringExpression(
+        self,
+        original_node: libcst.FormattedStringExpression,
+        updated_node: libcst.FormattedStringExpression,
+    ) -> libcst.FormattedStringExpression:
+        self.expression_depth -= 1
+        return updated_node
+
+    def leave_SimpleString(
+        self, original_node: libcst.SimpleString, updated_node: libcst.SimpleString
+    ) -> libcst.SimpleString:
+        # Before Python 3.12, strings inside of an f-string's expressions can't use
+        # the f-string's quotes, so they're left as they are.
+        if self.expression_depth > 0:
+            return updated_node
+        quote = updated_node.quote
+        body = self._convert(
+            updated_node.raw_value, quote, raw="r" in updated_node.prefix
+        )
+        new = self.quote
+        if body is None or new is None:
+            return updated_node
+        # Keep the prefix as written, since the ``prefix`` property is lowercased.
+        prefix = updated_node.value[: len(updated_node.prefix)]
+        new_quote = new * len(quote)
+        return updated_node.with_changes(value=f"{prefix}{new_quote}{body}{new_quote}")
+
+    def leave_FormattedString(
+        self,
+        original_node: libcst.FormattedString,
+        updated_node: libcst.FormattedString,
+    ) -> libcst.FormattedString:
+        new = self.quote
+        quote = updated_node.quote
+        if new is None or quote[0] == new or self.expression_depth > 0:
+            return updated_node
+        raw = "r" in updated_node.prefix
+        parts: List[libcst.BaseFormattedStringContent] = []
+        for part in updated_node.parts:
+            if isinstance(part, libcst.FormattedStringText):
+                body = self._convert(part.value, quote, raw)
+                if body is None:
+                    return updated_node
+                parts.append(part.with_changes(value=body))
+            else:
+                if new in self.module.code_for_node(part):
+                    return updated_node
+                parts.append(part)
+        if len(quote) == 3:
+            # The text is only checked part by part, so check it as a whole too.
+            text = "".join(
+                part.value if isinstance(part, libcst.FormattedStringText) else "{}"
+                for part in parts
+            )
+            if _convert_triple_body(text, new) is None:
+                return updated_node
+        prefix = updated_node.start[: len(updated_node.prefix)]
+        new_quote = new * len(quote)
+        return updated_node.with_changes(
+            start=f"{prefix}{new_quote}", parts=parts, end=new_quote
+        )
@@ -0,0 +1,208 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import argparse
+from typing import Optional, Sequence, TypeVar, Union
+
+import libcst
+from libcst import MaybeSentinel
+from libcst.codemod import CodemodContext, VisitorBasedCodemodCommand
+
+
+_BracketedT = TypeVar(
+    "_BracketedT",
+    libcst.Call,
+    libcst.List,
+    libcst.Set,
+    libcst.Tuple,
+    libcst.Dict,
+    libcst.ImportFrom,
+)
+_ElementT = Union[
+    libcst.Arg, libcst.BaseElement, libcst.BaseDictElement, libcst.ImportAlias
+]
+
+
+class NormalizeTrailingCommasCommand(VisitorBasedCodemodCommand):
+    """
+    Adds or removes the trailing comma after the last argument of a call, the last
+    element of a list, set, tuple or dict literal, and the last name of a
+    parenthesized ``from`` import. With ``--mode=multiline``, the default, a
+    trailing comma is added when the closing bracket is on its own line and removed
+    when the whole construct is on a single line, which matches the "magic trailing
+    comma" used by ``black``. With ``--mode=always`` or ``--mode=never`` it's always
+    added or always removed.
+
+    The comma of a single-element tuple is never removed, and no comma is added
+    after a generator expression that is the only argument of a call, since either
+    one would change the meaning of the code.
+    """
+
+    DESCRIPTION: str = "Adds or removes trailing commas in brackets."
+
+    @staticmethod
+    def add_args(parser: argparse.ArgumentParser) -> None:
+        parser.add_argument(
+            "--mode",
+            dest="mode",
+            help="When to put a comma after the last element.",
+            type=str,
+            choices=["always", "never", "multiline"],
+            default="multiline",
+        )
+
+    def __init__(self, context: CodemodContext, mode: str = "multiline") -> None:
+        super().__init__(context)
+        self.mode = mode
+
+    @staticmethod
+    def _elements(node: _BracketedT) -> Sequence[_ElementT]:
+        if isinstance(node, libcst.Call):
+            return node.args
+        if isinstance(node, libcst.ImportFrom):
+            names = node.names
+            return () if isinstance(names, libcst.ImportStar) else names
+        return node.elements
+
+    @staticmethod
+    def _closing(node: _BracketedT) -> libcst.BaseParenthesizableWhitespace:
+        """
+        Returns the whitespace between the last element's comma and the closing
+        bracket.
+        """
+        if isinstance(node, libcst.Call):
+            return node.args[-1].whitespace_after_arg
+        if isinstance(node, libcst.ImportFrom):
+            rpar = node.rpar
+            if rpar is None:
+                return libcst.SimpleWhitespace("")
+            return rpar.whitespace_before
+        if isinstance(node, libcst.Tuple):
+            return node.rpar[0].whitespace_before
+        if isinstance(node, libcst.List):
+            return node.rbracket.whitespace_before
+        return node.rbrace.whitespace_before
+
+    @staticmethod
+    def _with_closing(
+        node: _BracketedT,
+        last: _ElementT,
+        closing: libcst.BaseParenthesizableWhitespace,
+    ) -> _BracketedT:
+        if isinstance(node, libcst.Call):
+            if isinstance(last, libcst.Arg):
+                last = last.with_changes(whitespace_after_arg=closing)
+            return node.with_changes(args=[*node.args[:-1], last])
+        if isinstance(node, libcst.ImportFrom):
+            rpar = node.rpar
+            names = node.names
+            if rpar is None or isinstance(names, libcst.ImportStar):
+                return node
+            return node.with_changes(
+                names=[*names[:-1], last],
+                rpar=rpar.with_changes(whitespace_before=closing),
+            )
+        elements = [*node.elements[:-1], last]
+        if isinstance(node, libcst.Tuple):
+            return node.with_changes(
+                elements=elements,
+                rpar=[
+                    node.rpar[0].with_changes(whitespace_before=closing),
+                    *node.rpar[1:],
+                ],
+            )
+        if isinstance(node, libcst.List):
+            return node.with_changes(
+                elements=elements,
+                rbracket=node.rbracket.with_changes(whitespace_before=closing),
+            )
+        return node.with_changes(
+            elements=elements,
+            rbrace=node.rbrace.with_changes(whitespace_before=closing),
+        )
+
+    def _wants_comma(self, node: _BracketedT) -> Optional[bool]:
+        if self.mode == "always":
+            return True
+        if self.mode == "never":
+            return False
+        last = self._elements(node)[-1]
+        comma = last.comma
+        if isinstance(self._closing(node), libcst.ParenthesizedWhitespace) or (
+            isinstance(comma, libcst.Comma)
+            and isinstance(comma.whitespace_after, libcst.ParenthesizedWhitespace)
+        ):
+            return True
+        code = self.module.code_for_node(node)
+        if "\n" not in code and "\r" not in code:
+            return False
+        # The construct spans multiple lines, but the closing bracket isn't on its
+        # own line, so there's no obvious choice.
+        return None
+
+    def _normalize(self, node: _BracketedT) -> _BracketedT:
+        elements = self._elements(node)
+        if not elements:
+            return node
+        if isinstance(node, libcst.Tuple) and not node.lpar:
+            return node
+        if isinstance(node, libcst.ImportFrom) and node.rpar is None:
+            return node
+        wants_comma = self._wants_comma(node)
+        last = elements[-1]
+        comma = last.comma
+        closing = self._closing(node)
+
+        if wants_comma and not isinstance(comma, libcst.Comma):
+            if isinstance(node, libcst.Call) and any(
+                isinstance(arg.value, libcst.GeneratorExp) and not arg.value.lpar
+                for arg in node.args
+            ):
+                return node
+            # The comma goes right after the element, in front of any newline.
+            last = last.with_changes(comma=libcst.Comma(whitespace_after=closing))
+            return self._with_closing(node, last, libcst.SimpleWhitespace(""))
+
+        if wants_comma is False and isinstance(comma, libcst.Comma):
+            if isinstance(node, libcst.Tuple) and len(elements) == 1:
+                return node
+            whitespace = comma.whitespace_after
+            if isinstance(whitespace, libcst.ParenthesizedWhitespace):
+                closing = whitespace
+            last = last.with_changes(comma=MaybeSentinel.DEFAULT)
+            return self._with_closing(node, last, closing)
+
+        return node
+
+    def leave_Call(
+        self, original_node: libcst.Call, updated_node: libcst.Call
+    ) -> libcst.Call:
+        return self._normalize(updated_node)
+
+    def leave_List(
+        self, original_node: libcst.List, updated_node: libcst.List
+    ) -> libcst.List:
+        return self._normalize(updated_node)
+
+    def leave_Set(
+        self, original_node: libcst.Set, updated_node: libcst.Set
+    ) -> libcst.Set:
+        return self._normalize(updated_node)
+
+    def leave_Tuple(
+        self, original_node: libcst.Tuple, updated_node: libcst.Tuple
+    ) -> libcst.Tuple:
+        return self._normalize(updated_node)
+
+    def leave_Dict(
+        self, original_node: libcst.Dict, updated_node: libcst.Dict
+    ) -> libcst.Dict:
+        return self._normalize(updated_node)
+
+    def leave_ImportFrom(
+        self, original_node: libcst.ImportFrom, updated_node: libcst.ImportFrom
+    ) -> libcst.ImportFrom:
+        return self._normalize(updated_node)
@@ -0,0 +1,40 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import libcst
+from libcst.codemod import VisitorBasedCodemodCommand
+
+
+class NormalizeTrailingWhitespaceCommand(VisitorBasedCodemodCommand):
+    """
+    Removes spaces and tabs at the end of lines, including lines that are entirely
+    blank. Whitespace in front of a trailing comment is left alone, as is anything
+    inside of string literals.
+    """
+
+    DESCRIPTION: str = "Removes trailing whitespace from the end of every line."
+
+    def leave_TrailingWhitespace(
+        self,
+        original_node: libcst.TrailingWhitespace,
+        updated_node: libcst.TrailingWhitespace,
+    ) -> libcst.TrailingWhitespace:
+        if updated_node.comment is not None or updated_node.whitespace.empty:
+            return updated_node
+        return updated_node.with_changes(whitespace=libcst.SimpleWhitespace(""))
+
+    def leave_EmptyLine(
+        self, original_node: libcst.EmptyLine, updated_node: libcst.EmptyLine
+    ) -> libcst.EmptyLine:
+        if updated_node.comment is not None:
+            return updated_node
+        if not updated_node.indent and updated_node.whitespace.empty:
+            return updated_node
+        # A blank line that inherits the indentation of its block is nothing but
+        # trailing whitespace, so drop the indentation as well.
+        return updated_node.with_changes(
+            indent=False, whitespace=libcst.SimpleWhitespace("")
+        )
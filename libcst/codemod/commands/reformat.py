@@ -0,0 +1,33 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Generator, Type
+
+from libcst.codemod import Codemod, MagicArgsCodemodCommand
+from libcst.codemod.commands.normalize_blank_lines import NormalizeBlankLinesCommand
+from libcst.codemod.commands.normalize_indentation import NormalizeIndentationCommand
+from libcst.codemod.commands.normalize_quotes import NormalizeQuotesCommand
+from libcst.codemod.commands.normalize_trailing_whitespace import (
+    NormalizeTrailingWhitespaceCommand,
+)
+
+
+class ReformatCommand(MagicArgsCodemodCommand):
+    """
+    Applies a canonical PEP 8 style to a module by running each of the normalizing
+    codemods in turn: four-space indentation, two blank lines between top-level
+    definitions and one between methods, no trailing whitespace and double-quoted
+    strings. This is a lot coarser than a dedicated formatter such as ``black``,
+    since it doesn't touch whitespace inside of lines or wrap long lines.
+    """
+
+    DESCRIPTION: str = "Applies canonical PEP 8 formatting to a module."
+
+    def get_transforms(self) -> Generator[Type[Codemod], None, None]:
+        yield NormalizeIndentationCommand
+        yield NormalizeBlankLinesCommand
+        yield NormalizeTrailingWhitespaceCommand
+        yield NormalizeQuotesCommand
@@ -0,0 +1,94 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import argparse
+from typing import Generator, Optional, Type
+
+from libcst import Module
+from libcst.codemod import Codemod, MagicArgsCodemodCommand
+from libcst.codemod.visitors import GatherImportsVisitor, RemoveImportsVisitor
+
+
+class RemoveImportCommand(MagicArgsCodemodCommand):
+    """
+    Removes an import of a module, or of an entity from a module, optionally with
+    an alias. Imports that are still referenced are kept, since removing them would
+    leave the references undefined, and a warning is emitted for each one so that
+    they can be removed by hand once the references are gone.
+    """
+
+    DESCRIPTION: str = (
+        "Given a module and possibly an entity in that module, remove the import "
+        + "as long as nothing in the module still references it."
+    )
+
+    @staticmethod
+    def add_args(parser: argparse.ArgumentParser) -> None:
+        parser.add_argument(
+            "--module",
+            dest="module",
+            metavar="MODULE",
+            help="Module whose import should be removed.",
+            type=str,
+            required=True,
+        )
+        parser.add_argument(
+            "--entity",
+            dest="entity",
+            metavar="ENTITY",
+            help=(
+                "Entity imported from module that should be removed. If left empty, "
+                + "the import of the entire module will be removed."
+            ),
+            type=str,
+            default=None,
+        )
+        parser.add_argument(
+            "--alias",
+            dest="alias",
+            metavar="ALIAS",
+            help=(
+                "Alias that the imported module or entity is assigned to. If left "
+                "empty, only the unaliased import will be removed."
+            ),
+            type=str,
+            default=None,
+        )
+
+    def get_transforms(self) -> Generator[Type[Codemod], None, None]:
+        RemoveImportsVisitor.remove_unused_import(
+            self.context,
+            self.context.scratch["module"],
+            self.context.scratch["entity"],
+            self.context.scratch["alias"],
+        )
+        yield RemoveImportsVisitor
+
+    def transform_module_impl(self, tree: Module) -> Module:
+        tree = super().transform_module_impl(tree)
+        module: str = self.context.scratch["module"]
+        entity: Optional[str] = self.context.scratch["entity"]
+        alias: Optional[str] = self.context.scratch["alias"]
+        gatherer = GatherImportsVisitor(self.context)
+        tree.visit(gatherer)
+        if entity is None:
+            name = module
+            if alias is None:
+                kept = module in gatherer.module_imports
+            else:
+                kept = gatherer.module_aliases.get(module) == alias
+        else:
+            name = f"{entity} from {module}"
+            if alias is None:
+                kept = entity in gatherer.object_mapping.get(module, set())
+            else:
+                kept = (entity, alias) in gatherer.alias_mapping.get(module, [])
+        if kept:
+            as_alias = f" as {alias}" if alias is not None else ""
+            self.warn(
+                f"Kept the import of {name}{as_alias} because it is still referenced."
+            )
+        return tree
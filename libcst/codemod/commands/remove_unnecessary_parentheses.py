@@ -0,0 +1,114 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import TypeVar
+
+import libcst
+from libcst.codemod import VisitorBasedCodemodCommand
+
+
+_ExpressionT = TypeVar("_ExpressionT", bound=libcst.BaseExpression)
+
+
+class RemoveUnnecessaryParenthesesCommand(VisitorBasedCodemodCommand):
+    """
+    Removes parentheses around the whole expression of a ``return``, ``del``,
+    ``assert``, ``if``, ``elif`` or ``while`` statement and around the value of an
+    assignment, since those never need them for grouping or precedence. For example,
+    ``return (x)`` becomes ``return x`` and ``if(x):`` becomes ``if x:``.
+
+    Parentheses that span multiple lines are kept, since they also serve as an
+    implicit line continuation. Parentheses that belong to the expression itself,
+    such as those of a tuple or a generator expression, and those that are required
+    around ``yield`` and assignment expressions are kept too.
+    """
+
+    DESCRIPTION: str = "Removes redundant parentheses around whole expressions."
+
+    def _strip(self, expression: _ExpressionT) -> _ExpressionT:
+        if not expression.lpar:
+            return expression
+        if isinstance(
+            expression,
+            (libcst.Tuple, libcst.GeneratorExp, libcst.Yield, libcst.NamedExpr),
+        ):
+            return expression
+        code = self.module.code_for_node(expression)
+        if "\n" in code or "\r" in code:
+            return expression
+        return expression.with_changes(lpar=[], rpar=[])
+
+    @staticmethod
+    def _space(whitespace: libcst.SimpleWhitespace) -> libcst.SimpleWhitespace:
+        # Without parentheses, an expression can't touch the keyword in front of it.
+        return libcst.SimpleWhitespace(" ") if whitespace.empty else whitespace
+
+    def leave_Return(
+        self, original_node: libcst.Return, updated_node: libcst.Return
+    ) -> libcst.Return:
+        value = updated_node.value
+        if value is None:
+            return updated_node
+        stripped = self._strip(value)
+        if stripped is value:
+            return updated_node
+        whitespace = updated_node.whitespace_after_return
+        if isinstance(whitespace, libcst.SimpleWhitespace):
+            whitespace = self._space(whitespace)
+        return updated_node.with_changes(
+            value=stripped, whitespace_after_return=whitespace
+        )
+
+    def leave_Del(
+        self, original_node: libcst.Del, updated_node: libcst.Del
+    ) -> libcst.Del:
+        target = self._strip(updated_node.target)
+        if target is updated_node.target:
+            return updated_node
+        return updated_node.with_changes(
+            target=target,
+            whitespace_after_del=self._space(updated_node.whitespace_after_del),
+        )
+
+    def leave_Assert(
+        self, original_node: libcst.Assert, updated_node: libcst.Assert
+    ) -> libcst.Assert:
+        test = self._strip(updated_node.test)
+        msg = updated_node.msg
+        if msg is not None:
+            msg = self._strip(msg)
+        if test is updated_node.test and msg is updated_node.msg:
+            return updated_node
+        return updated_node.with_changes(
+            test=test,
+            msg=msg,
+            whitespace_after_assert=self._space(updated_node.whitespace_after_assert),
+        )
+
+    def leave_If(self, original_node: libcst.If, updated_node: libcst.If) -> libcst.If:
+        test = self._strip(updated_node.test)
+        if test is updated_node.test:
+            return updated_node
+        return updated_node.with_changes(
+            test=test,
+            whitespace_before_test=self._space(updated_node.whitespace_before_test),
+        )
+
+    def leave_While(
+        self, original_node: libcst.While, updated_node: libcst.While
+    ) -> libcst.While:
+        test = self._strip(updated_node.test)
+        if test is updated_node.test:
+            return updated_node
+        return updated_node.with_changes(
+            test=test,
+            whitespace_after_while=self._space(updated_node.whitespace_after_while),
+        )
+
+    def leave_Assign(
+        self, original_node: libcst.Assign, updated_node: libcst.Assign
+    ) -> libcst.Assign:
+        return updated_node.with_changes(value=self._strip(updated_node.value))
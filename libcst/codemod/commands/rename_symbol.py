@@ -0,0 +1,298 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import argparse
+from typing import List, Sequence, Set, Union
+
+import libcst
+from libcst.codemod import CodemodContext, SkipFile, VisitorBasedCodemodCommand
+from libcst.metadata import Assignment, ClassScope, GlobalScope, ScopeProvider
+
+
+# Statements that bind a name once everything they read has been evaluated.
+_BINDS_AFTER_READING = (
+    libcst.Assign,
+    libcst.AnnAssign,
+    libcst.FunctionDef,
+    libcst.ClassDef,
+)
+
+
+class _NodeCollector(libcst.CSTVisitor):
+    def __init__(self) -> None:
+        super().__init__()
+        self.nodes: Set[libcst.CSTNode] = set()
+
+    def on_visit(self, node: libcst.CSTNode) -> bool:
+        self.nodes.add(node)
+        return True
+
+
+def _collect_nodes(node: libcst.CSTNode) -> Set[libcst.CSTNode]:
+    collector = _NodeCollector()
+    node.visit(collector)
+    return collector.nodes
+
+
+class RenameSymbolCommand(VisitorBasedCodemodCommand):
+    """
+    Renames a module-level symbol along with every reference to it. Scoping rules are
+    respected, so a local variable or parameter in a nested scope that shadows the
+    symbol is left alone, while assignments in functions that declare the symbol
+    ``global`` are renamed. Attribute accesses and keyword arguments that happen to
+    use the same name are not references to the symbol, so they are untouched too.
+
+    Imported symbols are renamed by aliasing the import (``from a import b`` becomes
+    ``from a import b as c``). Modules where the symbol is bound by a dotted import
+    such as ``import a.b`` can't be renamed this way, so they are skipped.
+
+    In a class body that binds the same name, reads before the class attribute is
+    bound refer to the symbol, as in ``foo = foo + 1``, so they are renamed. Modules
+    where such a read may or may not come before the attribute is bound, such as
+    after a conditional assignment, are skipped.
+
+    Strings in a module-level ``__all__`` that name the symbol are renamed, and so
+    are string annotations such as ``"Foo"`` that consist of just the symbol's name.
+    Names inside of more complex string annotations are left alone.
+    """
+
+    DESCRIPTION: str = "Renames a module-level symbol and all of its references."
+
+    METADATA_DEPENDENCIES = (ScopeProvider,)
+
+    @staticmethod
+    def add_args(parser: argparse.ArgumentParser) -> None:
+        parser.add_argument(
+            "--old-name",
+            dest="old_name",
+            metavar="NAME",
+            help="Name of the module-level symbol to rename.",
+            type=str,
+            required=True,
+        )
+        parser.add_argument(
+            "--new-name",
+            dest="new_name",
+            metavar="NAME",
+            help="New name for the symbol.",
+            type=str,
+            required=True,
+        )
+
+    def __init__(self, context: CodemodContext, old_name: str, new_name: str) -> None:
+        super().__init__(context)
+        self.old_name = old_name
+        self.new_name = new_name
+        # Name nodes that should be renamed, and import aliases that should gain an
+        # alias, both keyed by identity of the node in the original tree.
+        self.names: Set[libcst.Name] = set()
+        self.aliases: Set[libcst.ImportAlias] = set()
+        # Strings that name the symbol, in ``__all__`` or as an annotation.
+        self.strings: Set[libcst.SimpleString] = set()
+
+    def _record_import(self, node: Union[libcst.Import, libcst.ImportFrom]) -> None:
+        names = node.names
+        if isinstance(names, libcst.ImportStar):
+            return
+        for alias in names:
+            asname = alias.asname
+            bound = asname.name if asname is not None else alias.name
+            if isinstance(bound, libcst.Name):
+                if bound.value != self.old_name:
+                    continue
+                if asname is not None:
+                    self.names.add(bound)
+                else:
+                    self.aliases.add(alias)
+            elif isinstance(bound, libcst.Attribute):
+                root = bound
+                while isinstance(root, libcst.Attribute):
+                    root = root.value
+                if isinstance(root, libcst.Name) and root.value == self.old_name:
+                    raise SkipFile(
+                        f"'{self.old_name}' is bound by a dotted import, so it "
+                        + "can't be renamed."
+                    )
+
+    def _record_all(self, node: libcst.BaseSmallStatement) -> None:
+        targets: Sequence[libcst.BaseExpression]
+        if isinstance(node, libcst.Assign):
+            targets = [target.target for target in node.targets]
+        elif isinstance(node, (libcst.AnnAssign, libcst.AugAssign)):
+            targets = [node.target]
+        else:
+            return
+        value = node.value
+        if not any(
+            isinstance(target, libcst.Name) and target.value == "__all__"
+            for target in targets
+        ) or not isinstance(value, (libcst.List, libcst.Tuple, libcst.Set)):
+            return
+        for element in value.elements:
+            string = element.value
+            if (
+                isinstance(string, libcst.SimpleString)
+                and string.evaluated_value == self.old_name
+            ):
+                self.strings.add(string)
+
+    def visit_Module(self, node: libcst.Module) -> None:
+        scope = self.get_metadata(ScopeProvider, node)
+        if scope is None:
+            return
+        if any(isinstance(a, Assignment) for a in scope[self.new_name]):
+            raise SkipFile(f"'{self.new_name}' is already defined in this module.")
+        for assignment in scope[self.old_name]:
+            if isinstance(assignment, Assignment):
+                assigned = assignment.node
+                if isinstance(assigned, libcst.Arg):
+                    # Keyword arguments are recorded as assignments by the scope
+                    # provider, but they don't bind the symbol.
+                    continue
+                if isinstance(assigned, libcst.Name):
+                    self.names.add(assigned)
+                elif isinstance(assigned, (libcst.FunctionDef, libcst.ClassDef)):
+                    self.names.add(assigned.name)
+                elif isinstance(assigned, (libcst.Import, libcst.ImportFrom)):
+                    self._record_import(assigned)
+            for access in assignment.references:
+                self.names.add(access.node)
+        if self.names or self.aliases:
+            for statement in node.body:
+                if isinstance(statement, libcst.SimpleStatementLine):
+                    for small in statement.body:
+                        self._record_all(small)
+
+    def visit_ClassDef(self, node: libcst.ClassDef) -> None:
+        if not (self.names or self.aliases):
+            return
+        body = node.body
+        units: List[libcst.CSTNode] = []
+        if isinstance(body, libcst.SimpleStatementSuite):
+            units.extend(body.body)
+        else:
+            for statement in body.body:
+                if isinstance(statement, libcst.SimpleStatementLine):
+                    units.extend(statement.body)
+                else:
+                    units.append(statement)
+        scope = self.get_metadata(ScopeProvider, units[0], None)
+        if not isinstance(scope, ClassScope):
+            return
+        bindings = [
+            assignment.node
+            for assignment in scope.assignments[self.old_name]
+            if isinstance(assignment, Assignment)
+            and not isinstance(assignment.node, libcst.Arg)
+        ]
+        if not bindings:
+            return
+        # The scope provider resolves every read in a class body that binds the name
+        # to the class attribute, but reads before the attribute is bound fall through
+        # to the enclosing scopes.
+        outer = scope.parent
+        while isinstance(outer, ClassScope):
+            outer = outer.parent
+        if not all(
+            isinstance(assignment.scope, GlobalScope)
+            for assignment in outer[self.old_name]
+        ):
+            return
+        unit_nodes = [_collect_nodes(unit) for unit in units]
+
+        def unit_index(target: libcst.CSTNode) -> int:
+            return next(i for i, nodes in enumerate(unit_nodes) if target in nodes)
+
+        first = len(units)
+        for binding in bindings:
+            index = unit_index(binding)
+            unit = units[index]
+            if isinstance(unit, libcst.AnnAssign) and unit.value is None:
+                # A bare annotation doesn't bind the name.
+                continue
+            first = min(first, index)
+        first_unit = units[first] if first < len(units) else None
+        unresolved = isinstance(first_unit, libcst.AugAssign)
+        for access in scope.accesses[self.old_name]:
+            index = unit_index(access.node)
+            if index < first or (
+                index == first and isinstance(first_unit, _BINDS_AFTER_READING)
+            ):
+                self.names.add(access.node)
+            elif index == first or not isinstance(
+                first_unit, _BINDS_AFTER_READING + (libcst.Import, libcst.ImportFrom)
+            ):
+                unresolved = True
+        if unresolved:
+            raise SkipFile(
+                f"Can't tell whether '{self.old_name}' in the body of class "
+                + f"'{node.name.value}' refers to the module-level symbol."
+            )
+
+    def visit_Annotation(self, node: libcst.Annotation) -> None:
+        annotation = node.annotation
+        if (
+            not isinstance(annotation, libcst.SimpleString)
+            or annotation.evaluated_value != self.old_name
+            or not (self.names or self.aliases)
+        ):
+            return
+        # The string refers to the symbol unless a nested scope shadows it.
+        scope = self.get_metadata(ScopeProvider, annotation, None)
+        if scope is not None and all(
+            isinstance(assignment.scope, GlobalScope)
+            for assignment in scope[self.old_name]
+        ):
+            self.strings.add(annotation)
+
+    def visit_Arg(self, node: libcst.Arg) -> None:
+        # The scope provider treats keywords as loads, but they name one of the
+        # callee's parameters rather than referencing the symbol.
+        keyword = node.keyword
+        if keyword is not None:
+            self.names.discard(keyword)
+
+    def leave_Name(
+        self, original_node: libcst.Name, updated_node: libcst.Name
+    ) -> libcst.Name:
+        if original_node in self.names:
+            return updated_node.with_changes(value=self.new_name)
+        return updated_node
+
+    def leave_SimpleString(
+        self, original_node: libcst.SimpleString, updated_node: libcst.SimpleString
+    ) -> libcst.SimpleString:
+        if original_node in self.strings:
+            quote = updated_node.quote
+            return updated_node.with_changes(
+                value=f"{updated_node.prefix}{quote}{self.new_name}{quote}"
+            )
+        return updated_node
+
+    def leave_ImportAlias(
+        self, original_node: libcst.ImportAlias, updated_node: libcst.ImportAlias
+    ) -> libcst.ImportAlias:
+        if original_node in self.aliases:
+            return updated_node.with_changes(
+                asname=libcst.AsName(libcst.Name(self.new_name))
+            )
+        return updated_node
+
+    def leave_Global(
+        self, original_node: libcst.Global, updated_node: libcst.Global
+    ) -> libcst.Global:
+        # A ``global`` declaration always refers to a module-level symbol, unlike a
+        # ``nonlocal`` one, which refers to an enclosing function's scope.
+        if not self.names and not self.aliases:
+            return updated_node
+        return updated_node.with_changes(
+            names=[
+                item.with_changes(name=libcst.Name(self.new_name))
+                if item.name.value == self.old_name
+                else item
+                for item in updated_node.names
+            ]
+        )
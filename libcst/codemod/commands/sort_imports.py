@@ -0,0 +1,213 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import argparse
+import importlib.util
+import os.path
+import sys
+import sysconfig
+from functools import lru_cache
+from typing import Callable, Dict, List, Optional, Sequence, Tuple, Union
+
+import libcst
+from libcst.codemod import CodemodCommand, CodemodContext
+from libcst.helpers import get_full_name_for_node
+
+
+_ImportT = Union[libcst.Import, libcst.ImportFrom]
+
+_FUTURE = 0
+_STDLIB = 1
+_THIRD_PARTY = 2
+_FIRST_PARTY = 3
+
+
+@lru_cache(maxsize=None)
+def _is_stdlib(name: str) -> bool:
+    """
+    Returns whether ``name`` is a top-level module of the standard library of the
+    running interpreter.
+    """
+    if name in sys.builtin_module_names:
+        return True
+    try:
+        spec = importlib.util.find_spec(name)
+    except (ImportError, ValueError):
+        return False
+    if spec is None:
+        return False
+    origin = spec.origin
+    if origin in ("built-in", "frozen"):
+        return True
+    if origin is None:
+        return False
+    stdlib = os.path.realpath(sysconfig.get_paths()["stdlib"])
+    path = os.path.realpath(origin)
+    return path.startswith(stdlib + os.sep) and "site-packages" not in path
+
+
+def _import_line(stmt: libcst.BaseStatement) -> Optional[_ImportT]:
+    if isinstance(stmt, libcst.SimpleStatementLine) and len(stmt.body) == 1:
+        small_stmt = stmt.body[0]
+        if isinstance(small_stmt, (libcst.Import, libcst.ImportFrom)):
+            return small_stmt
+    return None
+
+
+def _name_key(name: str) -> Tuple[int, str]:
+    # Like isort, order constants before classes before everything else.
+    if name.isupper() and len(name) > 1:
+        return (0, name.lower())
+    if name[:1].isupper():
+        return (1, name.lower())
+    return (2, name.lower())
+
+
+def _sort_aliases(
+    aliases: Sequence[libcst.ImportAlias], key: Callable[[str], object]
+) -> List[libcst.ImportAlias]:
+    # Commas stay where they are, so that the layout of the names is preserved.
+    commas = [alias.comma for alias in aliases]
+    ordered = sorted(aliases, key=lambda alias: key(alias.evaluated_name))
+    return [alias.with_changes(comma=comma) for alias, comma in zip(ordered, commas)]
+
+
+class SortImportsCommand(CodemodCommand):
+    """
+    Sorts the block of imports at the top of a module the way ``isort`` does by
+    default. Imports are grouped into ``__future__`` imports, standard library
+    imports, third-party imports and first-party imports, with one blank line
+    between the groups. Within a group, ``import`` statements come before ``from``
+    imports, and both are sorted by module name. The names imported by a single
+    statement are sorted too, with constants first and classes second.
+
+    Standard library modules are detected using the running interpreter, relative
+    imports are always first-party, and any other module is third-party unless it is
+    listed with ``--known-first-party``. Only the first run of consecutive import
+    statements in the module is sorted. Comments above an import move along with it.
+    """
+
+    DESCRIPTION: str = "Sorts and groups the imports at the top of a module."
+
+    @staticmethod
+    def add_args(parser: argparse.ArgumentParser) -> None:
+        parser.add_argument(
+            "--known-first-party",
+            dest="known_first_party",
+            metavar="MODULE",
+            help="Top-level packages that belong to the project being sorted.",
+            type=str,
+            nargs="*",
+            default=(),
+        )
+        parser.add_argument(
+            "--known-third-party",
+            dest="known_third_party",
+            metavar="MODULE",
+            help="Top-level packages that should always be treated as third-party.",
+            type=str,
+            nargs="*",
+            default=(),
+        )
+
+    def __init__(
+        self,
+        context: CodemodContext,
+        known_first_party: Sequence[str] = (),
+        known_third_party: Sequence[str] = (),
+    ) -> None:
+        super().__init__(context)
+        self.known_first_party = set(known_first_party)
+        self.known_third_party = set(known_third_party)
+
+    def _group(self, node: _ImportT) -> int:
+        if isinstance(node, libcst.ImportFrom):
+            if node.relative:
+                return _FIRST_PARTY
+            module = node.module
+            name = get_full_name_for_node(module) if module is not None else None
+        else:
+            name = node.names[0].evaluated_name
+        top_level = (name or "").split(".")[0]
+        if top_level == "__future__":
+            return _FUTURE
+        if top_level in self.known_first_party:
+            return _FIRST_PARTY
+        if top_level in self.known_third_party:
+            return _THIRD_PARTY
+        if _is_stdlib(top_level):
+            return _STDLIB
+        return _THIRD_PARTY
+
+    @staticmethod
+    def _sorted(node: _ImportT) -> _ImportT:
+        names = node.names
+        if isinstance(names, libcst.ImportStar):
+            return node
+        if isinstance(node, libcst.Import):
+            return node.with_changes(names=_sort_aliases(names, str.lower))
+        return node.with_changes(names=_sort_aliases(names, _name_key))
+
+    @staticmethod
+    def _key(node: _ImportT) -> Tuple[int, str, str]:
+        if isinstance(node, libcst.Import):
+            name = node.names[0].evaluated_name
+            return (0, name.lower(), name)
+        module = node.module
+        module_name = get_full_name_for_node(module) if module is not None else None
+        name = "." * len(node.relative) + (module_name or "")
+        return (1, name.lower(), name)
+
+    @staticmethod
+    def _strip_blank_lines(
+        leading_lines: Sequence[libcst.EmptyLine],
+    ) -> Tuple[Sequence[libcst.EmptyLine], Sequence[libcst.EmptyLine]]:
+        blank_count = 0
+        while (
+            blank_count < len(leading_lines)
+            and leading_lines[blank_count].comment is None
+        ):
+            blank_count += 1
+        return leading_lines[:blank_count], leading_lines[blank_count:]
+
+    def transform_module_impl(self, tree: libcst.Module) -> libcst.Module:
+        body = list(tree.body)
+        start = 0
+        while start < len(body) and _import_line(body[start]) is None:
+            start += 1
+        end = start
+        while end < len(body) and _import_line(body[end]) is not None:
+            end += 1
+        if start == end:
+            return tree
+
+        groups: Dict[int, List[Tuple[Tuple[int, str, str], libcst.BaseStatement]]] = {}
+        for stmt in body[start:end]:
+            node = _import_line(stmt)
+            if node is None:
+                raise Exception("Logic error, statement should be an import!")
+            node = self._sorted(node)
+            _, comments = self._strip_blank_lines(stmt.leading_lines)
+            groups.setdefault(self._group(node), []).append(
+                (
+                    self._key(node),
+                    stmt.with_changes(body=[node], leading_lines=comments),
+                )
+            )
+
+        blank_lines, _ = self._strip_blank_lines(body[start].leading_lines)
+        sorted_imports: List[libcst.BaseStatement] = []
+        for group in sorted(groups):
+            for i, (_, stmt) in enumerate(sorted(groups[group], key=lambda s: s[0])):
+                if i == 0:
+                    separator = (
+                        blank_lines if not sorted_imports else [libcst.EmptyLine()]
+                    )
+                    stmt = stmt.with_changes(
+                        leading_lines=[*separator, *stmt.leading_lines]
+                    )
+                sorted_imports.append(stmt)
+        return tree.with_changes(body=[*body[:start], *sorted_imports, *body[end:]])
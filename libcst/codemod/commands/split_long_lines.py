@@ -0,0 +1,214 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import argparse
+from typing import List, Optional, Sequence, TypeVar, Union
+
+import libcst
+from libcst import MaybeSentinel
+from libcst.codemod import CodemodContext, VisitorBasedCodemodCommand
+
+
+_SplittableT = Union[
+    libcst.Call,
+    libcst.List,
+    libcst.Set,
+    libcst.Tuple,
+    libcst.Dict,
+    libcst.ImportFrom,
+]
+_SPLITTABLE = (
+    libcst.Call,
+    libcst.List,
+    libcst.Set,
+    libcst.Tuple,
+    libcst.Dict,
+    libcst.ImportFrom,
+)
+_ElementT = TypeVar(
+    "_ElementT",
+    libcst.Arg,
+    libcst.BaseElement,
+    libcst.BaseDictElement,
+    libcst.ImportAlias,
+)
+
+
+class SplitLongLinesCommand(VisitorBasedCodemodCommand):
+    """
+    Splits simple statements that are longer than the maximum line length by
+    putting each argument of a call, each element of a list, set, tuple or dict
+    literal, or each name of a ``from`` import on its own line, indented one level
+    further than the statement, and adding a trailing comma after the last one.
+    The outermost of these constructs in the statement is split first, and then any
+    of its lines that are still too long are split the same way.
+
+    Statements that already span multiple lines are left alone, as are compound
+    statement headers such as ``def`` and ``if`` lines. A line might still be too
+    long afterwards if it doesn't contain anything that can be split.
+    """
+
+    DESCRIPTION: str = "Splits lines that are longer than the maximum line length."
+
+    @staticmethod
+    def add_args(parser: argparse.ArgumentParser) -> None:
+        parser.add_argument(
+            "--max-length",
+            dest="max_length",
+            metavar="LENGTH",
+            help="Maximum number of characters in a line.",
+            type=int,
+            default=88,
+        )
+
+    def __init__(self, context: CodemodContext, max_length: int = 88) -> None:
+        super().__init__(context)
+        self.max_length = max_length
+        self.indents: List[str] = []
+
+    def visit_IndentedBlock(self, node: libcst.IndentedBlock) -> None:
+        indent = node.indent
+        self.indents.append(self.module.default_indent if indent is None else indent)
+
+    def leave_IndentedBlock(
+        self, original_node: libcst.IndentedBlock, updated_node: libcst.IndentedBlock
+    ) -> libcst.IndentedBlock:
+        self.indents.pop()
+        return updated_node
+
+    def _whitespace(self, level: int) -> libcst.ParenthesizedWhitespace:
+        return libcst.ParenthesizedWhitespace(
+            indent=True,
+            last_line=libcst.SimpleWhitespace(self.module.default_indent * level),
+        )
+
+    @staticmethod
+    def _elements(node: _SplittableT) -> Sequence[libcst.CSTNode]:
+        if isinstance(node, libcst.Call):
+            return node.args
+        if isinstance(node, libcst.ImportFrom):
+            names = node.names
+            return () if isinstance(names, libcst.ImportStar) else names
+        return node.elements
+
+    @classmethod
+    def _is_splittable(cls, node: _SplittableT) -> bool:
+        if isinstance(node, libcst.Tuple) and not node.lpar:
+            return False
+        if isinstance(node, libcst.Call) and any(
+            # A trailing comma isn't allowed after a bare generator argument.
+            isinstance(arg.value, libcst.GeneratorExp) and not arg.value.lpar
+            for arg in node.args
+        ):
+            return False
+        return len(cls._elements(node)) > 0
+
+    def _outermost(self, node: libcst.CSTNode) -> Optional[_SplittableT]:
+        """
+        Returns the longest of the least deeply nested splittable nodes in ``node``.
+        """
+        level: List[libcst.CSTNode] = [node]
+        while level:
+            candidates: List[_SplittableT] = [
+                child
+                for child in level
+                if isinstance(child, _SPLITTABLE) and self._is_splittable(child)
+            ]
+            if candidates:
+                return max(candidates, key=lambda n: len(self.module.code_for_node(n)))
+            level = [
+                child
+                for parent in level
+                # Expressions inside of an f-string can't contain newlines.
+                if not isinstance(parent, libcst.FormattedString)
+                for child in parent.children
+            ]
+        return None
+
+    def _with_comma(self, element: _ElementT, level: int) -> _ElementT:
+        comma = libcst.Comma(whitespace_after=self._whitespace(level))
+        if isinstance(element, libcst.Arg):
+            return element.with_changes(
+                comma=comma, whitespace_after_arg=libcst.SimpleWhitespace("")
+            )
+        return element.with_changes(comma=comma)
+
+    def _layout(
+        self, node: _SplittableT, elements: Sequence[libcst.CSTNode], level: int
+    ) -> _SplittableT:
+        elements = [
+            self._with_comma(element, level if i < len(elements) - 1 else level - 1)
+            for i, element in enumerate(elements)
+        ]
+        opening = self._whitespace(level)
+        closing = libcst.SimpleWhitespace("")
+        if isinstance(node, libcst.Call):
+            return node.with_changes(whitespace_before_args=opening, args=elements)
+        if isinstance(node, libcst.ImportFrom):
+            return node.with_changes(
+                lpar=libcst.LeftParen(whitespace_after=opening),
+                names=elements,
+                rpar=libcst.RightParen(whitespace_before=closing),
+            )
+        if isinstance(node, libcst.Tuple):
+            # The elements are inside of the innermost pair of parentheses.
+            return node.with_changes(
+                lpar=[
+                    *node.lpar[:-1],
+                    node.lpar[-1].with_changes(whitespace_after=opening),
+                ],
+                elements=elements,
+                rpar=[
+                    node.rpar[0].with_changes(whitespace_before=closing),
+                    *node.rpar[1:],
+                ],
+            )
+        if isinstance(node, libcst.List):
+            return node.with_changes(
+                lbracket=node.lbracket.with_changes(whitespace_after=opening),
+                elements=elements,
+                rbracket=node.rbracket.with_changes(whitespace_before=closing),
+            )
+        return node.with_changes(
+            lbrace=node.lbrace.with_changes(whitespace_after=opening),
+            elements=elements,
+            rbrace=node.rbrace.with_changes(whitespace_before=closing),
+        )
+
+    def _split(self, node: _SplittableT, level: int, indent: int) -> _SplittableT:
+        elements: List[libcst.CSTNode] = []
+        for element in self._elements(node):
+            code = self.module.code_for_node(
+                element.with_changes(comma=MaybeSentinel.DEFAULT)
+            ).strip()
+            # Each element ends up on its own line, followed by a comma.
+            length = indent + len(self.module.default_indent) * level + len(code) + 1
+            if length > self.max_length:
+                inner = self._outermost(element)
+                if inner is not None:
+                    element = element.deep_replace(
+                        inner, self._split(inner, level + 1, indent)
+                    )
+            elements.append(element)
+        return self._layout(node, elements, level)
+
+    def leave_SimpleStatementLine(
+        self,
+        original_node: libcst.SimpleStatementLine,
+        updated_node: libcst.SimpleStatementLine,
+    ) -> libcst.SimpleStatementLine:
+        line = self.module.code_for_node(
+            updated_node.with_changes(leading_lines=())
+        ).rstrip("\r\n")
+        if "\n" in line or "\r" in line:
+            return updated_node
+        indent = len("".join(self.indents))
+        if indent + len(line) <= self.max_length:
+            return updated_node
+        target = self._outermost(updated_node)
+        if target is None:
+            return updated_node
+        return updated_node.deep_replace(target, self._split(target, 1, indent))
@@ -0,0 +1,131 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Type
+
+from libcst.codemod import Codemod, CodemodTest
+from libcst.codemod.commands.add_future_annotations import (
+    AddFutureAnnotationsCommand,
+)
+
+
+class TestAddFutureAnnotationsCommand(CodemodTest):
+    TRANSFORM: Type[Codemod] = AddFutureAnnotationsCommand
+
+    def test_noop(self) -> None:
+        before = """
+            from __future__ import annotations
+
+            def foo(a: Class, *args: int) -> Class:
+                return a
+        """
+        self.assertCodemod(before, before)
+
+    def test_adds_import(self) -> None:
+        before = """
+            import os
+
+            def foo(a: int) -> int:
+                return a
+        """
+        after = """
+            from __future__ import annotations
+            import os
+
+            def foo(a: int) -> int:
+                return a
+        """
+        self.assertCodemod(before, after)
+
+    def test_docstring(self) -> None:
+        before = """
+            \"\"\"doc\"\"\"
+            import os
+
+            def foo(a: int) -> int:
+                return a
+        """
+        after = """
+            \"\"\"doc\"\"\"
+            from __future__ import annotations
+            import os
+
+            def foo(a: int) -> int:
+                return a
+        """
+        self.assertCodemod(before, after)
+
+    def test_docstring_only(self) -> None:
+        before = """
+            \"\"\"doc\"\"\"
+            x = 1
+        """
+        after = """
+            \"\"\"doc\"\"\"
+            from __future__ import annotations
+
+            x = 1
+        """
+        self.assertCodemod(before, after)
+
+    def test_unquotes(self) -> None:
+        before = """
+            class Class:
+                def method(self, other: "Class", *args: "int", **kw: 'str') -> "Class":
+                    x: "Class" = other
+                    return x
+
+            async def foo(a: "List[Class]" = None, *, b: "Class" "Type") -> None:
+                pass
+        """
+        after = """
+            from __future__ import annotations
+
+            class Class:
+                def method(self, other: Class, *args: int, **kw: str) -> Class:
+                    x: "Class" = other
+                    return x
+
+            async def foo(a: List[Class] = None, *, b: ClassType) -> None:
+                pass
+        """
+        self.assertCodemod(before, after)
+
+    def test_type_arguments(self) -> None:
+        before = """
+            def foo(
+                a: List["Class"],
+                b: Dict[str, Optional["Class"]],
+                c: typing.Literal["a", "b"],
+                d: Annotated["Class", "metadata"],
+            ) -> "Optional[Literal['x']]":
+                pass
+        """
+        after = """
+            from __future__ import annotations
+
+            def foo(
+                a: List[Class],
+                b: Dict[str, Optional[Class]],
+                c: typing.Literal["a", "b"],
+                d: Annotated[Class, "metadata"],
+            ) -> Optional[Literal['x']]:
+                pass
+        """
+        self.assertCodemod(before, after)
+
+    def test_invalid_string(self) -> None:
+        before = """
+            def foo(a: "not valid(") -> "":
+                pass
+        """
+        after = """
+            from __future__ import annotations
+
+            def foo(a: "not valid(") -> "":
+                pass
+        """
+        self.assertCodemod(before, after)
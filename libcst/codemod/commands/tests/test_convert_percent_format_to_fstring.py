@@ -47,3 +47,62 @@ class ConvertPercentFormatStringCommandTest(CodemodTest):
         self.assertCodemod(code, code)
         code = 'b"a type %s" % var'
         self.assertCodemod(code, code)
+        code = 'rb"a type %s" % var'
+        self.assertCodemod(code, code)
+        code = '"%s %s" % (a,)'
+        self.assertCodemod(code, code)
+        code = '"%s" % (*args,)'
+        self.assertCodemod(code, code)
+        code = '"%x" % val'
+        self.assertCodemod(code, code)
+        code = '"%*d" % (width, val)'
+        self.assertCodemod(code, code)
+        code = '"%.3d" % val'
+        self.assertCodemod(code, code)
+        code = '"%#f" % val'
+        self.assertCodemod(code, code)
+        code = "fmt % val"
+        self.assertCodemod(code, code)
+        code = '"100%%" % ()'
+        self.assertCodemod(code, code)
+        code = '"%s" % {"a": 1}'
+        self.assertCodemod(code, code)
+
+    def test_conversions(self) -> None:
+        self.assertCodemod('"%r" % val', 'f"{val!r}"')
+        self.assertCodemod('"%d items" % count', 'f"{int(count)} items"')
+        self.assertCodemod('"%i" % count', 'f"{int(count)}"')
+        self.assertCodemod('"%f" % val', 'f"{val:f}"')
+        self.assertCodemod('"%.2f" % val', 'f"{val:.2f}"')
+        self.assertCodemod('"%-8.2f|" % val', 'f"{val:<8.2f}|"')
+        self.assertCodemod('"%+05d" % val', 'f"{int(val):+05d}"')
+        self.assertCodemod('"% d" % val', 'f"{int(val): d}"')
+        self.assertCodemod('"%10s" % val', 'f"{val!s:>10}"')
+        self.assertCodemod('"%-10r" % val', 'f"{val!r:<10}"')
+        self.assertCodemod('"%.3s" % val', 'f"{val!s:.3}"')
+        self.assertCodemod('"%d%% done" % pct', 'f"{int(pct)}% done"')
+        self.assertCodemod('u"%s" % val', 'f"{val}"')
+        self.assertCodemod('"%s" % (lambda: 1)', 'f"{(lambda: 1)}"')
+        self.assertCodemod(
+            '"%s: %r (%.1f%%)" % (name, value, ratio)',
+            'f"{name}: {value!r} ({ratio:.1f}%)"',
+        )
+
+    def test_named(self) -> None:
+        self.assertCodemod(
+            '"%(name)s is %(age)d" % {"name": user.name, "age": user.age}',
+            'f"{user.name} is {int(user.age)}"',
+        )
+        self.assertCodemod('"%(x)s, %(x)r" % {"x": x}', 'f"{x}, {x!r}"')
+        code = '"%(x)s, %(x)r" % {"x": get_x()}'
+        self.assertCodemod(code, code)
+        code = '"%(x)s" % {"x": x, "y": y}'
+        self.assertCodemod(code, code)
+        code = '"%(x)s %(y)s" % {"x": x}'
+        self.assertCodemod(code, code)
+        code = '"%(x)s" % {**values}'
+        self.assertCodemod(code, code)
+        code = '"%(x)s" % values'
+        self.assertCodemod(code, code)
+        code = '"%(x)s %s" % {"x": x}'
+        self.assertCodemod(code, code)
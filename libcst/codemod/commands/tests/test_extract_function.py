@@ -0,0 +1,264 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from libcst.codemod import CodemodTest
+from libcst.codemod.commands.extract_function import ExtractFunctionCommand
+
+
+class ExtractFunctionCommandTest(CodemodTest):
+    TRANSFORM = ExtractFunctionCommand
+
+    def assertExtract(
+        self, before: str, after: str, start: str, end: str, expected_skip: bool = False
+    ) -> None:
+        # Offsets are computed from the start of ``start`` to the end of ``end`` in
+        # the normalized fixture, since that's what the codemod will see.
+        code = CodemodTest.make_fixture_data(before).encode("utf-8")
+        self.assertCodemod(
+            before,
+            after,
+            start_offset=code.index(start.encode("utf-8")),
+            end_offset=code.index(end.encode("utf-8")) + len(end.encode("utf-8")),
+            name="helper",
+            expected_skip=expected_skip,
+        )
+
+    def test_parameters_and_return_value(self) -> None:
+        before = """
+            import os
+
+
+            # about foo
+            def foo(a, b):
+                x = a + 1
+                # comment
+                y = x * b
+                for i in range(y):
+                    if i:
+                        break
+                z = os.path.join(str(y), "é")
+                return z
+        """
+        after = """
+            import os
+
+
+            def helper(x, b):
+                y = x * b
+                for i in range(y):
+                    if i:
+                        break
+                return y
+
+
+            # about foo
+            def foo(a, b):
+                x = a + 1
+                # comment
+                y = helper(x, b)
+                z = os.path.join(str(y), "é")
+                return z
+        """
+        self.assertExtract(before, after, "y = x", "break")
+
+    def test_multiple_return_values(self) -> None:
+        before = """
+            def foo(a):
+                x = a + 1
+                y = a - 1
+                print(x, y)
+        """
+        after = """
+            def helper(a):
+                x = a + 1
+                y = a - 1
+                return x, y
+
+
+            def foo(a):
+                x, y = helper(a)
+                print(x, y)
+        """
+        self.assertExtract(before, after, "x = a", "a - 1")
+
+    def test_no_parameters_or_return_value(self) -> None:
+        before = """
+            def foo():
+                print("hello")
+                print("world")
+        """
+        after = """
+            def helper():
+                print("hello")
+                print("world")
+
+
+            def foo():
+                helper()
+        """
+        self.assertExtract(before, after, 'print("hello")', 'print("world")')
+
+    def test_module_level(self) -> None:
+        before = """
+            import os
+
+            x = 1
+            y = x + 1
+            print(y, os)
+        """
+        after = """
+            import os
+
+            x = 1
+
+
+            def helper():
+                y = x + 1
+                return y
+
+
+            y = helper()
+            print(y, os)
+        """
+        self.assertExtract(before, after, "y = x", "x + 1")
+
+    def test_nested_block(self) -> None:
+        before = """
+            def foo(items):
+                for item in items:
+                    if item:
+                        total = item * 2
+                        print(total)
+        """
+        after = """
+            def helper(item):
+                total = item * 2
+                print(total)
+
+
+            def foo(items):
+                for item in items:
+                    if item:
+                        helper(item)
+        """
+        self.assertExtract(before, after, "total = item", "print(total)")
+
+    def test_augmented_assignment(self) -> None:
+        before = """
+            def foo(items):
+                total = 0
+                for i in items:
+                    total += i
+                return total
+        """
+        after = """
+            def helper(items, total):
+                for i in items:
+                    total += i
+                return total
+
+
+            def foo(items):
+                total = 0
+                total = helper(items, total)
+                return total
+        """
+        self.assertExtract(before, after, "for i", "+= i")
+
+    def test_conditional_assignment(self) -> None:
+        before = """
+            def foo(c):
+                x = 1
+                if c:
+                    x = 2
+                return x
+        """
+        after = """
+            def helper(c, x):
+                if c:
+                    x = 2
+                return x
+
+
+            def foo(c):
+                x = 1
+                x = helper(c, x)
+                return x
+        """
+        self.assertExtract(before, after, "if c", "x = 2")
+
+    def test_module_level_unused_assignment(self) -> None:
+        before = """
+            A = 1
+            B = A + 1
+        """
+        after = """
+            A = 1
+
+
+            def helper():
+                B = A + 1
+                return B
+
+
+            B = helper()
+        """
+        self.assertExtract(before, after, "B = A", "A + 1")
+
+    def test_class_level(self) -> None:
+        before = """
+            class Foo:
+                x = 1
+                y = x + 1
+        """
+        after = """
+            def helper(x):
+                y = x + 1
+                return y
+
+
+            class Foo:
+                x = 1
+                y = helper(x)
+        """
+        self.assertExtract(before, after, "y = x", "x + 1")
+
+    def test_skip_partial_statement(self) -> None:
+        before = """
+            def foo(a):
+                x = a + 1
+                y = a - 1
+        """
+        self.assertExtract(before, before, "a + 1", "a - 1", expected_skip=True)
+
+    def test_skip_return(self) -> None:
+        before = """
+            def foo(a):
+                x = a + 1
+                return x
+        """
+        self.assertExtract(before, before, "x = a", "return x", expected_skip=True)
+
+    def test_skip_loop_control(self) -> None:
+        before = """
+            def foo(a):
+                for i in a:
+                    print(i)
+                    continue
+        """
+        self.assertExtract(before, before, "print(i)", "continue", expected_skip=True)
+
+    def test_skip_existing_name(self) -> None:
+        before = """
+            def helper():
+                pass
+
+            def foo():
+                print("hello")
+        """
+        self.assertExtract(
+            before, before, 'print("hello")', 'print("hello")', expected_skip=True
+        )
@@ -0,0 +1,126 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from libcst.codemod import CodemodTest
+from libcst.codemod.commands.inline_function import InlineFunctionCommand
+
+
+class InlineFunctionCommandTest(CodemodTest):
+    TRANSFORM = InlineFunctionCommand
+
+    def test_substitute_arguments(self) -> None:
+        before = """
+            def add(a, b=1):
+                \"\"\"Adds two numbers.\"\"\"
+                return a + b
+
+            x = add(3)
+            y = add(x, b=2) * 2
+            print(add(x, x))
+        """
+        after = """
+            def add(a, b=1):
+                \"\"\"Adds two numbers.\"\"\"
+                return a + b
+
+            x = 3 + 1
+            y = (x + 2) * 2
+            print(x + x)
+        """
+        self.assertCodemod(before, after, name="add")
+
+    def test_side_effectful_arguments(self) -> None:
+        before = """
+            def twice(a):
+                return a + a
+
+            def foo():
+                # comment
+                return twice(bar()) + twice(baz())
+        """
+        after = """
+            def twice(a):
+                return a + a
+
+            def foo():
+                # comment
+                _a = bar()
+                _a2 = baz()
+                return (_a + _a) + (_a2 + _a2)
+        """
+        self.assertCodemod(before, after, name="twice")
+
+    def test_unchanged_call_sites(self) -> None:
+        before = """
+            def twice(a):
+                return a + a
+
+            x = twice(*args)
+            y = twice(1, 2)
+            if cond: z = twice(bar())
+            w = cond or twice(bar())
+
+            def foo(twice):
+                return twice(1)
+        """
+        after = """
+            def twice(a):
+                return a + a
+
+            x = twice(*args)
+            y = twice(1, 2)
+            if cond: z = twice(bar())
+            w = cond or twice(bar())
+
+            def foo(twice):
+                return twice(1)
+        """
+        self.assertCodemod(before, after, name="twice")
+
+    def test_captured_names(self) -> None:
+        before = """
+            def pair(x):
+                return [(x, y) for y in range(3)]
+
+            def foo(y, z):
+                return pair(y), pair(z)
+        """
+        after = """
+            def pair(x):
+                return [(x, y) for y in range(3)]
+
+            def foo(y, z):
+                _x = y
+                return [(_x, y) for y in range(3)], [(z, y) for y in range(3)]
+        """
+        self.assertCodemod(before, after, name="pair")
+
+    def test_skip_multiple_returns(self) -> None:
+        before = """
+            def sign(a):
+                if a < 0:
+                    return -1
+                return 1
+
+            x = sign(3)
+        """
+        self.assertCodemod(before, before, name="sign", expected_skip=True)
+
+    def test_skip_multiple_statements(self) -> None:
+        before = """
+            def foo(a):
+                print(a)
+                return a
+
+            x = foo(3)
+        """
+        self.assertCodemod(before, before, name="foo", expected_skip=True)
+
+    def test_skip_missing_function(self) -> None:
+        before = """
+            x = foo(3)
+        """
+        self.assertCodemod(before, before, name="foo", expected_skip=True)
@@ -0,0 +1,145 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Type
+
+from libcst.codemod import Codemod, CodemodTest
+from libcst.codemod.commands.normalize_blank_lines import NormalizeBlankLinesCommand
+
+
+class TestNormalizeBlankLinesCommand(CodemodTest):
+    TRANSFORM: Type[Codemod] = NormalizeBlankLinesCommand
+
+    def test_noop(self) -> None:
+        before = """
+            import os
+
+
+            class Foo:
+                x = 1
+
+                def bar(self) -> None:
+                    pass
+
+
+            def baz() -> None:
+                pass
+        """
+        after = """
+            import os
+
+
+            class Foo:
+                x = 1
+
+                def bar(self) -> None:
+                    pass
+
+
+            def baz() -> None:
+                pass
+        """
+        self.assertCodemod(before, after)
+
+    def test_add_and_remove_lines(self) -> None:
+        before = """
+            import os
+            class Foo:
+                x = 1
+
+
+
+                def bar(self) -> None:
+                    pass
+                @property
+                def baz(self) -> int:
+                    return 1
+            def qux() -> None:
+                pass
+        """
+        after = """
+            import os
+
+
+            class Foo:
+                x = 1
+
+                def bar(self) -> None:
+                    pass
+
+                @property
+                def baz(self) -> int:
+                    return 1
+
+
+            def qux() -> None:
+                pass
+        """
+        self.assertCodemod(before, after)
+
+    def test_comments_and_nested_functions_untouched(self) -> None:
+        before = """
+            # header comment
+
+            import os
+
+            # attached comment
+
+            def foo() -> None:
+                x = 1
+                def bar() -> None:
+                    pass
+
+
+
+                class Baz:
+                    a = 1
+                    def qux(self) -> None:
+                        pass
+        """
+        after = """
+            # header comment
+
+            import os
+
+
+            # attached comment
+
+            def foo() -> None:
+                x = 1
+                def bar() -> None:
+                    pass
+
+
+
+                class Baz:
+                    a = 1
+                    def qux(self) -> None:
+                        pass
+        """
+        self.assertCodemod(before, after)
+
+    def test_custom_counts(self) -> None:
+        before = """
+            import os
+            class Foo:
+                x = 1
+                def bar(self) -> None:
+                    pass
+        """
+        after = """
+            import os
+
+
+
+            class Foo:
+                x = 1
+
+
+                def bar(self) -> None:
+                    pass
+        """
+        self.assertCodemod(before, after, top_level=3, method=2)
@@ -0,0 +1,40 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Type
+
+from libcst.codemod import Codemod, CodemodTest
+from libcst.codemod.commands.normalize_indentation import NormalizeIndentationCommand
+
+
+class TestNormalizeIndentationCommand(CodemodTest):
+    TRANSFORM: Type[Codemod] = NormalizeIndentationCommand
+
+    def test_noop(self) -> None:
+        before = """
+            def foo(a):
+                if a:
+                    return a
+        """
+        after = """
+            def foo(a):
+                if a:
+                    return a
+        """
+        self.assertCodemod(before, after)
+
+    def test_reindent(self) -> None:
+        before = "def foo(a):\n  if a:\n   # comment\n     return a\n  return (\n 1)\n"
+        after = (
+            "def foo(a):\n    if a:\n        # comment\n        return a\n"
+            + "    return (\n 1)\n"
+        )
+        self.assertCodemod(before, after)
+
+    def test_mixed_indentation(self) -> None:
+        before = "class Foo:\n\tdef foo(self):\n\t  pass\n"
+        after = "class Foo:\n    def foo(self):\n        pass\n"
+        self.assertCodemod(before, after)
@@ -0,0 +1,98 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Type
+
+from libcst.codemod import Codemod, CodemodTest
+from libcst.codemod.commands.normalize_quotes import NormalizeQuotesCommand
+
+
+class TestNormalizeQuotesCommand(CodemodTest):
+    TRANSFORM: Type[Codemod] = NormalizeQuotesCommand
+
+    def test_double_quotes(self) -> None:
+        before = r"""
+            x = 'foo'
+            y = b'bar' + R'\d' + ''
+            z = 'it\'s' + 'a\\' + 'b\"c'
+        """
+        after = r"""
+            x = "foo"
+            y = b"bar" + R"\d" + ""
+            z = "it's" + "a\\" + "b\"c"
+        """
+        self.assertCodemod(before, after)
+
+    def test_single_quotes(self) -> None:
+        before = r"""
+            x = "foo"
+            y = "it's" + "say \"hi\"" + "\'"
+        """
+        after = r"""
+            x = 'foo'
+            y = "it's" + 'say "hi"' + '\''
+        """
+        self.assertCodemod(before, after, style="single")
+
+    def test_preserve(self) -> None:
+        before = r"""
+            x = 'foo' + "bar" + '''baz''' + f'{x}'
+        """
+        after = r"""
+            x = 'foo' + "bar" + '''baz''' + f'{x}'
+        """
+        self.assertCodemod(before, after, style="preserve")
+
+    def test_more_escapes(self) -> None:
+        before = r"""
+            x = 'say "hi"'
+            y = r'say "hi"'
+            z = r'\''
+        """
+        after = r"""
+            x = 'say "hi"'
+            y = r'say "hi"'
+            z = r"\'"
+        """
+        self.assertCodemod(before, after)
+
+    def test_triple_quotes(self) -> None:
+        before = (
+            "def foo():\n"
+            + "    '''Say \"hi\".'''\n"
+            + "    return '''a\"\"\" b''' + '''it's''' + f'''{x}'''\n"
+        )
+        after = (
+            "def foo():\n"
+            + '    """Say "hi"."""\n'
+            + "    return '''a\"\"\" b''' + \"\"\"it's\"\"\" + f\"\"\"{x}\"\"\"\n"
+        )
+        self.assertCodemod(before, after)
+
+    def test_formatted_strings(self) -> None:
+        before = r"""
+            a = f'{x} isn\'t {y!r:>{width}}'
+            b = f'{x["key"]}' + f'{x:"^10}'
+            c = rf'{x}\''
+            d = f'{f"{x}"}'
+        """
+        after = r"""
+            a = f"{x} isn't {y!r:>{width}}"
+            b = f'{x["key"]}' + f'{x:"^10}'
+            c = rf"{x}\'"
+            d = f'{f"{x}"}'
+        """
+        self.assertCodemod(before, after)
+
+    def test_strings_in_formatted_strings(self) -> None:
+        before = """
+            a = f"{d['k']}"
+            b = f'{d["k"]}'
+            c = f'{f"{y}"}'
+            d = f"{f'{y}'}"
+        """
+        self.assertCodemod(before, before)
+        self.assertCodemod(before, before, style="single")
@@ -0,0 +1,87 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Type
+
+from libcst.codemod import Codemod, CodemodTest
+from libcst.codemod.commands.normalize_trailing_commas import (
+    NormalizeTrailingCommasCommand,
+)
+
+
+class TestNormalizeTrailingCommasCommand(CodemodTest):
+    TRANSFORM: Type[Codemod] = NormalizeTrailingCommasCommand
+
+    def test_multiline(self) -> None:
+        before = """
+            from foo import (
+                bar,
+                baz
+            )
+            x = call(
+                a,
+                b
+            )
+            y = [a, b,] + [a, b, ] + (a,) + {a: b,}
+            z = {
+                a,
+                b}
+        """
+        after = """
+            from foo import (
+                bar,
+                baz,
+            )
+            x = call(
+                a,
+                b,
+            )
+            y = [a, b] + [a, b ] + (a,) + {a: b}
+            z = {
+                a,
+                b}
+        """
+        self.assertCodemod(before, after)
+
+    def test_always(self) -> None:
+        before = """
+            from foo import bar, baz
+            from foo import (bar, baz)
+            x = call(a, *b, **c) + call() + call(x for x in y)
+            y = [a] + (a, b) + {a} + {a: b} + [a, b,]
+            z = {
+                a: b
+            }
+        """
+        after = """
+            from foo import bar, baz
+            from foo import (bar, baz,)
+            x = call(a, *b, **c,) + call() + call(x for x in y)
+            y = [a,] + (a, b,) + {a,} + {a: b,} + [a, b,]
+            z = {
+                a: b,
+            }
+        """
+        self.assertCodemod(before, after, mode="always")
+
+    def test_never(self) -> None:
+        before = """
+            from foo import (bar, baz,)
+            x = call(
+                a,
+                b,
+            )
+            y = [a,] + (a,) + (a, b, ) + {a,} + {a: b,}
+        """
+        after = """
+            from foo import (bar, baz)
+            x = call(
+                a,
+                b
+            )
+            y = [a] + (a,) + (a, b ) + {a} + {a: b}
+        """
+        self.assertCodemod(before, after, mode="never")
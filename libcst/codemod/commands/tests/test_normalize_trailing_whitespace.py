@@ -0,0 +1,53 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Type
+
+from libcst import parse_module
+from libcst.codemod import Codemod, CodemodContext, CodemodTest
+from libcst.codemod.commands.normalize_trailing_whitespace import (
+    NormalizeTrailingWhitespaceCommand,
+)
+
+
+class TestNormalizeTrailingWhitespaceCommand(CodemodTest):
+    TRANSFORM: Type[Codemod] = NormalizeTrailingWhitespaceCommand
+
+    def test_noop(self) -> None:
+        before = """
+            def foo(a, b):
+                # comment
+                return a + b  # trailing comment
+        """
+        after = """
+            def foo(a, b):
+                # comment
+                return a + b  # trailing comment
+        """
+        self.assertCodemod(before, after)
+
+    def test_statements(self) -> None:
+        before = "import os  \ndef foo(): \t\n    return (  \n        os  \n    )\n"
+        after = "import os\ndef foo():\n    return (\n        os\n    )\n"
+        self.assertCodemod(before, after)
+
+    def test_comments_untouched(self) -> None:
+        before = "x = 1  # comment  \ny = 2 \n"
+        after = "x = 1  # comment  \ny = 2\n"
+        self.assertCodemod(before, after)
+
+    def test_strings_untouched(self) -> None:
+        before = 'x = """  \n  """  \n'
+        after = 'x = """  \n  """\n'
+        self.assertCodemod(before, after)
+
+    def test_blank_lines(self) -> None:
+        # Whitespace-only lines are stripped by the fixture helpers, so check the
+        # transform directly.
+        before = "  \nif x:\n    y = 1\n    \n\t\n    z = 2\n  # comment\n    \n"
+        after = "\nif x:\n    y = 1\n\n\n    z = 2\n  # comment\n\n"
+        transform = NormalizeTrailingWhitespaceCommand(CodemodContext())
+        self.assertEqual(transform.transform_module(parse_module(before)).code, after)
@@ -0,0 +1,53 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Type
+
+from libcst.codemod import Codemod, CodemodTest
+from libcst.codemod.commands.reformat import ReformatCommand
+
+
+class TestReformatCommand(CodemodTest):
+    TRANSFORM: Type[Codemod] = ReformatCommand
+
+    def test_reformat(self) -> None:
+        before = (
+            "import os \n"
+            + "def foo(a):\n"
+            + "  return 'foo'  # comment  \n"
+            + "class Foo:\n"
+            + "  x = 1\n"
+            + "  def bar(self):\n"
+            + "      pass\n"
+            + "\n"
+            + "\n"
+            + "\n"
+            + "  def baz(self):\n"
+            + "    return os\n"
+        )
+        after = (
+            "import os\n"
+            + "\n"
+            + "\n"
+            + "def foo(a):\n"
+            + '    return "foo"  # comment  \n'
+            + "\n"
+            + "\n"
+            + "class Foo:\n"
+            + "    x = 1\n"
+            + "\n"
+            + "    def bar(self):\n"
+            + "        pass\n"
+            + "\n"
+            + "    def baz(self):\n"
+            + "        return os\n"
+        )
+        self.assertCodemod(before, after)
+
+    def test_strings_in_formatted_strings(self) -> None:
+        before = "x = f\"{d['k']}\" + f'{d[\"k\"]}' + 'k'\n"
+        after = "x = f\"{d['k']}\" + f'{d[\"k\"]}' + \"k\"\n"
+        self.assertCodemod(before, after)
@@ -0,0 +1,95 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from libcst.codemod import CodemodTest
+from libcst.codemod.commands.remove_import import RemoveImportCommand
+
+
+class RemoveImportCommandTest(CodemodTest):
+    TRANSFORM = RemoveImportCommand
+
+    def test_remove_module(self) -> None:
+        before = """
+            import a
+            import b
+        """
+        after = """
+            import b
+        """
+        self.assertCodemod(before, after, module="a", entity=None, alias=None)
+
+    def test_remove_entity(self) -> None:
+        before = """
+            from a import b, c
+        """
+        after = """
+            from a import c
+        """
+        self.assertCodemod(before, after, module="a", entity="b", alias=None)
+
+    def test_remove_module_aliased(self) -> None:
+        before = """
+            import a as c
+            import a
+        """
+        after = """
+            import a
+        """
+        self.assertCodemod(before, after, module="a", entity=None, alias="c")
+
+    def test_remove_entity_aliased(self) -> None:
+        before = """
+            from a import b as c
+        """
+        after = ""
+        self.assertCodemod(before, after, module="a", entity="b", alias="c")
+
+    def test_keep_used_import(self) -> None:
+        before = """
+            from a import b
+
+            b()
+        """
+        after = """
+            from a import b
+
+            b()
+        """
+        self.assertCodemod(
+            before,
+            after,
+            module="a",
+            entity="b",
+            alias=None,
+            expected_warnings=[
+                "Kept the import of b from a because it is still referenced."
+            ],
+        )
+
+    def test_keep_used_module_aliased(self) -> None:
+        before = """
+            import a as c
+
+            c.d()
+        """
+        self.assertCodemod(
+            before,
+            before,
+            module="a",
+            entity=None,
+            alias="c",
+            expected_warnings=[
+                "Kept the import of a as c because it is still referenced."
+            ],
+        )
+
+    def test_missing_import(self) -> None:
+        before = """
+            import b
+        """
+        self.assertCodemod(
+            before, before, module="a", entity=None, alias=None, expected_warnings=[]
+        )
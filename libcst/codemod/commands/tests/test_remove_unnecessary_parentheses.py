@@ -0,0 +1,124 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Type
+
+from libcst.codemod import Codemod, CodemodTest
+from libcst.codemod.commands.remove_unnecessary_parentheses import (
+    RemoveUnnecessaryParenthesesCommand,
+)
+
+
+class TestRemoveUnnecessaryParenthesesCommand(CodemodTest):
+    TRANSFORM: Type[Codemod] = RemoveUnnecessaryParenthesesCommand
+
+    def test_statements(self) -> None:
+        before = """
+            def foo(a, b):
+                del (a)
+                assert (a), ("message")
+                if (a):
+                    pass
+                elif ((b)):
+                    pass
+                while (a and b):
+                    pass
+                x = (a + b)
+                return (x)
+        """
+        after = """
+            def foo(a, b):
+                del a
+                assert a, "message"
+                if a:
+                    pass
+                elif b:
+                    pass
+                while a and b:
+                    pass
+                x = a + b
+                return x
+        """
+        self.assertCodemod(before, after)
+
+    def test_missing_whitespace(self) -> None:
+        before = """
+            def foo(a):
+                del(a)
+                assert(a)
+                if(a):
+                    pass
+                elif(a):
+                    pass
+                while(a):
+                    pass
+                return(a)
+        """
+        after = """
+            def foo(a):
+                del a
+                assert a
+                if a:
+                    pass
+                elif a:
+                    pass
+                while a:
+                    pass
+                return a
+        """
+        self.assertCodemod(before, after)
+
+    def test_necessary_parentheses(self) -> None:
+        before = """
+            def foo(a, b):
+                del (a), b
+                assert (a, b)
+                if (a) and b:
+                    pass
+                x = (yield)
+                y = (i for i in a)
+                z = (a, b)
+                w = ()
+                return (a + b) * 2
+        """
+        after = """
+            def foo(a, b):
+                del (a), b
+                assert (a, b)
+                if (a) and b:
+                    pass
+                x = (yield)
+                y = (i for i in a)
+                z = (a, b)
+                w = ()
+                return (a + b) * 2
+        """
+        self.assertCodemod(before, after)
+
+    def test_multiple_lines(self) -> None:
+        before = """
+            def foo(a, b):
+                x = (
+                    a
+                )
+                if (a and
+                        b):
+                    pass
+                return (a +  # comment
+                        b)
+        """
+        after = """
+            def foo(a, b):
+                x = (
+                    a
+                )
+                if (a and
+                        b):
+                    pass
+                return (a +  # comment
+                        b)
+        """
+        self.assertCodemod(before, after)
@@ -0,0 +1,222 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from libcst.codemod import CodemodTest
+from libcst.codemod.commands.rename_symbol import RenameSymbolCommand
+
+
+class RenameSymbolCommandTest(CodemodTest):
+    TRANSFORM = RenameSymbolCommand
+
+    def test_rename_variable(self) -> None:
+        before = """
+            foo = 1
+            bar = foo + 1
+            del foo
+        """
+        after = """
+            baz = 1
+            bar = baz + 1
+            del baz
+        """
+        self.assertCodemod(before, after, old_name="foo", new_name="baz")
+
+    def test_rename_function_and_class(self) -> None:
+        before = """
+            def foo() -> None:
+                pass
+
+            class Foo:
+                pass
+
+            foo()
+            x: Foo = Foo()
+        """
+        after = """
+            def bar() -> None:
+                pass
+
+            class Foo:
+                pass
+
+            bar()
+            x: Foo = Foo()
+        """
+        self.assertCodemod(before, after, old_name="foo", new_name="bar")
+
+    def test_nested_scopes(self) -> None:
+        before = """
+            foo = 1
+
+            def a() -> int:
+                return foo
+
+            def b(foo: int) -> int:
+                return foo
+
+            def c() -> int:
+                foo = 2
+                return foo
+
+            def d() -> None:
+                global foo
+                foo = 3
+
+            class E:
+                foo = 4
+                bar = foo
+
+            obj.foo = call(foo=foo)
+            values = [foo for foo in range(3)]
+        """
+        after = """
+            baz = 1
+
+            def a() -> int:
+                return baz
+
+            def b(foo: int) -> int:
+                return foo
+
+            def c() -> int:
+                foo = 2
+                return foo
+
+            def d() -> None:
+                global baz
+                baz = 3
+
+            class E:
+                foo = 4
+                bar = foo
+
+            obj.foo = call(foo=baz)
+            values = [foo for foo in range(3)]
+        """
+        self.assertCodemod(before, after, old_name="foo", new_name="baz")
+
+    def test_rename_import(self) -> None:
+        before = """
+            from a import foo, bar
+            import foo2
+
+            foo(bar, foo2)
+        """
+        after = """
+            from a import foo as baz, bar
+            import foo2
+
+            baz(bar, foo2)
+        """
+        self.assertCodemod(before, after, old_name="foo", new_name="baz")
+
+    def test_rename_import_alias(self) -> None:
+        before = """
+            import bar as foo
+            import foo2
+
+            foo(foo2)
+        """
+        after = """
+            import bar as baz
+            import foo2
+
+            baz(foo2)
+        """
+        self.assertCodemod(before, after, old_name="foo", new_name="baz")
+
+    def test_skip_dotted_import(self) -> None:
+        before = """
+            import foo.bar
+
+            foo.bar.baz()
+        """
+        self.assertCodemod(
+            before, before, old_name="foo", new_name="qux", expected_skip=True
+        )
+
+    def test_skip_existing_name(self) -> None:
+        before = """
+            foo = 1
+            bar = 2
+        """
+        self.assertCodemod(
+            before, before, old_name="foo", new_name="bar", expected_skip=True
+        )
+
+    def test_class_body(self) -> None:
+        before = """
+            foo = 1
+
+            class C:
+                foo = foo + 1
+                bar = foo
+
+            class D:
+                foo: int
+                bar = foo
+        """
+        after = """
+            baz = 1
+
+            class C:
+                foo = baz + 1
+                bar = foo
+
+            class D:
+                foo: int
+                bar = baz
+        """
+        self.assertCodemod(before, after, old_name="foo", new_name="baz")
+
+    def test_skip_class_body(self) -> None:
+        before = """
+            foo = 1
+
+            class C:
+                if cond:
+                    foo = 2
+                bar = foo
+        """
+        self.assertCodemod(
+            before, before, old_name="foo", new_name="baz", expected_skip=True
+        )
+
+    def test_keyword_only(self) -> None:
+        before = """
+            call(foo=1)
+        """
+        after = """
+            call(foo=1)
+        """
+        self.assertCodemod(before, after, old_name="foo", new_name="bar")
+
+    def test_strings(self) -> None:
+        before = """
+            __all__ = ["foo", "other"]
+            __all__ += ("foo",)
+
+            def foo(a: "foo") -> 'foo':
+                x: "foo" = a
+                y: "List[foo]" = []
+                return "foo"
+
+            def bar(foo: int) -> None:
+                z: "foo" = foo
+        """
+        after = """
+            __all__ = ["baz", "other"]
+            __all__ += ("baz",)
+
+            def baz(a: "baz") -> 'baz':
+                x: "baz" = a
+                y: "List[foo]" = []
+                return "foo"
+
+            def bar(foo: int) -> None:
+                z: "foo" = foo
+        """
+        self.assertCodemod(before, after, old_name="foo", new_name="baz")
@@ -0,0 +1,144 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Type
+
+from libcst.codemod import Codemod, CodemodTest
+from libcst.codemod.commands.sort_imports import SortImportsCommand
+
+
+class TestSortImportsCommand(CodemodTest):
+    TRANSFORM: Type[Codemod] = SortImportsCommand
+
+    def test_noop(self) -> None:
+        before = """
+            from __future__ import annotations
+
+            import os
+            import sys
+            from typing import List
+
+            import attr
+
+            from . import sibling
+
+            x = 1
+        """
+        self.assertCodemod(before, before)
+
+    def test_groups(self) -> None:
+        before = """
+            \"\"\"Docstring.\"\"\"
+
+            import sys
+            from . import sibling
+            import attr
+            from myproject.utils import helper
+            from __future__ import annotations
+            import os
+
+            x = 1
+        """
+        after = """
+            \"\"\"Docstring.\"\"\"
+
+            from __future__ import annotations
+
+            import os
+            import sys
+
+            import attr
+
+            from . import sibling
+            from myproject.utils import helper
+
+            x = 1
+        """
+        self.assertCodemod(before, after, known_first_party=["myproject"])
+
+    def test_known_third_party(self) -> None:
+        before = """
+            import os
+            import json
+        """
+        after = """
+            import os
+
+            import json
+        """
+        self.assertCodemod(before, after, known_third_party=["json"])
+
+    def test_imports_before_from_imports(self) -> None:
+        before = """
+            from os import path
+            import sys
+            import os.path
+            from collections import abc
+        """
+        after = """
+            import os.path
+            import sys
+            from collections import abc
+            from os import path
+        """
+        self.assertCodemod(before, after)
+
+    def test_names(self) -> None:
+        before = """
+            import sys, os
+            from typing import cast, List, TYPE_CHECKING
+            from typing import (
+                cast,
+                List,
+                TYPE_CHECKING,
+            )
+        """
+        after = """
+            import os, sys
+            from typing import TYPE_CHECKING, List, cast
+            from typing import (
+                TYPE_CHECKING,
+                List,
+                cast,
+            )
+        """
+        self.assertCodemod(before, after)
+
+    def test_comments(self) -> None:
+        before = """
+            import sys
+            # Needed for paths.
+            import os
+            x = 1
+        """
+        after = """
+            # Needed for paths.
+            import os
+            import sys
+            x = 1
+        """
+        self.assertCodemod(before, after)
+
+    def test_first_block_only(self) -> None:
+        before = """
+            import sys
+            import os
+
+            x = 1
+
+            import attr
+            import abc
+        """
+        after = """
+            import os
+            import sys
+
+            x = 1
+
+            import attr
+            import abc
+        """
+        self.assertCodemod(before, after)
@@ -0,0 +1,85 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Type
+
+from libcst.codemod import Codemod, CodemodTest
+from libcst.codemod.commands.split_long_lines import SplitLongLinesCommand
+
+
+class TestSplitLongLinesCommand(CodemodTest):
+    TRANSFORM: Type[Codemod] = SplitLongLinesCommand
+
+    def test_short_lines(self) -> None:
+        before = """
+            from foo import bar, baz
+            x = call(a, [b, c], {d: e})
+        """
+        after = """
+            from foo import bar, baz
+            x = call(a, [b, c], {d: e})
+        """
+        self.assertCodemod(before, after, max_length=30)
+
+    def test_import(self) -> None:
+        before = """
+            from foo import bar, baz as qux
+            from foo import (bar, baz)
+        """
+        after = """
+            from foo import (
+                bar,
+                baz as qux,
+            )
+            from foo import (
+                bar,
+                baz,
+            )
+        """
+        self.assertCodemod(before, after, max_length=20)
+
+    def test_nested(self) -> None:
+        before = """
+            def foo():
+                x = call(argument, [first, second, third], key=value)
+                return {"a": 1, **other}, (first, second)
+        """
+        after = """
+            def foo():
+                x = call(
+                    argument,
+                    [
+                        first,
+                        second,
+                        third,
+                    ],
+                    key=value,
+                )
+                return {
+                    "a": 1,
+                    **other,
+                }, (first, second)
+        """
+        self.assertCodemod(before, after, max_length=30)
+
+    def test_unsplittable(self) -> None:
+        before = """
+            x = some_very_long_name + another_very_long_name
+            y = call()
+            z = sum(x for x in items)
+            w = f"{call(first, second)}"
+            v = call(
+                first, second)
+        """
+        after = """
+            x = some_very_long_name + another_very_long_name
+            y = call()
+            z = sum(x for x in items)
+            w = f"{call(first, second)}"
+            v = call(
+                first, second)
+        """
+        self.assertCodemod(before, after, max_length=10)
@@ -249,6 +249,10 @@ class AddImportsVisitor(ContextAwareTransformer):
     ]:
         import_add_location = 0
 
+        # never insert an import before the module docstring
+        if orig_module.get_docstring(clean=False) is not None:
+            import_add_location = 1
+
         # never insert an import before initial __strict__ flag
         if m.matches(
             orig_module,
@@ -343,9 +347,13 @@ class AddImportsVisitor(ContextAwareTransformer):
             for module, aliases in module_and_alias_mapping.items()
         }
         # import ptvsd; ptvsd.set_trace()
+        # __future__ imports have to come first, but after the module docstring
+        docstring_count = 0 if updated_node.get_docstring(clean=False) is None else 1
+
         # Now, add all of the imports we need!
         return updated_node.with_changes(
             body=(
+                *statements_before_imports[:docstring_count],
                 *[
                     parse_statement(
                         f"from {module} import "
@@ -360,7 +368,7 @@ class AddImportsVisitor(ContextAwareTransformer):
                     for module, aliases in module_and_alias_mapping.items()
                     if module == "__future__"
                 ],
-                *statements_before_imports,
+                *statements_before_imports[docstring_count:],
                 *[
                     parse_statement(
                         f"import {module}", config=updated_node.config_for_parsing
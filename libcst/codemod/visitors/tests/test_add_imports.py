@@ -484,6 +484,33 @@ class TestAddImportsCodemod(CodemodTest):
 
         self.assertCodemod(before, after, [("a.b.c", "D", None)])
 
+    def test_add_import_after_module_docstring(self) -> None:
+        """
+        Should never add an import, including a __future__ import, above the
+        module docstring.
+        """
+
+        before = """
+            \"\"\"This is the module docstring.\"\"\"
+
+            def foo() -> None:
+                pass
+        """
+        after = """
+            \"\"\"This is the module docstring.\"\"\"
+            from __future__ import annotations
+            import argparse
+
+            def foo() -> None:
+                pass
+        """
+
+        self.assertCodemod(
+            before,
+            after,
+            [("__future__", "annotations", None), ("argparse", None, None)],
+        )
+
     def test_add_import_preserve_doctring_multiples(self) -> None:
         """
         Should preserve any doctring if adding to the beginning.
@@ -14,22 +14,117 @@ from libcst.helpers._template import (
     parse_template_module,
     parse_template_statement,
 )
+from libcst.helpers.comment import (
+    NoqaComment,
+    extract_type_comment,
+    has_noqa,
+    is_type_ignore,
+    noqa_codes,
+    parse_noqa,
+    parse_type_comment,
+    type_ignore_codes,
+)
+from libcst.helpers.class_body import ClassBodyAnalysis, analyze_class_body
 from libcst.helpers.common import ensure_type
+from libcst.helpers.diff import (
+    CstDiff,
+    CstDiffKind,
+    DiffConflictError,
+    apply_diff,
+    diff_modules,
+)
 from libcst.helpers.expression import (
+    PyConstant,
+    collect_names_from_expression,
+    collect_names_from_statement,
+    evaluate_constant,
     get_full_name_for_node,
     get_full_name_for_node_or_raise,
+    is_constant,
+    is_truthy,
+    qualified_name_of_call,
+    resolve_annotation,
+    unwrap_parens,
+    wrap_in_parens,
+)
+from libcst.helpers.function import (
+    FunctionSignature,
+    ParamInfo,
+    ParamKind,
+    ParamPosition,
+    add_param,
+    extract_signature,
+    remove_param,
+)
+from libcst.helpers.metrics import (
+    count_lines,
+    count_statements,
+    cyclomatic_complexity,
+)
+from libcst.helpers.module import (
+    detect_python_version_from_imports,
+    get_future_imports,
+    insert_header_comments,
+    is_import_from_future,
+)
+from libcst.helpers.node import (
+    find_node_at_offset,
+    get_statements_of_type,
+    iter_nodes,
 )
-from libcst.helpers.module import insert_header_comments
+from libcst.helpers.stub import generate_stub
 
 
 __all__ = [
+    "count_lines",
+    "count_statements",
+    "cyclomatic_complexity",
+    "add_param",
+    "analyze_class_body",
+    "apply_diff",
+    "ClassBodyAnalysis",
+    "collect_names_from_expression",
+    "collect_names_from_statement",
+    "CstDiff",
+    "CstDiffKind",
+    "DiffConflictError",
+    "detect_python_version_from_imports",
+    "diff_modules",
     "get_absolute_module_for_import",
     "get_absolute_module_for_import_or_raise",
     "get_full_name_for_node",
     "get_full_name_for_node_or_raise",
+    "get_future_imports",
+    "get_statements_of_type",
     "ensure_type",
+    "evaluate_constant",
+    "extract_signature",
+    "extract_type_comment",
+    "is_constant",
+    "is_import_from_future",
+    "is_truthy",
+    "is_type_ignore",
     "insert_header_comments",
+    "find_node_at_offset",
+    "FunctionSignature",
+    "generate_stub",
+    "has_noqa",
+    "iter_nodes",
+    "NoqaComment",
+    "noqa_codes",
+    "ParamInfo",
+    "ParamKind",
+    "ParamPosition",
+    "parse_noqa",
+    "parse_type_comment",
     "parse_template_module",
     "parse_template_statement",
     "parse_template_expression",
+    "PyConstant",
+    "qualified_name_of_call",
+    "remove_param",
+    "resolve_annotation",
+    "type_ignore_codes",
+    "unwrap_parens",
+    "wrap_in_parens",
 ]
@@ -0,0 +1,75 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from dataclasses import dataclass
+from typing import List, Optional, Sequence
+
+import libcst
+from libcst._add_slots import add_slots
+from libcst.helpers.expression import get_full_name_for_node
+from libcst.helpers.node import get_statements_of_type
+
+
+_DATACLASS_NAMES = ("dataclass", "dataclasses.dataclass")
+
+
+@add_slots
+@dataclass(frozen=True)
+class ClassBodyAnalysis:
+    """
+    A summary of a class definition, as returned by :func:`analyze_class_body`.
+    """
+
+    #: The methods defined directly in the class body, in the order they're defined
+    #: in, including ``__init__``.
+    methods: Sequence[libcst.FunctionDef]
+
+    #: The annotated assignments directly in the class body, such as ``x: int`` or
+    #: ``y: str = ""``, which are the fields of a dataclass.
+    class_variables: Sequence[libcst.AnnAssign]
+
+    #: The ``__init__`` method, or ``None`` if the class doesn't define one. If it's
+    #: defined more than once, this is the last definition, which is the one that's
+    #: used.
+    init_method: Optional[libcst.FunctionDef]
+
+    #: Whether the class is decorated with ``@dataclass`` or
+    #: ``@dataclasses.dataclass``, with or without arguments.
+    is_dataclass: bool
+
+    #: The dotted names of the base classes, such as ``"typing.Generic"`` for
+    #: ``typing.Generic[T]``, as returned by :func:`get_full_name_for_node`. A base
+    #: that's a call gets the name of the function. Keyword arguments such as
+    #: ``metaclass=`` aren't bases.
+    base_names: Sequence[str]
+
+
+def analyze_class_body(cls: libcst.ClassDef) -> ClassBodyAnalysis:
+    """
+    Return a summary of the methods, annotated class variables, decorators and bases
+    of a class. Only statements directly in the class body are considered, so
+    methods defined in an ``if`` block or a nested class aren't included.
+    """
+    methods = get_statements_of_type(cls.body, libcst.FunctionDef)
+    init_method: Optional[libcst.FunctionDef] = None
+    for method in methods:
+        if method.name.value == "__init__":
+            init_method = method
+    base_names: List[str] = []
+    for base in cls.bases:
+        name = get_full_name_for_node(base.value)
+        if name is not None:
+            base_names.append(name)
+    return ClassBodyAnalysis(
+        methods=methods,
+        class_variables=get_statements_of_type(cls.body, libcst.AnnAssign),
+        init_method=init_method,
+        is_dataclass=any(
+            get_full_name_for_node(decorator) in _DATACLASS_NAMES
+            for decorator in cls.decorators
+        ),
+        base_names=base_names,
+    )
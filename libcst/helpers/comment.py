@@ -0,0 +1,139 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import re
+from dataclasses import dataclass
+from typing import Match, Optional, Pattern, Sequence
+
+import libcst
+from libcst._add_slots import add_slots
+from libcst.helpers.expression import _parse_annotation
+
+
+# The type ends at the next comment, as in ``# type: int  # noqa``.
+_TYPE_COMMENT_RE: Pattern[str] = re.compile(r"#\s*type:\s*([^#]*?)\s*(?:#.*)?")
+_TYPE_IGNORE_RE: Pattern[str] = re.compile(r"ignore\s*(?:\[(?P<codes>[^\]]*)\])?")
+# This matches the directives that flake8 accepts, which can follow other comments.
+_NOQA_RE: Pattern[str] = re.compile(
+    r"#\s*noqa\b(?::\s*(?P<codes>[a-z]+[0-9]+(?:[,\s]+[a-z]+[0-9]+)*))?",
+    re.IGNORECASE,
+)
+_NOQA_SEPARATOR_RE: Pattern[str] = re.compile(r"[,\s]+")
+
+
+def extract_type_comment(comment: libcst.Comment) -> Optional[str]:
+    """
+    Return the type of a :pep:`484` type comment such as ``# type: List[int]``,
+    without the ``# type:`` prefix, or ``None`` if ``comment`` isn't a type comment.
+    ``# type: ignore`` comments don't hold a type, so they return ``None`` too.
+    """
+    match = _TYPE_COMMENT_RE.fullmatch(comment.value)
+    if match is None:
+        return None
+    text = match.group(1)
+    if not text or _TYPE_IGNORE_RE.fullmatch(text) is not None:
+        return None
+    return text
+
+
+def parse_type_comment(text: str) -> libcst.BaseExpression:
+    """
+    Parse a :pep:`484` type comment such as ``# type: List[int]`` into an
+    expression. ``text`` can be the whole comment, or just the type that follows the
+    ``# type:`` prefix, as returned by :func:`extract_type_comment`. Raise
+    :class:`~libcst.ParserSyntaxError` if the type isn't a valid expression, which
+    is the case for function signature comments such as ``# type: (int) -> str``.
+
+    Types are parsed like string annotations in
+    :func:`~libcst.helpers.resolve_annotation`, so parsing the same type twice
+    returns the same node. Use :meth:`~libcst.CSTNode.deep_clone` before inserting
+    it into a tree more than once.
+    """
+    match = _TYPE_COMMENT_RE.fullmatch(text)
+    if match is not None:
+        text = match.group(1)
+    return _parse_annotation(text)
+
+
+def _match_type_ignore(comment: libcst.Comment) -> Optional[Match[str]]:
+    match = _TYPE_COMMENT_RE.fullmatch(comment.value)
+    if match is None:
+        return None
+    return _TYPE_IGNORE_RE.fullmatch(match.group(1))
+
+
+def is_type_ignore(comment: libcst.Comment) -> bool:
+    """
+    Return whether ``comment`` is a ``# type: ignore`` comment, with or without error
+    codes, as in ``# type: ignore[attr-defined]``. Like for type comments, another
+    comment can follow it, as in ``# type: ignore  # noqa``.
+    """
+    return _match_type_ignore(comment) is not None
+
+
+def type_ignore_codes(comment: libcst.Comment) -> Sequence[str]:
+    """
+    Return the error codes of a ``# type: ignore`` comment, such as
+    ``["attr-defined", "misc"]`` for ``# type: ignore[attr-defined, misc]``. This is
+    empty if ``comment`` isn't a ``# type: ignore`` comment, and for one without
+    codes, which ignores every error on the line, so use :func:`is_type_ignore` to
+    tell those apart.
+    """
+    match = _match_type_ignore(comment)
+    codes = match.group("codes") if match is not None else None
+    if codes is None:
+        return []
+    return [code.strip() for code in codes.split(",") if code.strip()]
+
+
+@add_slots
+@dataclass(frozen=True)
+class NoqaComment:
+    """
+    A ``# noqa`` directive, as returned by :func:`parse_noqa`.
+    """
+
+    #: The error codes that are suppressed, such as ``["E501", "W503"]`` for
+    #: ``# noqa: E501, W503``. This is empty for a bare ``# noqa``, which suppresses
+    #: every error on the line.
+    codes: Sequence[str]
+
+
+def parse_noqa(comment: libcst.Comment) -> Optional[NoqaComment]:
+    """
+    Parse a ``# noqa`` directive such as ``# noqa``, ``# noqa: E501`` or
+    ``# noqa: E501, W503``, or return ``None`` if ``comment`` doesn't contain one.
+    The directive doesn't have to be at the start of the comment, so
+    ``# type: int  # noqa`` contains one too. Codes that can't be parsed are
+    ignored, which makes the directive suppress every error, like flake8 does.
+    """
+    match = _NOQA_RE.search(comment.value)
+    if match is None:
+        return None
+    codes = match.group("codes")
+    return NoqaComment(
+        codes=_NOQA_SEPARATOR_RE.split(codes) if codes is not None else []
+    )
+
+
+def has_noqa(trailing: libcst.TrailingWhitespace) -> bool:
+    """
+    Return whether the comment at the end of a line contains a ``# noqa``
+    directive.
+    """
+    comment = trailing.comment
+    return comment is not None and parse_noqa(comment) is not None
+
+
+def noqa_codes(trailing: libcst.TrailingWhitespace) -> Sequence[str]:
+    """
+    Return the error codes of the ``# noqa`` directive at the end of a line. This is
+    empty if the line doesn't have a directive, and for a bare ``# noqa``, so use
+    :func:`has_noqa` to tell those apart.
+    """
+    comment = trailing.comment
+    noqa = parse_noqa(comment) if comment is not None else None
+    return noqa.codes if noqa is not None else []
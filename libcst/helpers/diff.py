@@ -0,0 +1,179 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from dataclasses import dataclass
+from difflib import SequenceMatcher
+from enum import Enum
+from typing import List, Optional, Sequence
+
+import libcst
+from libcst._add_slots import add_slots
+
+
+class CstDiffKind(Enum):
+    """
+    An enumeration of the ways a top-level statement can differ between two modules.
+    """
+
+    #: The statement only exists in the new module.
+    ADDED = "added"
+
+    #: The statement only exists in the old module.
+    REMOVED = "removed"
+
+    #: The statement in the old module was replaced by the one in the new module.
+    CHANGED = "changed"
+
+
+@add_slots
+@dataclass(frozen=True)
+class CstDiff:
+    """
+    A single difference between the top-level statements of two modules, as
+    returned by :func:`diff_modules`.
+    """
+
+    #: How the statement differs.
+    kind: CstDiffKind
+
+    #: The statement in the old module, or ``None`` for
+    #: :attr:`CstDiffKind.ADDED`.
+    old: Optional[libcst.BaseStatement]
+
+    #: The statement in the new module, or ``None`` for
+    #: :attr:`CstDiffKind.REMOVED`.
+    new: Optional[libcst.BaseStatement]
+
+    #: The index of :attr:`old` in the old module's body. For
+    #: :attr:`CstDiffKind.ADDED`, the index in the old module's body that the new
+    #: statement was inserted at.
+    old_index: int
+
+    #: The index of :attr:`new` in the new module's body. For
+    #: :attr:`CstDiffKind.REMOVED`, the index in the new module's body that the old
+    #: statement was removed from.
+    new_index: int
+
+
+class DiffConflictError(Exception):
+    """
+    Raised by :func:`apply_diff` when a statement that the diff removes or changes
+    can't be found in the module it's being applied to.
+    """
+
+    #: The difference that couldn't be applied.
+    diff: CstDiff
+
+    def __init__(self, message: str, diff: CstDiff) -> None:
+        super().__init__(message)
+        self.diff = diff
+
+
+def diff_modules(a: libcst.Module, b: libcst.Module) -> List[CstDiff]:
+    """
+    Return the differences between the top-level statements of module ``a`` and
+    module ``b``, ordered by their position in the modules. Two statements are the
+    same if they generate the same code, including comments and blank lines above
+    them, so unlike a textual diff, a statement that changes is reported as a whole.
+
+    When a run of statements is replaced by another run, they're paired up as
+    :attr:`CstDiffKind.CHANGED` in order and any leftover statements are reported as
+    :attr:`CstDiffKind.REMOVED` or :attr:`CstDiffKind.ADDED`. Changes to the
+    module's header or footer aren't reported.
+    """
+    old_code = [a.code_for_node(stmt) for stmt in a.body]
+    new_code = [b.code_for_node(stmt) for stmt in b.body]
+    matcher = SequenceMatcher(None, old_code, new_code, autojunk=False)
+
+    diffs: List[CstDiff] = []
+    for tag, i1, i2, j1, j2 in matcher.get_opcodes():
+        if tag == "equal":
+            continue
+        paired = min(i2 - i1, j2 - j1)
+        for offset in range(paired):
+            diffs.append(
+                CstDiff(
+                    CstDiffKind.CHANGED,
+                    a.body[i1 + offset],
+                    b.body[j1 + offset],
+                    i1 + offset,
+                    j1 + offset,
+                )
+            )
+        for i in range(i1 + paired, i2):
+            diffs.append(CstDiff(CstDiffKind.REMOVED, a.body[i], None, i, j2))
+        for j in range(j1 + paired, j2):
+            diffs.append(CstDiff(CstDiffKind.ADDED, None, b.body[j], i2, j))
+    return diffs
+
+
+def _find_statement(
+    body: Sequence[libcst.BaseStatement],
+    stmt: libcst.BaseStatement,
+    expected: int,
+    start: int,
+) -> Optional[int]:
+    # Search outwards from where the statement is expected to be, preferring the
+    # closest match, without going back past statements we already patched.
+    for distance in range(len(body)):
+        for index in (expected - distance, expected + distance):
+            if start <= index < len(body) and body[index].deep_equals(stmt):
+                return index
+    return None
+
+
+def apply_diff(base: libcst.Module, diff: Sequence[CstDiff]) -> libcst.Module:
+    """
+    Apply a diff returned by :func:`diff_modules` to ``base``, which can be the old
+    module that the diff was computed from or a modified version of it, and return
+    the patched module.
+
+    Like ``patch``, statements that the diff removes or changes are located by
+    content near their original index, so ``base`` can have other statements added
+    or removed. New statements are inserted at their original index, shifted by
+    however much ``base`` has shifted around the preceding statements. Raises
+    :class:`DiffConflictError` if a statement to remove or change isn't in
+    ``base``, for example because ``base`` changed that same statement.
+    """
+    body = list(base.body)
+    # How far statements in ``body`` are shifted from their index in the old module,
+    # and the first index in ``body`` that hasn't been patched yet.
+    shift = 0
+    start = 0
+    for change in diff:
+        expected = change.old_index + shift
+        if change.kind == CstDiffKind.ADDED:
+            new = change.new
+            if new is None:
+                raise ValueError("An added statement must have a new statement.")
+            index = min(max(expected, start), len(body))
+            body.insert(index, new)
+            shift += 1
+            start = index + 1
+            continue
+
+        old = change.old
+        if old is None:
+            raise ValueError(f"A {change.kind.value} statement must have an old one.")
+        index = _find_statement(body, old, expected, start)
+        if index is None:
+            raise DiffConflictError(
+                f"Statement {change.old_index} of the original module was "
+                + f"{change.kind.value}, but it isn't in the module being patched.",
+                change,
+            )
+        shift = index - change.old_index
+        if change.kind == CstDiffKind.CHANGED:
+            new = change.new
+            if new is None:
+                raise ValueError("A changed statement must have a new statement.")
+            body[index] = new
+            start = index + 1
+        else:
+            del body[index]
+            shift -= 1
+            start = index
+    return base.with_changes(body=body)
@@ -4,9 +4,24 @@
 # LICENSE file in the root directory of this source tree.
 #
 # pyre-strict
-from typing import Optional, Union
+import operator
+from dataclasses import dataclass
+from functools import lru_cache
+from typing import Callable, Dict, List, Optional, Sequence, Type, TypeVar, Union
 
 import libcst as cst
+from libcst._add_slots import add_slots
+
+
+_ExpressionT = TypeVar("_ExpressionT", bound=cst.BaseExpression)
+
+_CONSTANT_NAMES = ("None", "True", "False")
+_UNARY_OPERATORS: Dict[Type[cst.BaseUnaryOp], Callable[[object], object]] = {
+    cst.Plus: operator.pos,
+    cst.Minus: operator.neg,
+    cst.BitInvert: operator.invert,
+    cst.Not: operator.not_,
+}
 
 
 def get_full_name_for_node(node: Union[str, cst.CSTNode]) -> Optional[str]:
@@ -41,3 +56,238 @@ def get_full_name_for_node_or_raise(node: Union[str, cst.CSTNode]) -> str:
     if full_name is None:
         raise Exception(f"Not able to parse full name for: {node}")
     return full_name
+
+
+@lru_cache(maxsize=1024)
+def _parse_annotation(source: str) -> cst.BaseExpression:
+    return cst.parse_expression(source.strip())
+
+
+def resolve_annotation(annotation: cst.Annotation) -> cst.BaseExpression:
+    """Return the expression of an :class:`~libcst.Annotation`, re-parsing it with
+    :func:`~libcst.parse_expression` if it's a string literal (a forward reference
+    such as ``"List[int]"``). Annotations that aren't strings, including f-strings,
+    are returned as-is.
+    Raise :class:`~libcst.ParserSyntaxError` if the string isn't a valid expression.
+
+    Parsed strings are cached, so resolving the same string twice returns the same
+    node. Use :meth:`~libcst.CSTNode.deep_clone` before inserting it into a tree more
+    than once.
+    """
+    expression = annotation.annotation
+    if isinstance(expression, (cst.SimpleString, cst.ConcatenatedString)):
+        value = expression.evaluated_value
+        if value is not None:
+            return _parse_annotation(value)
+    return expression
+
+
+def wrap_in_parens(expr: _ExpressionT) -> _ExpressionT:
+    """Return ``expr`` wrapped in one more pair of parentheses, outside of any it
+    already has. Parentheses allow an expression to span multiple lines, so this can
+    be used before inserting newlines into a long expression.
+    """
+    return expr.with_changes(
+        lpar=[cst.LeftParen(), *expr.lpar], rpar=[*expr.rpar, cst.RightParen()]
+    )
+
+
+def unwrap_parens(expr: _ExpressionT) -> _ExpressionT:
+    """Return ``expr`` with its outermost pair of parentheses removed, along with the
+    whitespace inside of them. Return ``expr`` unchanged if it isn't parenthesized.
+    This doesn't check whether the parentheses are needed, so the caller must make
+    sure that the expression is still valid where it's used.
+    """
+    if not expr.lpar:
+        return expr
+    return expr.with_changes(lpar=expr.lpar[1:], rpar=expr.rpar[:-1])
+
+
+def _is_literal(expr: cst.BaseExpression) -> bool:
+    if isinstance(expr, (cst.Integer, cst.Float, cst.Imaginary, cst.Ellipsis)):
+        return True
+    if isinstance(expr, cst.SimpleString):
+        return True
+    if isinstance(expr, cst.ConcatenatedString):
+        return _is_literal(expr.left) and _is_literal(expr.right)
+    if isinstance(expr, cst.Name):
+        return expr.value in _CONSTANT_NAMES
+    if isinstance(expr, cst.UnaryOperation):
+        if not _is_literal(expr.expression):
+            return False
+        if isinstance(expr.operator, cst.Not):
+            return True
+        # ``+``, ``-`` and ``~`` only work on numbers, so ``-"text"`` and ``~1.5``
+        # would raise a TypeError.
+        return evaluate_constant(expr) is not None
+    return False
+
+
+def is_constant(expr: cst.BaseExpression) -> bool:
+    """Return whether ``expr`` is a constant, which is a literal such as ``1``,
+    ``2.5``, ``"text"``, ``b"bytes"``, ``None``, ``True``, ``False`` or ``...``, a
+    unary operation on a literal such as ``-1`` or ``not True``, or a tuple, list,
+    set or dict display whose elements are all constants. F-strings aren't
+    constants, and neither are displays that contain ``*`` or ``**`` unpacking.
+    ``+``, ``-`` and ``~`` only make a constant out of a number, so ``-"text"``
+    isn't one. Any constant that isn't a list, set or dict, or a tuple containing
+    one, can be evaluated with :func:`evaluate_constant`.
+    """
+    if _is_literal(expr):
+        return True
+    if isinstance(expr, (cst.Tuple, cst.List, cst.Set)):
+        return all(
+            isinstance(element, cst.Element) and is_constant(element.value)
+            for element in expr.elements
+        )
+    if isinstance(expr, cst.Dict):
+        return all(
+            isinstance(element, cst.DictElement)
+            and is_constant(element.key)
+            and is_constant(element.value)
+            for element in expr.elements
+        )
+    return False
+
+
+@add_slots
+@dataclass(frozen=True)
+class PyConstant:
+    """The value of a constant expression, as returned by :func:`evaluate_constant`.
+    Values are wrapped so that a ``None`` constant can be told apart from an
+    expression that isn't constant.
+    """
+
+    #: The evaluated value, which is an ``int``, ``float``, ``complex``, ``str``,
+    #: ``bytes``, ``bool``, ``None``, ``Ellipsis``, or a ``tuple`` of those.
+    value: object
+
+
+def _evaluate(expr: cst.BaseExpression) -> PyConstant:
+    if isinstance(expr, (cst.Integer, cst.Float, cst.Imaginary)):
+        return PyConstant(expr.evaluated_value)
+    if isinstance(expr, (cst.SimpleString, cst.ConcatenatedString)):
+        value = expr.evaluated_value
+        if value is None:
+            raise ValueError("F-strings can't be evaluated")
+        return PyConstant(value)
+    if isinstance(expr, cst.Name) and expr.value in _CONSTANT_NAMES:
+        return PyConstant({"None": None, "True": True, "False": False}[expr.value])
+    if isinstance(expr, cst.Ellipsis):
+        return PyConstant(...)
+    if isinstance(expr, cst.UnaryOperation):
+        operand = _evaluate(expr.expression).value
+        return PyConstant(_UNARY_OPERATORS[type(expr.operator)](operand))
+    if isinstance(expr, cst.Tuple):
+        values: List[object] = []
+        for element in expr.elements:
+            if not isinstance(element, cst.Element):
+                raise ValueError("Starred elements can't be evaluated")
+            values.append(_evaluate(element.value).value)
+        return PyConstant(tuple(values))
+    raise ValueError(f"{type(expr).__name__} isn't a constant")
+
+
+def evaluate_constant(expr: cst.BaseExpression) -> Optional[PyConstant]:
+    """Return the value of a constant expression wrapped in a :class:`PyConstant`, or
+    ``None`` if ``expr`` isn't constant. Literals, unary operations on them, and
+    tuples of constants can be evaluated, so ``(3, -1)`` evaluates to
+    ``PyConstant((3, -1))``. Unlike :func:`is_constant`, lists, sets and dicts aren't
+    evaluated, since their values are mutable or unhashable. An expression that
+    would raise an error, such as ``-"text"``, isn't constant either.
+    """
+    try:
+        return _evaluate(expr)
+    except (TypeError, ValueError):
+        return None
+
+
+def is_truthy(expr: cst.BaseExpression) -> Optional[bool]:
+    """Return whether ``expr`` is always true or always false when used as a
+    condition, or ``None`` if that can't be known without running the code. Any
+    expression that :func:`evaluate_constant` can evaluate is known, so ``0``,
+    ``""``, ``None`` and ``()`` are false, and ``1``, ``"text"`` and ``True`` are
+    true. A tuple, list, set or dict display is known too, even if its elements
+    aren't constant: it's false if it's empty, and true if it has an element that
+    isn't ``*`` or ``**`` unpacking, since the unpacked value might be empty.
+    """
+    constant = evaluate_constant(expr)
+    if constant is not None:
+        return bool(constant.value)
+    if isinstance(expr, (cst.Tuple, cst.List, cst.Set, cst.Dict)):
+        elements: Sequence[cst.CSTNode] = expr.elements
+        if not elements:
+            return False
+        if any(
+            isinstance(element, (cst.Element, cst.DictElement))
+            for element in elements
+        ):
+            return True
+    return None
+
+
+def _dotted_name(expr: cst.BaseExpression) -> Optional[str]:
+    if isinstance(expr, cst.Name):
+        return expr.value
+    if isinstance(expr, cst.Attribute):
+        value = _dotted_name(expr.value)
+        return f"{value}.{expr.attr.value}" if value is not None else None
+    return None
+
+
+def qualified_name_of_call(call: cst.Call) -> Optional[str]:
+    """Return the dotted name of the function that ``call`` calls, such as
+    ``"os.path.join"`` for ``os.path.join(a, b)``, or ``None`` if the function is
+    computed, as in ``f()()``, ``handlers[0]()`` or ``a.b().c()``. Unlike
+    :func:`get_full_name_for_node`, which returns ``"a.b.c"`` for the last one, only
+    a name or a chain of attributes on a name counts.
+
+    The name is the one written in the source. Use
+    :class:`~libcst.metadata.QualifiedNameProvider` to resolve it through imports.
+    """
+    return _dotted_name(call.func)
+
+
+class _NameCollector(cst.CSTVisitor):
+    def __init__(self) -> None:
+        super().__init__()
+        self.names: Dict[str, None] = {}
+
+    def visit_Name(self, node: cst.Name) -> None:
+        self.names[node.value] = None
+
+    def visit_Attribute(self, node: cst.Attribute) -> bool:
+        # Only the leftmost name of ``a.b.c`` is a variable.
+        node.value.visit(self)
+        return False
+
+    def visit_Arg(self, node: cst.Arg) -> bool:
+        # The keyword of ``f(x=1)`` is the name of a parameter, not a variable.
+        node.value.visit(self)
+        return False
+
+
+def collect_names_from_expression(expr: cst.BaseExpression) -> List[str]:
+    """Return the names that ``expr`` refers to, in the order they first appear and
+    without duplicates, so ``a.b + c[d]`` returns ``["a", "c", "d"]``. Attribute
+    names and the keywords of keyword arguments aren't variables, so they're
+    skipped. Names bound inside of ``expr``, such as the parameters of a lambda or
+    the targets of a comprehension, are included.
+    """
+    collector = _NameCollector()
+    expr.visit(collector)
+    return list(collector.names)
+
+
+def collect_names_from_statement(
+    statement: Union[cst.BaseStatement, cst.BaseSmallStatement]
+) -> List[str]:
+    """Return the names that ``statement`` refers to, like
+    :func:`collect_names_from_expression` does for an expression. Names that are
+    bound by the statement, such as assignment targets, imported names, and the
+    name and parameters of a function, are included too, and so are the names in
+    nested statements.
+    """
+    collector = _NameCollector()
+    statement.visit(collector)
+    return list(collector.names)
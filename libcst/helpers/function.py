@@ -0,0 +1,308 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from dataclasses import dataclass
+from enum import Enum
+from typing import Dict, List, Optional, Sequence, Tuple, Union
+
+import libcst
+from libcst import MaybeSentinel
+from libcst._add_slots import add_slots
+
+
+class ParamKind(Enum):
+    """
+    An enumeration of the ways an argument can be passed to a parameter, which match
+    the kinds of :class:`inspect.Parameter`.
+    """
+
+    #: A parameter before a ``/``, which can only be passed by position.
+    POSITIONAL_ONLY = "positional_only"
+
+    #: A regular parameter, which can be passed by position or by keyword.
+    POSITIONAL_OR_KEYWORD = "positional_or_keyword"
+
+    #: A ``*args`` parameter.
+    VAR_POSITIONAL = "var_positional"
+
+    #: A parameter after a ``*`` or ``*args``, which can only be passed by keyword.
+    KEYWORD_ONLY = "keyword_only"
+
+    #: A ``**kwargs`` parameter.
+    VAR_KEYWORD = "var_keyword"
+
+
+class ParamPosition(Enum):
+    """
+    Where :func:`add_param` adds a parameter.
+    """
+
+    #: Before every other parameter.
+    FIRST = "first"
+
+    #: After the last positional parameter, so before any ``*args``, keyword-only
+    #: parameters or ``**kwargs``.
+    LAST = "last"
+
+    #: Right before the parameter called ``anchor``.
+    BEFORE = "before"
+
+    #: Right after the parameter called ``anchor``.
+    AFTER = "after"
+
+
+@add_slots
+@dataclass(frozen=True)
+class ParamInfo:
+    """
+    A parameter of a :class:`FunctionSignature`.
+    """
+
+    #: The name of the parameter, without any ``*`` or ``**``.
+    name: str
+
+    #: How arguments can be passed to the parameter.
+    kind: ParamKind
+
+    #: The source code of the annotation, without the colon, or ``None`` if the
+    #: parameter isn't annotated.
+    annotation: Optional[str]
+
+    #: The source code of the default value, or ``None`` if there isn't one.
+    default: Optional[str]
+
+
+@add_slots
+@dataclass(frozen=True)
+class FunctionSignature:
+    """
+    The signature of a function, as returned by :func:`extract_signature`.
+    """
+
+    #: The name of the function.
+    name: str
+
+    #: The parameters of the function, in the order they're declared in.
+    params: Sequence[ParamInfo]
+
+    #: The source code of the return annotation, without the arrow, or ``None`` if
+    #: the function doesn't have one.
+    return_annotation: Optional[str]
+
+
+def _param_info(
+    module: libcst.Module, param: libcst.Param, kind: ParamKind
+) -> ParamInfo:
+    annotation = param.annotation
+    default = param.default
+    return ParamInfo(
+        name=param.name.value,
+        kind=kind,
+        annotation=(
+            module.code_for_node(annotation.annotation)
+            if annotation is not None
+            else None
+        ),
+        default=module.code_for_node(default) if default is not None else None,
+    )
+
+
+def extract_signature(func: libcst.FunctionDef) -> FunctionSignature:
+    """
+    Return the signature of a function, with the annotations and default values of
+    its parameters as source code. This can be used to generate documentation or
+    stubs without having to walk the parameters of the function by hand.
+    """
+    # Generating code for an expression doesn't depend on the module it's in.
+    module = libcst.Module(body=())
+    parameters = func.params
+    params: List[ParamInfo] = [
+        *(
+            _param_info(module, param, ParamKind.POSITIONAL_ONLY)
+            for param in parameters.posonly_params
+        ),
+        *(
+            _param_info(module, param, ParamKind.POSITIONAL_OR_KEYWORD)
+            for param in parameters.params
+        ),
+    ]
+    star_arg = parameters.star_arg
+    if isinstance(star_arg, libcst.Param):
+        params.append(_param_info(module, star_arg, ParamKind.VAR_POSITIONAL))
+    params.extend(
+        _param_info(module, param, ParamKind.KEYWORD_ONLY)
+        for param in parameters.kwonly_params
+    )
+    star_kwarg = parameters.star_kwarg
+    if star_kwarg is not None:
+        params.append(_param_info(module, star_kwarg, ParamKind.VAR_KEYWORD))
+    returns = func.returns
+    return FunctionSignature(
+        name=func.name.value,
+        params=params,
+        return_annotation=(
+            module.code_for_node(returns.annotation) if returns is not None else None
+        ),
+    )
+
+
+_PARAM_LISTS = ("posonly_params", "params", "kwonly_params")
+_CommaT = Union[libcst.Comma, MaybeSentinel]
+
+
+def _find_param(params: libcst.Parameters, name: str) -> Optional[Tuple[str, int]]:
+    for field in _PARAM_LISTS:
+        for i, param in enumerate(getattr(params, field)):
+            if param.name.value == name:
+                return field, i
+    star_arg = params.star_arg
+    if isinstance(star_arg, libcst.Param) and star_arg.name.value == name:
+        return "star_arg", 0
+    star_kwarg = params.star_kwarg
+    if star_kwarg is not None and star_kwarg.name.value == name:
+        return "star_kwarg", 0
+    return None
+
+
+def _last_field(params: libcst.Parameters) -> Optional[str]:
+    """
+    Returns the field holding the last node that's rendered with a comma, which is
+    the one that owns any trailing comma.
+    """
+    if params.star_kwarg is not None:
+        return "star_kwarg"
+    if params.kwonly_params:
+        return "kwonly_params"
+    if isinstance(params.star_arg, libcst.Param):
+        return "star_arg"
+    if params.params:
+        return "params"
+    if isinstance(params.posonly_ind, libcst.ParamSlash):
+        return "posonly_ind"
+    return None
+
+
+def _get_last_comma(params: libcst.Parameters) -> _CommaT:
+    field = _last_field(params)
+    if field is None:
+        return MaybeSentinel.DEFAULT
+    node = getattr(params, field)
+    return (node[-1] if field in _PARAM_LISTS else node).comma
+
+
+def _set_last_comma(params: libcst.Parameters, comma: _CommaT) -> libcst.Parameters:
+    field = _last_field(params)
+    if field is None:
+        return params
+    node = getattr(params, field)
+    if field in _PARAM_LISTS:
+        value = [*node[:-1], node[-1].with_changes(comma=comma)]
+    else:
+        value = node.with_changes(comma=comma)
+    return params.with_changes(**{field: value})
+
+
+def _insert(
+    params: libcst.Parameters, field: str, index: int, param: libcst.Param
+) -> libcst.Parameters:
+    existing = getattr(params, field)
+    return params.with_changes(**{field: [*existing[:index], param, *existing[index:]]})
+
+
+def add_param(
+    params: libcst.Parameters,
+    param: libcst.Param,
+    position: ParamPosition = ParamPosition.LAST,
+    anchor: Optional[str] = None,
+) -> libcst.Parameters:
+    """
+    Return ``params`` with a regular parameter added at ``position``. For
+    :attr:`ParamPosition.BEFORE` and :attr:`ParamPosition.AFTER`, ``anchor`` is the
+    name of the parameter to add it next to, and the new parameter becomes the same
+    kind of parameter, so adding one after a keyword-only parameter adds another
+    keyword-only parameter. Adding one before ``*args`` adds a positional parameter,
+    and adding one after it adds a keyword-only parameter.
+
+    Commas are added as needed, and if the new parameter ends up last, it takes over
+    any trailing comma. Raise :class:`ValueError` if ``anchor`` is missing or there
+    isn't a parameter with that name, or if it's ``**kwargs`` and ``position`` is
+    :attr:`ParamPosition.AFTER`. Like any other change, this raises
+    :class:`~libcst.CSTValidationError` if the result isn't valid, such as when a
+    parameter without a default follows one with a default.
+    """
+    if position is ParamPosition.FIRST:
+        field = "posonly_params" if params.posonly_params else "params"
+        index = 0
+    elif position is ParamPosition.LAST:
+        field = "params"
+        index = len(params.params)
+    else:
+        found = _find_param(params, anchor) if anchor is not None else None
+        if found is None:
+            raise ValueError(f"There is no parameter called {anchor!r}.")
+        field, index = found
+        before = position is ParamPosition.BEFORE
+        if field == "star_arg":
+            field = "params" if before else "kwonly_params"
+            index = len(params.params) if before else 0
+        elif field == "star_kwarg":
+            if not before:
+                raise ValueError("Cannot add a parameter after **kwargs.")
+            if isinstance(params.star_arg, MaybeSentinel) and not params.kwonly_params:
+                field = "params"
+            else:
+                field = "kwonly_params"
+            index = len(getattr(params, field))
+        elif not before:
+            index += 1
+
+    updated = _insert(params, field, index, param)
+    if (
+        param.comma is MaybeSentinel.DEFAULT
+        and _last_field(params) is not None
+        and _last_field(updated) == field
+        and index == len(getattr(updated, field)) - 1
+    ):
+        # The new parameter is last, so it takes over the trailing comma.
+        updated = _insert(
+            _set_last_comma(params, MaybeSentinel.DEFAULT),
+            field,
+            index,
+            param.with_changes(comma=_get_last_comma(params)),
+        )
+    return updated
+
+
+def remove_param(params: libcst.Parameters, name: str) -> Optional[libcst.Parameters]:
+    """
+    Return ``params`` without the parameter called ``name``, or ``None`` if there
+    isn't one. A ``/`` or bare ``*`` that's no longer needed is removed too, and if
+    the removed parameter was last, its trailing comma (or lack of one) moves to the
+    parameter before it.
+    """
+    found = _find_param(params, name)
+    if found is None:
+        return None
+    field, index = found
+    changes: Dict[str, object] = {}
+    if field in _PARAM_LISTS:
+        existing = getattr(params, field)
+        removed = existing[index]
+        is_last = _last_field(params) == field and index == len(existing) - 1
+        changes[field] = [*existing[:index], *existing[index + 1 :]]
+        if len(existing) == 1:
+            if field == "posonly_params":
+                changes["posonly_ind"] = MaybeSentinel.DEFAULT
+            elif field == "kwonly_params" and isinstance(
+                params.star_arg, libcst.ParamStar
+            ):
+                changes["star_arg"] = MaybeSentinel.DEFAULT
+    else:
+        removed = getattr(params, field)
+        is_last = _last_field(params) == field
+        changes[field] = MaybeSentinel.DEFAULT if field == "star_arg" else None
+    updated = params.with_changes(**changes)
+    return _set_last_comma(updated, removed.comma) if is_last else updated
@@ -0,0 +1,112 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+import libcst
+from libcst._nodes.whitespace import NEWLINE_RE
+
+
+class _ComplexityVisitor(libcst.CSTVisitor):
+    def __init__(self) -> None:
+        super().__init__()
+        self.complexity = 1
+
+    def visit_FunctionDef(self, node: libcst.FunctionDef) -> bool:
+        # Nested functions and classes are measured on their own.
+        return False
+
+    def visit_ClassDef(self, node: libcst.ClassDef) -> bool:
+        return False
+
+    def visit_If(self, node: libcst.If) -> None:
+        # This also covers ``elif``, which is an ``If`` nested in ``orelse``.
+        self.complexity += 1
+
+    def visit_For(self, node: libcst.For) -> None:
+        self.complexity += 1
+
+    def visit_While(self, node: libcst.While) -> None:
+        self.complexity += 1
+
+    def visit_ExceptHandler(self, node: libcst.ExceptHandler) -> None:
+        self.complexity += 1
+
+    def visit_ExceptStarHandler(self, node: libcst.ExceptStarHandler) -> None:
+        self.complexity += 1
+
+    def visit_MatchCase(self, node: libcst.MatchCase) -> None:
+        # A capture or wildcard pattern such as ``case _`` always matches, so it's
+        # only a decision point if it has a guard.
+        pattern = node.pattern
+        if (
+            not isinstance(pattern, libcst.MatchAs)
+            or pattern.pattern is not None
+            or node.guard is not None
+        ):
+            self.complexity += 1
+
+    def visit_CompIf(self, node: libcst.CompIf) -> None:
+        self.complexity += 1
+
+    def visit_BooleanOperation(self, node: libcst.BooleanOperation) -> None:
+        # Every ``and`` and ``or`` is its own node, so chains are counted fully.
+        self.complexity += 1
+
+    def visit_IfExp(self, node: libcst.IfExp) -> None:
+        self.complexity += 1
+
+
+class _StatementCountVisitor(libcst.CSTVisitor):
+    def __init__(self) -> None:
+        super().__init__()
+        self.count = 0
+
+    def on_visit(self, node: libcst.CSTNode) -> bool:
+        # Visitor methods are looked up by concrete node type, so base classes have
+        # to be matched here instead.
+        if isinstance(node, (libcst.BaseSmallStatement, libcst.BaseCompoundStatement)):
+            self.count += 1
+        return super().on_visit(node)
+
+
+def cyclomatic_complexity(func: libcst.FunctionDef) -> int:
+    """
+    Return the McCabe cyclomatic complexity of a function, which is one plus the
+    number of decision points in its body. Every ``if``, ``elif``, ``for``,
+    ``while``, ``except``, ``case``, ``and``, ``or``, conditional expression and
+    ``if`` clause of a comprehension is a decision point. A ``case`` whose pattern
+    always matches, such as ``case _``, is only one if it has a guard. Functions and
+    classes defined inside of ``func`` aren't included, since they are measured
+    separately, but lambdas can't be, so their decision points count toward
+    ``func``.
+    """
+    visitor = _ComplexityVisitor()
+    func.body.visit(visitor)
+    return visitor.complexity
+
+
+def count_lines(node: libcst.Module) -> int:
+    """
+    Return the number of lines in the module's source code, which is the number of
+    newlines in it. A final line without a trailing newline is counted too.
+    """
+    code = node.code
+    lines = len(NEWLINE_RE.findall(code))
+    if code and NEWLINE_RE.fullmatch(code[-1]) is None:
+        lines += 1
+    return lines
+
+
+def count_statements(node: libcst.Module) -> int:
+    """
+    Return the number of statements in the module, including statements nested in
+    compound statements. Every small statement counts separately, so ``x = 1; y = 2``
+    is two statements, and so does every compound statement such as an ``if`` or a
+    function definition. An ``elif`` is an :class:`~libcst.If` too, so it counts as
+    its own statement.
+    """
+    visitor = _StatementCountVisitor()
+    node.visit(visitor)
+    return visitor.count
@@ -5,9 +5,38 @@
 #
 # pyre-strict
 from itertools import islice
-from typing import List
+from typing import Dict, List, Optional, Sequence, Tuple, Union
 
 import libcst
+from libcst.helpers.expression import get_full_name_for_node
+
+
+# The version of Python whose ``typing`` module added each of these names, so that
+# importing them from ``typing_extensions`` means the code runs on older versions.
+_TYPING_EXTENSIONS_VERSIONS = {
+    "OrderedDict": (3, 7),
+    "Final": (3, 8),
+    "Literal": (3, 8),
+    "Protocol": (3, 8),
+    "TypedDict": (3, 8),
+    "final": (3, 8),
+    "runtime_checkable": (3, 8),
+    "Annotated": (3, 9),
+    "Concatenate": (3, 10),
+    "ParamSpec": (3, 10),
+    "TypeAlias": (3, 10),
+    "TypeGuard": (3, 10),
+    "LiteralString": (3, 11),
+    "Never": (3, 11),
+    "NotRequired": (3, 11),
+    "Required": (3, 11),
+    "Self": (3, 11),
+    "TypeVarTuple": (3, 11),
+    "Unpack": (3, 11),
+    "assert_never": (3, 11),
+    "reveal_type": (3, 11),
+    "override": (3, 12),
+}
 
 
 def insert_header_comments(node: libcst.Module, comments: List[str]) -> libcst.Module:
@@ -30,3 +59,136 @@ def insert_header_comments(node: libcst.Module, comments: List[str]) -> libcst.M
         libcst.EmptyLine(comment=libcst.Comment(value=comment)) for comment in comments
     ]
     return node.with_changes(header=(*comment_lines, *inserted_lines, *empty_lines))
+
+
+def _previous_version(version: Tuple[int, int]) -> Tuple[int, int]:
+    major, minor = version
+    if minor > 0:
+        return (major, minor - 1)
+    # Python 2.7 was the last release before 3.0.
+    return (major - 1, 7)
+
+
+def _version_tuple(node: libcst.BaseExpression) -> Optional[Tuple[int, int]]:
+    if not isinstance(node, libcst.Tuple) or not 1 <= len(node.elements) <= 3:
+        return None
+    parts: List[int] = []
+    for element in node.elements:
+        value = element.value
+        if not isinstance(value, libcst.Integer):
+            return None
+        parts.append(value.evaluated_value)
+    return (parts[0], parts[1] if len(parts) > 1 else 0)
+
+
+class _VersionHintVisitor(libcst.CSTVisitor):
+    def __init__(self) -> None:
+        super().__init__()
+        # Versions that the code needs at least.
+        self.floors: List[Tuple[int, int]] = []
+        # Versions that the code also runs on something older than.
+        self.ceilings: List[Tuple[int, int]] = []
+
+    def _add_typing_extensions_name(self, name: str) -> None:
+        version = _TYPING_EXTENSIONS_VERSIONS.get(name)
+        if version is not None:
+            self.ceilings.append(_previous_version(version))
+
+    def visit_ImportFrom(self, node: libcst.ImportFrom) -> None:
+        module = node.module
+        if node.relative or module is None:
+            return
+        names = node.names
+        if isinstance(names, libcst.ImportStar):
+            return
+        module_name = get_full_name_for_node(module)
+        if module_name == "__future__":
+            if any(alias.evaluated_name == "annotations" for alias in names):
+                self.floors.append((3, 7))
+        elif module_name == "typing_extensions":
+            for alias in names:
+                self._add_typing_extensions_name(alias.evaluated_name)
+
+    def visit_Attribute(self, node: libcst.Attribute) -> None:
+        if get_full_name_for_node(node.value) == "typing_extensions":
+            self._add_typing_extensions_name(node.attr.value)
+
+    def visit_Comparison(self, node: libcst.Comparison) -> None:
+        operands: Sequence[libcst.BaseExpression] = [
+            node.left,
+            *(target.comparator for target in node.comparisons),
+        ]
+        if not any(
+            get_full_name_for_node(operand) == "sys.version_info"
+            for operand in operands
+        ):
+            return
+        for operand in operands:
+            version = _version_tuple(operand)
+            if version is not None:
+                # Checking the version only makes sense if the code also runs on
+                # versions older than the one it's compared against.
+                self.ceilings.append(_previous_version(version))
+
+
+def detect_python_version_from_imports(
+    node: libcst.Module,
+) -> Optional[Tuple[int, int]]:
+    """
+    Guess the oldest Python version that the module is written to support, as a
+    ``(major, minor)`` tuple, or return ``None`` if there is nothing to go on.
+
+    This is a heuristic that looks at a few hints. A ``from __future__ import
+    annotations`` means the code needs at least Python 3.7. Comparing
+    ``sys.version_info`` against a version, or importing a name from
+    ``typing_extensions`` that was added to ``typing`` in some version, means the
+    code also runs on the release before that version. The oldest such release is
+    returned, unless the code needs a newer one anyway.
+    """
+    visitor = _VersionHintVisitor()
+    node.visit(visitor)
+    floor = max(visitor.floors, default=None)
+    ceiling = min(visitor.ceilings, default=None)
+    if ceiling is None:
+        return floor
+    if floor is None:
+        return ceiling
+    return max(floor, ceiling)
+
+
+def is_import_from_future(
+    statement: Union[libcst.BaseStatement, libcst.BaseSmallStatement]
+) -> bool:
+    """
+    Return whether ``statement`` is a ``from __future__ import ...`` statement. A
+    :class:`~libcst.SimpleStatementLine` counts if any of its statements is one.
+    """
+    if isinstance(statement, libcst.SimpleStatementLine):
+        return any(is_import_from_future(small) for small in statement.body)
+    if not isinstance(statement, libcst.ImportFrom) or statement.relative:
+        return False
+    module = statement.module
+    return module is not None and get_full_name_for_node(module) == "__future__"
+
+
+def get_future_imports(module: libcst.Module) -> List[str]:
+    """
+    Return the names of the features that ``module`` imports from ``__future__``,
+    such as ``["annotations"]`` for ``from __future__ import annotations``, in the
+    order they're imported and without duplicates. Only statements at the top
+    level of the module are considered, since that's the only place where Python
+    allows them.
+    """
+    features: Dict[str, None] = {}
+    for statement in module.body:
+        if not isinstance(statement, libcst.SimpleStatementLine):
+            continue
+        for small in statement.body:
+            if not is_import_from_future(small):
+                continue
+            names = libcst.ensure_type(small, libcst.ImportFrom).names
+            if isinstance(names, libcst.ImportStar):
+                continue
+            for alias in names:
+                features[alias.evaluated_name] = None
+    return list(features)
@@ -0,0 +1,101 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Iterator, List, Mapping, Optional, Sequence, Type, TypeVar, Union
+
+import libcst
+from libcst._nodes.whitespace import NEWLINE_RE
+from libcst._position import CodePosition, CodeRange
+from libcst.metadata import MetadataWrapper, WhitespaceInclusivePositionProvider
+
+
+_StatementT = TypeVar(
+    "_StatementT", bound=Union[libcst.BaseStatement, libcst.BaseSmallStatement]
+)
+
+
+def iter_nodes(node: libcst.CSTNode) -> Iterator[libcst.CSTNode]:
+    """
+    Yield ``node`` and all of its transitive children in depth-first order, visiting
+    each node before its children and children in the order they appear lexically.
+
+    This is a lightweight alternative to writing a :class:`~libcst.CSTVisitor` when
+    you only need to inspect nodes, and composes with the rest of Python's iteration
+    tools. For example, to collect every function definition in a module::
+
+        functions = [n for n in iter_nodes(module) if isinstance(n, cst.FunctionDef)]
+    """
+    stack: List[libcst.CSTNode] = [node]
+    while stack:
+        current = stack.pop()
+        yield current
+        stack.extend(reversed(current.children))
+
+
+def _contains(code_range: CodeRange, position: CodePosition) -> bool:
+    start = (code_range.start.line, code_range.start.column)
+    end = (code_range.end.line, code_range.end.column)
+    return start <= (position.line, position.column) < end
+
+
+def find_node_at_offset(module: libcst.Module, offset: int) -> Optional[libcst.CSTNode]:
+    """
+    Return the innermost node in ``module`` whose source code contains the byte at
+    ``offset``, or ``None`` if the offset is outside of the module's code. The offset
+    is counted in bytes of the module's encoded source (see
+    :attr:`~libcst.Module.bytes`), so it can be used directly with offsets reported by
+    editors and other tools.
+
+    Ranges include all of the whitespace owned by each node, so an offset pointing at
+    whitespace or a comment resolves to that whitespace or comment node. The tree is
+    searched by descending only into the child containing the offset at each level.
+    """
+    if offset < 0:
+        return None
+    source = module.bytes
+    if offset >= len(source):
+        return None
+    # An offset in the middle of a multi-byte character belongs to that character.
+    segments = NEWLINE_RE.split(source[:offset].decode(module.encoding, "ignore"))
+    position = CodePosition(len(segments), len(segments[-1]))
+
+    positions: Mapping[libcst.CSTNode, CodeRange] = MetadataWrapper(
+        module, unsafe_skip_copy=True
+    ).resolve(WhitespaceInclusivePositionProvider)
+
+    node = module
+    while True:
+        for child in node.children:
+            if _contains(positions[child], position):
+                node = child
+                break
+        else:
+            return node
+
+
+def get_statements_of_type(
+    block: Union[libcst.BaseSuite, libcst.Module], statement_type: Type[_StatementT]
+) -> List[_StatementT]:
+    """
+    Return the statements directly in ``block`` that are instances of
+    ``statement_type``, in the order they appear. ``block`` is usually the body of a
+    class or function, or a module. Small statements such as
+    :class:`~libcst.AnnAssign` are found inside of each
+    :class:`~libcst.SimpleStatementLine`, so both compound and small statement types
+    can be used. Statements in nested blocks aren't included. For example, to get the
+    methods of a class::
+
+        methods = get_statements_of_type(cls.body, cst.FunctionDef)
+    """
+    statements: List[Union[libcst.BaseStatement, libcst.BaseSmallStatement]] = []
+    body: Sequence[Union[libcst.BaseStatement, libcst.BaseSmallStatement]] = block.body
+    for statement in body:
+        statements.append(statement)
+        if isinstance(statement, libcst.SimpleStatementLine):
+            statements.extend(statement.body)
+    return [
+        statement for statement in statements if isinstance(statement, statement_type)
+    ]
@@ -0,0 +1,235 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import List, Optional, Sequence, Set, Union
+
+import libcst
+from libcst import MaybeSentinel
+from libcst.helpers.expression import get_full_name_for_node, resolve_annotation
+
+
+_ImportT = Union[libcst.Import, libcst.ImportFrom]
+
+
+class _NameCollector(libcst.CSTVisitor):
+    """
+    Collects the names that a stub refers to, which are the names it needs to import.
+    """
+
+    def __init__(self) -> None:
+        super().__init__()
+        self.names: Set[str] = set()
+        self.annotation_depth = 0
+
+    def visit_Name(self, node: libcst.Name) -> None:
+        self.names.add(node.value)
+
+    def visit_Attribute(self, node: libcst.Attribute) -> bool:
+        # Only the leftmost name of ``a.b.c`` needs to be imported.
+        node.value.visit(self)
+        return False
+
+    def visit_Annotation(self, node: libcst.Annotation) -> None:
+        self.annotation_depth += 1
+
+    def leave_Annotation(self, original_node: libcst.Annotation) -> None:
+        self.annotation_depth -= 1
+
+    def visit_SimpleString(self, node: libcst.SimpleString) -> None:
+        if self.annotation_depth == 0:
+            return
+        # Forward references refer to names too.
+        try:
+            expression = resolve_annotation(libcst.Annotation(annotation=node))
+        except libcst.ParserSyntaxError:
+            return
+        if expression is not node:
+            expression.visit(self)
+
+    def _visit_all(self, target: libcst.BaseExpression, value: libcst.CSTNode) -> None:
+        # Names that are exported through ``__all__`` might be re-exported imports.
+        if not isinstance(target, libcst.Name) or target.value != "__all__":
+            return
+        if isinstance(value, (libcst.List, libcst.Tuple)):
+            for element in value.elements:
+                name = element.value
+                if isinstance(name, libcst.SimpleString):
+                    evaluated = name.evaluated_value
+                    if isinstance(evaluated, str):
+                        self.names.add(evaluated)
+
+    def visit_Assign(self, node: libcst.Assign) -> None:
+        for target in node.targets:
+            self._visit_all(target.target, node.value)
+
+    def visit_AugAssign(self, node: libcst.AugAssign) -> None:
+        self._visit_all(node.target, node.value)
+
+
+def _ellipsis_suite() -> libcst.SimpleStatementSuite:
+    return libcst.SimpleStatementSuite(body=[libcst.Expr(libcst.Ellipsis())])
+
+
+def _is_all(target: libcst.BaseExpression) -> bool:
+    return isinstance(target, libcst.Name) and target.value == "__all__"
+
+
+def _is_type_checking(node: libcst.If) -> bool:
+    name = get_full_name_for_node(node.test)
+    return name is not None and name.split(".")[-1] == "TYPE_CHECKING"
+
+
+def _stub_param(param: libcst.Param) -> libcst.Param:
+    if param.default is None:
+        return param
+    return param.with_changes(default=libcst.Ellipsis())
+
+
+def _stub_function(func: libcst.FunctionDef) -> libcst.FunctionDef:
+    params = func.params
+    return func.with_changes(
+        params=params.with_changes(
+            posonly_params=[_stub_param(param) for param in params.posonly_params],
+            params=[_stub_param(param) for param in params.params],
+            kwonly_params=[_stub_param(param) for param in params.kwonly_params],
+        ),
+        body=_ellipsis_suite(),
+    )
+
+
+def _stub_small_statements(
+    statements: Sequence[libcst.BaseSmallStatement],
+) -> List[libcst.BaseSmallStatement]:
+    kept: List[libcst.BaseSmallStatement] = []
+    for statement in statements:
+        if isinstance(statement, libcst.Assign):
+            if len(statement.targets) == 1 and _is_all(statement.targets[0].target):
+                kept.append(statement)
+        elif isinstance(statement, libcst.AugAssign):
+            if _is_all(statement.target):
+                kept.append(statement)
+        elif isinstance(statement, libcst.AnnAssign):
+            # The annotation is what matters for a stub, so only the value goes.
+            if isinstance(statement.target, libcst.Name):
+                kept.append(
+                    statement.with_changes(value=None, equal=MaybeSentinel.DEFAULT)
+                )
+    if kept:
+        kept[-1] = kept[-1].with_changes(semicolon=MaybeSentinel.DEFAULT)
+    return kept
+
+
+def _stub_class(cls: libcst.ClassDef) -> libcst.ClassDef:
+    body = cls.body
+    if isinstance(body, libcst.IndentedBlock):
+        members = _stub_statements(body.body, None)
+        return cls.with_changes(
+            body=body.with_changes(body=members) if members else _ellipsis_suite()
+        )
+    small_statements = _stub_small_statements(body.body)
+    return cls.with_changes(
+        body=(
+            body.with_changes(body=small_statements)
+            if small_statements
+            else _ellipsis_suite()
+        )
+    )
+
+
+def _stub_statements(
+    statements: Sequence[libcst.BaseStatement],
+    imports: Optional[List[libcst.SimpleStatementLine]],
+) -> List[libcst.BaseStatement]:
+    """
+    Returns the stubs for ``statements``. Imports are only kept at the top level of
+    the module, where they are moved into ``imports`` instead.
+    """
+    result: List[libcst.BaseStatement] = []
+    for statement in statements:
+        if isinstance(statement, libcst.FunctionDef):
+            result.append(_stub_function(statement))
+        elif isinstance(statement, libcst.ClassDef):
+            result.append(_stub_class(statement))
+        elif isinstance(statement, libcst.If):
+            # Imports that are only needed for type checking are needed in stubs too.
+            body = statement.body
+            if (
+                imports is not None
+                and _is_type_checking(statement)
+                and isinstance(body, libcst.IndentedBlock)
+            ):
+                result.extend(_stub_statements(body.body, imports))
+        elif isinstance(statement, libcst.SimpleStatementLine):
+            if imports is not None:
+                imports.extend(
+                    statement.with_changes(body=[small_statement])
+                    for small_statement in statement.body
+                    if isinstance(small_statement, (libcst.Import, libcst.ImportFrom))
+                )
+            small_statements = _stub_small_statements(statement.body)
+            if small_statements:
+                result.append(statement.with_changes(body=small_statements))
+    return result
+
+
+def _bound_name(node: _ImportT, alias: libcst.ImportAlias) -> str:
+    asname = alias.asname
+    if asname is not None:
+        return libcst.ensure_type(asname.name, libcst.Name).value
+    if isinstance(node, libcst.Import):
+        # ``import a.b`` binds ``a``.
+        return alias.evaluated_name.split(".")[0]
+    return alias.evaluated_name
+
+
+def _filter_import(
+    line: libcst.SimpleStatementLine, used: Set[str]
+) -> Optional[libcst.SimpleStatementLine]:
+    node = line.body[0]
+    if not isinstance(node, (libcst.Import, libcst.ImportFrom)):
+        return None
+    if isinstance(node, libcst.ImportFrom):
+        module = node.module
+        if not node.relative and module is not None:
+            if get_full_name_for_node(module) == "__future__":
+                return None
+    names = node.names
+    if isinstance(names, libcst.ImportStar):
+        return line
+    aliases = [alias for alias in names if _bound_name(node, alias) in used]
+    if not aliases:
+        return None
+    aliases[-1] = aliases[-1].with_changes(comma=MaybeSentinel.DEFAULT)
+    return line.with_changes(
+        body=[node.with_changes(names=aliases, semicolon=MaybeSentinel.DEFAULT)]
+    )
+
+
+def generate_stub(module: libcst.Module) -> libcst.Module:
+    """
+    Return a type stub (``.pyi`` file) for a module. Functions and methods keep
+    their signatures and decorators, but their bodies are replaced with ``...``, and
+    so are the values of default arguments. Classes keep their bases, decorators,
+    methods, nested classes and annotated attributes. Annotated assignments lose
+    their values, and all other assignments are removed, except for the ones to
+    ``__all__``. Anything else, such as docstrings and top-level code, is removed.
+
+    Imports are moved to the top of the stub, including the ones in ``if
+    TYPE_CHECKING:`` blocks, and only the ones that the stub refers to are kept.
+    Names exported through ``__all__`` count as referred to, so re-exports are kept.
+    """
+    imports: List[libcst.SimpleStatementLine] = []
+    body = _stub_statements(module.body, imports)
+
+    collector = _NameCollector()
+    for statement in body:
+        statement.visit(collector)
+    used_imports: List[libcst.BaseStatement] = []
+    for line in imports:
+        filtered = _filter_import(line, collector.names)
+        if filtered is not None:
+            used_imports.append(filtered)
+    return module.with_changes(body=[*used_imports, *body])
@@ -0,0 +1,73 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Sequence
+
+import libcst as cst
+from libcst.helpers import ClassBodyAnalysis, analyze_class_body, ensure_type
+from libcst.testing.utils import UnitTest, data_provider
+
+
+def _analyze(code: str) -> ClassBodyAnalysis:
+    return analyze_class_body(ensure_type(cst.parse_statement(code), cst.ClassDef))
+
+
+class ClassBodyAnalysisTest(UnitTest):
+    def test_empty(self) -> None:
+        analysis = _analyze("class Foo: pass\n")
+        self.assertEqual(analysis.methods, [])
+        self.assertEqual(analysis.class_variables, [])
+        self.assertIsNone(analysis.init_method)
+        self.assertFalse(analysis.is_dataclass)
+        self.assertEqual(analysis.base_names, [])
+
+    def test_members(self) -> None:
+        analysis = _analyze(
+            "class Foo:\n"
+            "    x: int\n"
+            "    y: str = ''\n"
+            "    z = 1\n"
+            "    def __init__(self) -> None:\n"
+            "        self.a: int = 1\n"
+            "    async def foo(self) -> None: ...\n"
+            "    class Bar:\n"
+            "        def bar(self) -> None: ...\n"
+        )
+        self.assertEqual(
+            [method.name.value for method in analysis.methods], ["__init__", "foo"]
+        )
+        self.assertEqual(
+            [
+                ensure_type(variable.target, cst.Name).value
+                for variable in analysis.class_variables
+            ],
+            ["x", "y"],
+        )
+        self.assertIs(analysis.init_method, analysis.methods[0])
+
+    @data_provider(
+        (
+            ("@dataclass\nclass Foo: pass\n", True),
+            ("@dataclass(frozen=True)\nclass Foo: pass\n", True),
+            ("@dataclasses.dataclass\nclass Foo: pass\n", True),
+            ("@add_slots\n@dataclass\nclass Foo: pass\n", True),
+            ("@other.dataclass\nclass Foo: pass\n", False),
+            ("@final\nclass Foo: pass\n", False),
+        )
+    )
+    def test_is_dataclass(self, code: str, expected: bool) -> None:
+        self.assertEqual(_analyze(code).is_dataclass, expected)
+
+    @data_provider(
+        (
+            ("class Foo(Bar): pass\n", ["Bar"]),
+            ("class Foo(a.Bar, Generic[T]): pass\n", ["a.Bar", "Generic"]),
+            ("class Foo(Bar, metaclass=Meta): pass\n", ["Bar"]),
+            ("class Foo(namedtuple('Foo', 'a')): pass\n", ["namedtuple"]),
+        )
+    )
+    def test_base_names(self, code: str, expected: Sequence[str]) -> None:
+        self.assertEqual(_analyze(code).base_names, expected)
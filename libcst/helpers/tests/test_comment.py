@@ -0,0 +1,122 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import List, Optional
+
+import libcst as cst
+from libcst.helpers import (
+    NoqaComment,
+    extract_type_comment,
+    has_noqa,
+    is_type_ignore,
+    noqa_codes,
+    parse_noqa,
+    parse_type_comment,
+    type_ignore_codes,
+)
+from libcst.testing.utils import UnitTest, data_provider
+
+
+class CommentTest(UnitTest):
+    @data_provider(
+        (
+            ("# type: List[int]", "List[int]"),
+            ("#type:int", "int"),
+            ("#  type:   Dict[str, int]   ", "Dict[str, int]"),
+            ("# type: (int, str) -> bool", "(int, str) -> bool"),
+            ("# type: int  # a comment", "int"),
+            ("# type: ignore", None),
+            ("# type: ignore[attr-defined]", None),
+            ("# type:", None),
+            ("# types: int", None),
+            ("# a type: int", None),
+            ("# noqa", None),
+        )
+    )
+    def test_extract_type_comment(self, comment: str, expected: Optional[str]) -> None:
+        self.assertEqual(extract_type_comment(cst.Comment(comment)), expected)
+
+    @data_provider(
+        (
+            ("# type: List[int]", "List[int]"),
+            ("# type: Optional['Foo']  # a comment", "Optional['Foo']"),
+            ("  Dict[str, int] ", "Dict[str, int]"),
+        )
+    )
+    def test_parse_type_comment(self, text: str, expected: str) -> None:
+        expression = parse_type_comment(text)
+        self.assertEqual(cst.Module([]).code_for_node(expression), expected)
+
+    def test_parse_type_comment_invalid(self) -> None:
+        with self.assertRaises(cst.ParserSyntaxError):
+            parse_type_comment("# type: (int, str) -> bool")
+
+    def test_parse_type_comment_from_module(self) -> None:
+        module = cst.parse_module("x = []  # type: List[int]\n")
+        statement = cst.ensure_type(module.body[0], cst.SimpleStatementLine)
+        comment = cst.ensure_type(statement.trailing_whitespace.comment, cst.Comment)
+        self.assertEqual(extract_type_comment(comment), "List[int]")
+        expression = parse_type_comment(comment.value)
+        self.assertEqual(cst.Module([]).code_for_node(expression), "List[int]")
+
+    @data_provider(
+        (
+            ("# noqa", []),
+            ("#noqa", []),
+            ("# NOQA", []),
+            ("# noqa: E501", ["E501"]),
+            ("# noqa:E501,W503", ["E501", "W503"]),
+            ("# noqa: E501, W503", ["E501", "W503"]),
+            ("# noqa: E501 W503 because", ["E501", "W503"]),
+            ("# type: int  # noqa: F401", ["F401"]),
+            ("# noqa: not a code", []),
+            ("# noqanother", None),
+            ("# a comment", None),
+        )
+    )
+    def test_parse_noqa(self, comment: str, expected: Optional[List[str]]) -> None:
+        noqa = parse_noqa(cst.Comment(comment))
+        self.assertEqual(
+            noqa, NoqaComment(codes=expected) if expected is not None else None
+        )
+
+    def test_noqa_trailing_whitespace(self) -> None:
+        module = cst.parse_module(
+            "import a  # noqa\nimport b  # noqa: F401, E402\nimport c  # comment\n"
+            + "import d\n"
+        )
+        trailing = [
+            cst.ensure_type(statement, cst.SimpleStatementLine).trailing_whitespace
+            for statement in module.body
+        ]
+        self.assertEqual(
+            [has_noqa(whitespace) for whitespace in trailing],
+            [True, True, False, False],
+        )
+        self.assertEqual(
+            [noqa_codes(whitespace) for whitespace in trailing],
+            [[], ["F401", "E402"], [], []],
+        )
+
+    @data_provider(
+        (
+            ("# type: ignore", []),
+            ("#type:ignore", []),
+            ("# type: ignore[attr-defined]", ["attr-defined"]),
+            ("# type: ignore [attr-defined, misc]", ["attr-defined", "misc"]),
+            ("# type: ignore[]", []),
+            ("# type: ignore  # noqa", []),
+            ("# type: ignore[misc]  # reason", ["misc"]),
+            ("# type: ignored", None),
+            ("# type: ignore because", None),
+            ("# type: int", None),
+            ("# ignore", None),
+        )
+    )
+    def test_type_ignore(self, comment: str, expected: Optional[List[str]]) -> None:
+        node = cst.Comment(comment)
+        self.assertEqual(is_type_ignore(node), expected is not None)
+        self.assertEqual(type_ignore_codes(node), expected or [])
@@ -0,0 +1,151 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Optional, Sequence, Tuple
+
+import libcst as cst
+from libcst.helpers import CstDiffKind, DiffConflictError, apply_diff, diff_modules
+from libcst.testing.utils import UnitTest, data_provider
+
+
+def _summary(
+    a: cst.Module, b: cst.Module
+) -> Sequence[Tuple[CstDiffKind, Optional[str], Optional[str], int, int]]:
+    return [
+        (
+            diff.kind,
+            None if diff.old is None else a.code_for_node(diff.old),
+            None if diff.new is None else b.code_for_node(diff.new),
+            diff.old_index,
+            diff.new_index,
+        )
+        for diff in diff_modules(a, b)
+    ]
+
+
+class DiffModulesTest(UnitTest):
+    @data_provider(
+        {
+            "identical": {
+                "a": "import os\nx = 1\n",
+                "b": "import os\nx = 1\n",
+                "expected": [],
+            },
+            "added": {
+                "a": "x = 1\nz = 3\n",
+                "b": "x = 1\ny = 2\nz = 3\n",
+                "expected": [(CstDiffKind.ADDED, None, "y = 2\n", 1, 1)],
+            },
+            "removed": {
+                "a": "x = 1\ny = 2\nz = 3\n",
+                "b": "x = 1\nz = 3\n",
+                "expected": [(CstDiffKind.REMOVED, "y = 2\n", None, 1, 1)],
+            },
+            "changed": {
+                "a": "def foo():\n    return 1\nx = 1\n",
+                "b": "def foo():\n    return 2\nx = 1\n",
+                "expected": [
+                    (
+                        CstDiffKind.CHANGED,
+                        "def foo():\n    return 1\n",
+                        "def foo():\n    return 2\n",
+                        0,
+                        0,
+                    )
+                ],
+            },
+            "comment_change": {
+                "a": "x = 1\ny = 2\n",
+                "b": "x = 1\n# comment\ny = 2\n",
+                "expected": [
+                    (CstDiffKind.CHANGED, "y = 2\n", "# comment\ny = 2\n", 1, 1)
+                ],
+            },
+            "replaced_run": {
+                "a": "a = 1\nb = 2\nc = 3\nd = 4\n",
+                "b": "a = 1\nx = 2\nd = 4\ny = 5\nz = 6\n",
+                "expected": [
+                    (CstDiffKind.CHANGED, "b = 2\n", "x = 2\n", 1, 1),
+                    (CstDiffKind.REMOVED, "c = 3\n", None, 2, 2),
+                    (CstDiffKind.ADDED, None, "y = 5\n", 4, 3),
+                    (CstDiffKind.ADDED, None, "z = 6\n", 4, 4),
+                ],
+            },
+            "header_ignored": {
+                "a": "# header\n\nx = 1\n",
+                "b": "# other header\n\nx = 1\n",
+                "expected": [],
+            },
+        }
+    )
+    def test_diff_modules(
+        self,
+        a: str,
+        b: str,
+        expected: Sequence[Tuple[CstDiffKind, Optional[str], Optional[str], int, int]],
+    ) -> None:
+        self.assertEqual(_summary(cst.parse_module(a), cst.parse_module(b)), expected)
+
+    def test_diff_nodes(self) -> None:
+        a = cst.parse_module("x = 1\ny = 2\n")
+        b = cst.parse_module("x = 1\ny = 3\n")
+        (diff,) = diff_modules(a, b)
+        self.assertIs(diff.old, a.body[1])
+        self.assertIs(diff.new, b.body[1])
+
+
+class ApplyDiffTest(UnitTest):
+    @data_provider(
+        {
+            "identical": {"a": "x = 1\n", "b": "x = 1\n"},
+            "added": {"a": "x = 1\n", "b": "w = 0\nx = 1\ny = 2\n"},
+            "removed": {"a": "x = 1\ny = 2\nz = 3\n", "b": "y = 2\n"},
+            "replaced_run": {
+                "a": "a = 1\nb = 2\nc = 3\nd = 4\n",
+                "b": "a = 1\nx = 2\nd = 4\ny = 5\nz = 6\n",
+            },
+            "swapped": {"a": "x = 1\ny = 2\n", "b": "y = 2\nx = 1\n"},
+        }
+    )
+    def test_round_trip(self, a: str, b: str) -> None:
+        old = cst.parse_module(a)
+        new = cst.parse_module(b)
+        self.assertEqual(apply_diff(old, diff_modules(old, new)).code, b)
+
+    @data_provider(
+        {
+            "unrelated_insertions": {
+                "a": "x = 1\ny = 2\nz = 3\n",
+                "b": "x = 1\ny = 20\nz = 3\nw = 4\n",
+                "base": "import os\nx = 1\nv = 0\ny = 2\nz = 3\n",
+                "expected": "import os\nx = 1\nv = 0\ny = 20\nz = 3\nw = 4\n",
+            },
+            "unrelated_removals": {
+                "a": "x = 1\ny = 2\nz = 3\n",
+                "b": "x = 1\nz = 3\nw = 4\n",
+                "base": "# header\n\ny = 2\nz = 3\n",
+                "expected": "# header\n\nz = 3\nw = 4\n",
+            },
+            "duplicate_statements": {
+                "a": "pass\nx = 1\npass\n",
+                "b": "pass\nx = 1\n",
+                "base": "pass\nx = 1\npass\n",
+                "expected": "pass\nx = 1\n",
+            },
+        }
+    )
+    def test_modified_base(self, a: str, b: str, base: str, expected: str) -> None:
+        diff = diff_modules(cst.parse_module(a), cst.parse_module(b))
+        self.assertEqual(apply_diff(cst.parse_module(base), diff).code, expected)
+
+    def test_conflict(self) -> None:
+        a = cst.parse_module("x = 1\ny = 2\n")
+        b = cst.parse_module("x = 1\ny = 3\n")
+        base = cst.parse_module("x = 1\ny = 4\n")
+        diff = diff_modules(a, b)
+        with self.assertRaisesRegex(DiffConflictError, "Statement 1") as context:
+            apply_diff(base, diff)
+        self.assertIs(context.exception.diff, diff[0])
@@ -5,13 +5,23 @@
 #
 # pyre-strict
 from ast import literal_eval
-from typing import Optional, Union
+from typing import List, Optional, Union
 
 import libcst as cst
 from libcst.helpers import (
+    PyConstant,
+    collect_names_from_expression,
+    collect_names_from_statement,
     ensure_type,
+    evaluate_constant,
     get_full_name_for_node,
     get_full_name_for_node_or_raise,
+    is_constant,
+    is_truthy,
+    qualified_name_of_call,
+    resolve_annotation,
+    unwrap_parens,
+    wrap_in_parens,
 )
 from libcst.testing.utils import UnitTest, data_provider
 
@@ -68,3 +78,247 @@ class ExpressionTest(UnitTest):
         node = ensure_type(cst.parse_expression(raw_value), cst.Imaginary)
         self.assertEqual(node.value, raw_value)
         self.assertEqual(node.evaluated_value, literal_eval(raw_value))
+
+    @data_provider(
+        (
+            ("int", "int"),
+            ("List[int]", "List[int]"),
+            ('"List[int]"', "List[int]"),
+            ("'Foo.Bar'", "Foo.Bar"),
+            ('" Optional[Foo] "', "Optional[Foo]"),
+            ('"Dict[" "str, int]"', "Dict[str, int]"),
+            ('f"Foo"', 'f"Foo"'),
+        )
+    )
+    def test_resolve_annotation(self, annotation: str, expected: str) -> None:
+        node = cst.Annotation(cst.parse_expression(annotation))
+        self.assertEqual(
+            cst.Module([]).code_for_node(resolve_annotation(node)), expected
+        )
+
+    def test_resolve_annotation_cached(self) -> None:
+        first = resolve_annotation(cst.Annotation(cst.SimpleString('"Foo[int]"')))
+        second = resolve_annotation(cst.Annotation(cst.SimpleString("'Foo[int]'")))
+        self.assertIs(first, second)
+
+    def test_resolve_annotation_invalid(self) -> None:
+        with self.assertRaises(cst.ParserSyntaxError):
+            resolve_annotation(cst.Annotation(cst.SimpleString('"List["')))
+
+    @data_provider(
+        (
+            ("x", "(x)"),
+            ("(x)", "((x))"),
+            ("( x )", "(( x ))"),
+            ("a + b", "(a + b)"),
+            ("(a, b)", "((a, b))"),
+        )
+    )
+    def test_wrap_in_parens(self, code: str, expected: str) -> None:
+        node = cst.parse_expression(code)
+        wrapped = wrap_in_parens(node)
+        self.assertIsInstance(wrapped, type(node))
+        self.assertEqual(cst.Module([]).code_for_node(wrapped), expected)
+        self.assertTrue(unwrap_parens(wrapped).deep_equals(node))
+
+    @data_provider(
+        (
+            ("x", "x"),
+            ("(x)", "x"),
+            ("(( x ))", "( x )"),
+            ("(a)+(b)", "(a)+(b)"),
+            ("(\n    a\n)", "a"),
+        )
+    )
+    def test_unwrap_parens(self, code: str, expected: str) -> None:
+        node = unwrap_parens(cst.parse_expression(code))
+        self.assertEqual(cst.Module([]).code_for_node(node), expected)
+
+    @data_provider(
+        (
+            ("1", True),
+            ("1.5", True),
+            ("2j", True),
+            ("'text'", True),
+            ("b'bytes'", True),
+            ("'a' 'b'", True),
+            ("None", True),
+            ("True", True),
+            ("False", True),
+            ("...", True),
+            ("-1", True),
+            ("not True", True),
+            ("~-1", True),
+            ("(1)", True),
+            ("()", True),
+            ("(1, 'a', None)", True),
+            ("[1, [2, (3,)]]", True),
+            ("{1, 2}", True),
+            ("{}", True),
+            ("{'a': 1, 2: [3]}", True),
+            ("x", False),
+            ("f'text'", False),
+            ("'a' f'b'", False),
+            ("-x", False),
+            ("-'text'", False),
+            ("+b'x'", False),
+            ("~1.5", False),
+            ("1 + 2", False),
+            ("(1, x)", False),
+            ("[*a]", False),
+            ("{'a': x}", False),
+            ("{**a}", False),
+            ("[x for x in y]", False),
+            ("f(1)", False),
+        )
+    )
+    def test_is_constant(self, code: str, expected: bool) -> None:
+        self.assertEqual(is_constant(cst.parse_expression(code)), expected)
+
+    @data_provider(
+        (
+            ("1", 1),
+            ("0x10", 16),
+            ("1.5", 1.5),
+            ("2j", 2j),
+            ("'text'", "text"),
+            ("b'bytes'", b"bytes"),
+            ("'a' 'b'", "ab"),
+            ("None", None),
+            ("True", True),
+            ("False", False),
+            ("...", ...),
+            ("-1", -1),
+            ("+1", 1),
+            ("~1", -2),
+            ("not 0", True),
+            ("(1)", 1),
+            ("()", ()),
+            ("(3, 8)", (3, 8)),
+            ("(1, ('a', None), -2.5)", (1, ("a", None), -2.5)),
+        )
+    )
+    def test_evaluate_constant(self, code: str, expected: object) -> None:
+        self.assertEqual(
+            evaluate_constant(cst.parse_expression(code)), PyConstant(expected)
+        )
+
+    @data_provider(
+        (
+            ("x",),
+            ("f'text'",),
+            ("-x",),
+            ("-'text'",),
+            ("1 + 2",),
+            ("(1, x)",),
+            ("(*a,)",),
+            ("[1]",),
+            ("{1: 2}",),
+        )
+    )
+    def test_evaluate_constant_not_constant(self, code: str) -> None:
+        self.assertIsNone(evaluate_constant(cst.parse_expression(code)))
+
+    @data_provider(
+        (
+            ("1",),
+            ("'text'",),
+            ("None",),
+            ("-1.5",),
+            ("~-1",),
+            ("+True",),
+            ("not 'text'",),
+            ("-(not 1)",),
+            ("(1, -2j)",),
+            ("x",),
+            ("-'text'",),
+            ("+b'x'",),
+            ("~1.5",),
+            ("-()",),
+            ("(1, -'a')",),
+        )
+    )
+    def test_is_constant_agrees_with_evaluate_constant(self, code: str) -> None:
+        expr = cst.parse_expression(code)
+        self.assertEqual(is_constant(expr), evaluate_constant(expr) is not None)
+
+    @data_provider(
+        (
+            ("0", False),
+            ("0.0", False),
+            ("''", False),
+            ("b''", False),
+            ("None", False),
+            ("False", False),
+            ("not True", False),
+            ("()", False),
+            ("[]", False),
+            ("{}", False),
+            ("1", True),
+            ("-1", True),
+            ("'text'", True),
+            ("True", True),
+            ("...", True),
+            ("(0,)", True),
+            ("[x]", True),
+            ("{x}", True),
+            ("{x: y}", True),
+            ("[*a, b]", True),
+            ("x", None),
+            ("f()", None),
+            ("f'text'", None),
+            ("[*a]", None),
+            ("{**a}", None),
+            ("x or 1", None),
+        )
+    )
+    def test_is_truthy(self, code: str, expected: Optional[bool]) -> None:
+        self.assertEqual(is_truthy(cst.parse_expression(code)), expected)
+
+    @data_provider(
+        (
+            ("f()", "f"),
+            ("os.path.join(a, b)", "os.path.join"),
+            ("(a.b)(c)", "a.b"),
+            ("f()()", None),
+            ("handlers[0]()", None),
+            ("a.b().c()", None),
+            ("(lambda: 1)()", None),
+            ("'{}'.format(x)", None),
+        )
+    )
+    def test_qualified_name_of_call(self, code: str, expected: Optional[str]) -> None:
+        call = ensure_type(cst.parse_expression(code), cst.Call)
+        self.assertEqual(qualified_name_of_call(call), expected)
+
+    @data_provider(
+        (
+            ("a.b + c[d]", ["a", "c", "d"]),
+            ("a.b.c(x, key=y)", ["a", "x", "y"]),
+            ("x + x * y", ["x", "y"]),
+            ("[i for i in range(n)]", ["i", "range", "n"]),
+            ("lambda a, b=c: a", ["a", "b", "c"]),
+            ("f'{x!r}'", ["x"]),
+            ("1 + 2", []),
+        )
+    )
+    def test_collect_names_from_expression(
+        self, code: str, expected: List[str]
+    ) -> None:
+        names = collect_names_from_expression(cst.parse_expression(code))
+        self.assertEqual(names, expected)
+
+    @data_provider(
+        (
+            ("x = a.b + c\n", ["x", "a", "c"]),
+            ("import os.path as p\n", ["os", "p"]),
+            ("del x[i], y\n", ["x", "i", "y"]),
+            (
+                "def f(a: int = b) -> T:\n    return a.c + d\n",
+                ["f", "a", "int", "b", "T", "d"],
+            ),
+        )
+    )
+    def test_collect_names_from_statement(self, code: str, expected: List[str]) -> None:
+        names = collect_names_from_statement(cst.parse_statement(code))
+        self.assertEqual(names, expected)
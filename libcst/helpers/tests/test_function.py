@@ -0,0 +1,239 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import Callable, Optional
+
+import libcst as cst
+from libcst.helpers import (
+    FunctionSignature,
+    ParamInfo,
+    ParamKind,
+    ParamPosition,
+    add_param,
+    ensure_type,
+    extract_signature,
+    remove_param,
+)
+from libcst.testing.utils import UnitTest, data_provider
+
+
+def _signature(code: str) -> FunctionSignature:
+    module = cst.parse_module(code)
+    return extract_signature(ensure_type(module.body[0], cst.FunctionDef))
+
+
+class FunctionSignatureTest(UnitTest):
+    def test_no_params(self) -> None:
+        self.assertEqual(
+            _signature("def foo():\n    pass\n"),
+            FunctionSignature(name="foo", params=[], return_annotation=None),
+        )
+
+    def test_all_kinds(self) -> None:
+        signature = _signature(
+            "async def foo(a, /, b: int = 1, *args: str, c, d: 'Foo' = None, **kw)"
+            " -> Dict[str, int]:\n"
+            "    pass\n"
+        )
+        self.assertEqual(signature.name, "foo")
+        self.assertEqual(signature.return_annotation, "Dict[str, int]")
+        self.assertEqual(
+            list(signature.params),
+            [
+                ParamInfo("a", ParamKind.POSITIONAL_ONLY, None, None),
+                ParamInfo("b", ParamKind.POSITIONAL_OR_KEYWORD, "int", "1"),
+                ParamInfo("args", ParamKind.VAR_POSITIONAL, "str", None),
+                ParamInfo("c", ParamKind.KEYWORD_ONLY, None, None),
+                ParamInfo("d", ParamKind.KEYWORD_ONLY, "'Foo'", "None"),
+                ParamInfo("kw", ParamKind.VAR_KEYWORD, None, None),
+            ],
+        )
+
+    def test_bare_star(self) -> None:
+        signature = _signature("def foo(self, *, key=lambda x: x):\n    pass\n")
+        self.assertEqual(
+            list(signature.params),
+            [
+                ParamInfo("self", ParamKind.POSITIONAL_OR_KEYWORD, None, None),
+                ParamInfo("key", ParamKind.KEYWORD_ONLY, None, "lambda x: x"),
+            ],
+        )
+
+    def test_multiline(self) -> None:
+        signature = _signature(
+            "def foo(\n"
+            "    a: List[\n"
+            "        int\n"
+            "    ] = (1,\n"
+            "         2),\n"
+            ") -> None:\n"
+            "    pass\n"
+        )
+        self.assertEqual(signature.return_annotation, "None")
+        self.assertEqual(
+            list(signature.params),
+            [
+                ParamInfo(
+                    "a",
+                    ParamKind.POSITIONAL_OR_KEYWORD,
+                    "List[\n        int\n    ]",
+                    "(1,\n         2)",
+                )
+            ],
+        )
+
+
+def _edit_params(
+    code: str, edit: Callable[[cst.Parameters], Optional[cst.Parameters]]
+) -> Optional[str]:
+    module = cst.parse_module(code)
+    funcdef = ensure_type(module.body[0], cst.FunctionDef)
+    params = edit(funcdef.params)
+    if params is None:
+        return None
+    return module.with_changes(body=[funcdef.with_changes(params=params)]).code
+
+
+class ParamEditTest(UnitTest):
+    @data_provider(
+        (
+            ("def foo(): pass\n", ParamPosition.LAST, None, "def foo(x): pass\n"),
+            (
+                "def foo(a, b): pass\n",
+                ParamPosition.LAST,
+                None,
+                "def foo(a, b, x): pass\n",
+            ),
+            (
+                "def foo(a, b,): pass\n",
+                ParamPosition.LAST,
+                None,
+                "def foo(a, b, x,): pass\n",
+            ),
+            (
+                "def foo(a, *, b): pass\n",
+                ParamPosition.LAST,
+                None,
+                "def foo(a, x, *, b): pass\n",
+            ),
+            (
+                "def foo(a, b): pass\n",
+                ParamPosition.FIRST,
+                None,
+                "def foo(x, a, b): pass\n",
+            ),
+            (
+                "def foo(a, /, b): pass\n",
+                ParamPosition.FIRST,
+                None,
+                "def foo(x, a, /, b): pass\n",
+            ),
+            (
+                "def foo(a, /): pass\n",
+                ParamPosition.LAST,
+                None,
+                "def foo(a, /, x): pass\n",
+            ),
+            (
+                "def foo(a, b): pass\n",
+                ParamPosition.BEFORE,
+                "b",
+                "def foo(a, x, b): pass\n",
+            ),
+            (
+                "def foo(a, b): pass\n",
+                ParamPosition.AFTER,
+                "a",
+                "def foo(a, x, b): pass\n",
+            ),
+            (
+                "def foo(a, b): pass\n",
+                ParamPosition.AFTER,
+                "b",
+                "def foo(a, b, x): pass\n",
+            ),
+            (
+                "def foo(a, *args): pass\n",
+                ParamPosition.BEFORE,
+                "args",
+                "def foo(a, x, *args): pass\n",
+            ),
+            (
+                "def foo(a, *args): pass\n",
+                ParamPosition.AFTER,
+                "args",
+                "def foo(a, *args, x): pass\n",
+            ),
+            (
+                "def foo(a, *, b): pass\n",
+                ParamPosition.AFTER,
+                "b",
+                "def foo(a, *, b, x): pass\n",
+            ),
+            (
+                "def foo(a, **kw): pass\n",
+                ParamPosition.BEFORE,
+                "kw",
+                "def foo(a, x, **kw): pass\n",
+            ),
+            (
+                "def foo(*, a, **kw): pass\n",
+                ParamPosition.BEFORE,
+                "kw",
+                "def foo(*, a, x, **kw): pass\n",
+            ),
+        )
+    )
+    def test_add_param(
+        self,
+        code: str,
+        position: ParamPosition,
+        anchor: Optional[str],
+        expected: str,
+    ) -> None:
+        param = cst.Param(cst.Name("x"))
+        self.assertEqual(
+            _edit_params(
+                code, lambda params: add_param(params, param, position, anchor)
+            ),
+            expected,
+        )
+
+    @data_provider(
+        (
+            (ParamPosition.BEFORE, None),
+            (ParamPosition.BEFORE, "missing"),
+            (ParamPosition.AFTER, "kw"),
+        )
+    )
+    def test_add_param_invalid(
+        self, position: ParamPosition, anchor: Optional[str]
+    ) -> None:
+        funcdef = ensure_type(
+            cst.parse_statement("def foo(a, **kw): pass\n"), cst.FunctionDef
+        )
+        with self.assertRaises(ValueError):
+            add_param(funcdef.params, cst.Param(cst.Name("x")), position, anchor)
+
+    @data_provider(
+        (
+            ("def foo(a, b): pass\n", "a", "def foo(b): pass\n"),
+            ("def foo(a, b): pass\n", "b", "def foo(a): pass\n"),
+            ("def foo(a, b,): pass\n", "b", "def foo(a,): pass\n"),
+            ("def foo(a, /, b): pass\n", "a", "def foo(b): pass\n"),
+            ("def foo(a, b, /): pass\n", "b", "def foo(a, /): pass\n"),
+            ("def foo(a, *args): pass\n", "args", "def foo(a): pass\n"),
+            ("def foo(a, *args, b): pass\n", "args", "def foo(a, *, b): pass\n"),
+            ("def foo(a, *, b): pass\n", "b", "def foo(a): pass\n"),
+            ("def foo(a, *, b, **kw): pass\n", "b", "def foo(a, **kw): pass\n"),
+            ("def foo(a, **kw): pass\n", "kw", "def foo(a): pass\n"),
+            ("def foo(a, **kw): pass\n", "missing", None),
+        )
+    )
+    def test_remove_param(self, code: str, name: str, expected: Optional[str]) -> None:
+        self.assertEqual(
+            _edit_params(code, lambda params: remove_param(params, name)), expected
+        )
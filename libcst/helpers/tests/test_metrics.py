@@ -0,0 +1,171 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from textwrap import dedent
+
+import libcst as cst
+from libcst.helpers import (
+    count_lines,
+    count_statements,
+    cyclomatic_complexity,
+    ensure_type,
+)
+from libcst.testing.utils import UnitTest, data_provider
+
+
+class CyclomaticComplexityTest(UnitTest):
+    @data_provider(
+        (
+            ("def foo():\n    pass\n", 1),
+            ("def foo(a):\n    return a if a else None\n", 2),
+            ("def foo(a, b, c):\n    return a and b or c\n", 3),
+            (
+                """
+                def foo(items):
+                    for item in items:
+                        if item > 0:
+                            print(item)
+                        elif item < 0:
+                            continue
+                        else:
+                            break
+                    while items:
+                        items.pop()
+                """,
+                5,
+            ),
+            (
+                """
+                async def foo(a):
+                    try:
+                        async for x in a:
+                            pass
+                    except ValueError:
+                        pass
+                    except (TypeError, KeyError):
+                        pass
+                    finally:
+                        pass
+                """,
+                4,
+            ),
+            (
+                """
+                def foo(a):
+                    def bar(b):
+                        if b:
+                            return b
+                    class Baz:
+                        x = 1 if a else 2
+                    return lambda c: c or a
+                """,
+                2,
+            ),
+            (
+                """
+                def foo(items):
+                    return [x for x in items if x if x > 0 for y in x if y]
+                """,
+                4,
+            ),
+            (
+                """
+                def foo(a):
+                    return lambda b: b if a else None
+                """,
+                2,
+            ),
+        )
+    )
+    def test_cyclomatic_complexity(self, code: str, expected: int) -> None:
+        func = ensure_type(cst.parse_statement(dedent(code)), cst.FunctionDef)
+        self.assertEqual(cyclomatic_complexity(func), expected)
+
+    def test_match(self) -> None:
+        code = dedent(
+            """
+            def foo(command):
+                match command:
+                    case "go":
+                        pass
+                    case "stop" if command:
+                        pass
+                    case other if other:
+                        pass
+                    case _:
+                        pass
+            """
+        )
+        func = ensure_type(
+            cst.parse_statement(
+                code, config=cst.PartialParserConfig(python_version="3.10")
+            ),
+            cst.FunctionDef,
+        )
+        # ``case _`` always matches, so it isn't a decision point.
+        self.assertEqual(cyclomatic_complexity(func), 4)
+
+    def test_except_star(self) -> None:
+        code = dedent(
+            """
+            def foo():
+                try:
+                    pass
+                except* ValueError:
+                    pass
+                except* (TypeError, KeyError):
+                    pass
+            """
+        )
+        func = ensure_type(
+            cst.parse_statement(
+                code, config=cst.PartialParserConfig(python_version="3.11")
+            ),
+            cst.FunctionDef,
+        )
+        self.assertEqual(cyclomatic_complexity(func), 3)
+
+
+class CountTest(UnitTest):
+    @data_provider(
+        (
+            ("", 0),
+            ("\n", 1),
+            ("x = 1", 1),
+            ("x = 1\n", 1),
+            ("x = 1\r\ny = 2\rz = 3\n", 3),
+            ("# comment\n\nx = (\n    1\n)", 5),
+        )
+    )
+    def test_count_lines(self, code: str, expected: int) -> None:
+        self.assertEqual(count_lines(cst.parse_module(code)), expected)
+
+    @data_provider(
+        (
+            ("", 0),
+            ("# comment\n", 0),
+            ("x = 1; y = 2\n", 2),
+            (
+                """
+                import os
+
+                def foo(a):
+                    if a:
+                        pass
+                    elif not a:
+                        return 1
+                    else:
+                        x = 1; y = 2
+
+                class Bar:
+                    def baz(self): return self
+                """,
+                11,
+            ),
+        )
+    )
+    def test_count_statements(self, code: str, expected: int) -> None:
+        self.assertEqual(count_statements(cst.parse_module(dedent(code))), expected)
@@ -4,9 +4,16 @@
 # LICENSE file in the root directory of this source tree.
 #
 # pyre-strict
+from typing import List, Optional, Tuple
+
 import libcst
-from libcst.helpers import insert_header_comments
-from libcst.testing.utils import UnitTest
+from libcst.helpers import (
+    detect_python_version_from_imports,
+    get_future_imports,
+    insert_header_comments,
+    is_import_from_future,
+)
+from libcst.testing.utils import UnitTest, data_provider
 
 
 class ModuleTest(UnitTest):
@@ -58,3 +65,80 @@ class ModuleTest(UnitTest):
         self.assertEqual(
             insert_header_comments(node, inserted_comments).code, expected_code
         )
+
+    @data_provider(
+        (
+            ("import os\n", None),
+            ("from __future__ import annotations\n", (3, 7)),
+            ("from __future__ import division\n", None),
+            ("if sys.version_info >= (3, 8):\n    pass\n", (3, 7)),
+            ("if (3,) <= sys.version_info:\n    pass\n", (2, 7)),
+            (
+                "PY2 = sys.version_info < (3, 0)\n"
+                "PY36 = sys.version_info >= (3, 6)\n",
+                (2, 7),
+            ),
+            ("if version_info >= (3, 8):\n    pass\n", None),
+            ("from typing_extensions import Literal, Protocol\n", (3, 7)),
+            (
+                "import typing_extensions\n"
+                "x: typing_extensions.Annotated[int, 0]\n",
+                (3, 8),
+            ),
+            ("from typing_extensions import Self, TypedDict\n", (3, 7)),
+            (
+                "from typing_extensions import Literal\n"
+                "from __future__ import annotations\n",
+                (3, 7),
+            ),
+            (
+                "from typing_extensions import Protocol\n"
+                "if sys.version_info >= (3, 7):\n"
+                "    pass\n",
+                (3, 6),
+            ),
+            (
+                "from __future__ import annotations\n"
+                "from typing_extensions import OrderedDict\n",
+                (3, 7),
+            ),
+        )
+    )
+    def test_detect_python_version_from_imports(
+        self, code: str, expected: Optional[Tuple[int, int]]
+    ) -> None:
+        self.assertEqual(
+            detect_python_version_from_imports(libcst.parse_module(code)), expected
+        )
+
+    @data_provider(
+        (
+            ("from __future__ import annotations\n", True),
+            ("from __future__ import division, print_function\n", True),
+            ("import os; from __future__ import annotations\n", True),
+            ("import __future__\n", False),
+            ("from .__future__ import annotations\n", False),
+            ("from __future__.x import y\n", False),
+            ("from typing import List\n", False),
+            ("x = 1\n", False),
+        )
+    )
+    def test_is_import_from_future(self, code: str, expected: bool) -> None:
+        statement = libcst.parse_module(code).body[0]
+        self.assertEqual(is_import_from_future(statement), expected)
+
+    @data_provider(
+        (
+            ("import os\n", []),
+            (
+                '"""Docstring."""\n'
+                "from __future__ import annotations\n"
+                "from __future__ import division as d, annotations\n"
+                "import os\n",
+                ["annotations", "division"],
+            ),
+            ("def f():\n    from __future__ import annotations\n", []),
+        )
+    )
+    def test_get_future_imports(self, code: str, expected: List[str]) -> None:
+        self.assertEqual(get_future_imports(libcst.parse_module(code)), expected)
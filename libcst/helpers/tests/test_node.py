@@ -0,0 +1,135 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from typing import List, Type
+
+import libcst as cst
+from libcst.helpers import (
+    ensure_type,
+    find_node_at_offset,
+    get_statements_of_type,
+    iter_nodes,
+)
+from libcst.testing.utils import UnitTest, data_provider
+
+
+class IterNodesTest(UnitTest):
+    def test_depth_first_order(self) -> None:
+        expr = cst.parse_expression("a + b")
+        self.assertEqual(
+            [type(node) for node in iter_nodes(expr)],
+            [
+                cst.BinaryOperation,
+                cst.Name,
+                cst.Add,
+                cst.SimpleWhitespace,
+                cst.SimpleWhitespace,
+                cst.Name,
+            ],
+        )
+
+    def test_filter_nodes(self) -> None:
+        module = cst.parse_module(
+            "def foo():\n    def bar(): pass\nclass Baz:\n    def qux(self): pass\n"
+        )
+        self.assertEqual(
+            [
+                node.name.value
+                for node in iter_nodes(module)
+                if isinstance(node, cst.FunctionDef)
+            ],
+            ["foo", "bar", "qux"],
+        )
+
+    def test_matches_visitor(self) -> None:
+        module = cst.parse_module("x = [1, 2]\nif x:\n    y = x[0]  # comment\n")
+        visited: List[cst.CSTNode] = []
+
+        class Collector(cst.CSTVisitor):
+            def on_visit(self, node: cst.CSTNode) -> bool:
+                visited.append(node)
+                return True
+
+        module.visit(Collector())
+        self.assertEqual(list(iter_nodes(module)), visited)
+
+
+class FindNodeAtOffsetTest(UnitTest):
+    @data_provider(
+        (
+            ("# comment", cst.Comment),
+            ("foo", cst.Name),
+            ("a,", cst.Name),
+            (", b", cst.Comma),
+            (" b)", cst.SimpleWhitespace),
+            ("+", cst.Add),
+            ("bé", cst.Name),
+            ("  # trailing", cst.SimpleWhitespace),
+            ("# trailing", cst.Comment),
+            ("\n    return", cst.Newline),
+        )
+    )
+    def test_find_node(self, needle: str, expected: Type[cst.CSTNode]) -> None:
+        module = cst.parse_module(
+            "# comment\ndef foo(a, b):\n    return a + bé  # trailing\n"
+        )
+        node = find_node_at_offset(module, module.bytes.index(needle.encode()))
+        self.assertIsInstance(node, expected)
+
+    def test_byte_offsets(self) -> None:
+        module = cst.parse_module("x = 'é'\nyy = 1\n")
+        node = find_node_at_offset(module, module.bytes.index(b"yy") + 1)
+        self.assertIsInstance(node, cst.Name)
+        self.assertEqual(ensure_type(node, cst.Name).value, "yy")
+
+    def test_identity(self) -> None:
+        module = cst.parse_module("x = 1\n")
+        assign = ensure_type(module.body[0], cst.SimpleStatementLine).body[0]
+        value = ensure_type(assign, cst.Assign).value
+        self.assertIs(find_node_at_offset(module, 4), value)
+
+    def test_out_of_range(self) -> None:
+        module = cst.parse_module("x = 1\n")
+        self.assertIsNone(find_node_at_offset(module, -1))
+        self.assertIsNone(find_node_at_offset(module, len(module.bytes)))
+
+
+class GetStatementsOfTypeTest(UnitTest):
+    CLASS: str = (
+        "class Foo:\n"
+        "    x: int\n"
+        "    y: str = ''; z = 1\n"
+        "    def foo(self) -> None:\n"
+        "        a: int = 1\n"
+        "    if TYPE_CHECKING:\n"
+        "        def bar(self) -> None: ...\n"
+        "    async def baz(self) -> None: ...\n"
+    )
+
+    def test_compound_statements(self) -> None:
+        cls = ensure_type(cst.parse_statement(self.CLASS), cst.ClassDef)
+        functions = get_statements_of_type(cls.body, cst.FunctionDef)
+        self.assertEqual([f.name.value for f in functions], ["foo", "baz"])
+        self.assertEqual(len(get_statements_of_type(cls.body, cst.If)), 1)
+
+    def test_small_statements(self) -> None:
+        cls = ensure_type(cst.parse_statement(self.CLASS), cst.ClassDef)
+        assignments = get_statements_of_type(cls.body, cst.AnnAssign)
+        self.assertEqual(
+            [ensure_type(a.target, cst.Name).value for a in assignments], ["x", "y"]
+        )
+        self.assertEqual(len(get_statements_of_type(cls.body, cst.Assign)), 1)
+        self.assertEqual(
+            len(get_statements_of_type(cls.body, cst.SimpleStatementLine)), 2
+        )
+
+    def test_simple_suite_and_module(self) -> None:
+        cls = ensure_type(
+            cst.parse_statement("class Foo: x: int; y = 1\n"), cst.ClassDef
+        )
+        self.assertEqual(len(get_statements_of_type(cls.body, cst.AnnAssign)), 1)
+        module = cst.parse_module("import a\nx = 1\nimport b\n")
+        self.assertEqual(len(get_statements_of_type(module, cst.Import)), 2)
@@ -0,0 +1,136 @@
+# Copyright (c) Facebook, Inc. and its affiliates.
+#
+# This source code is licensed under the MIT license found in the
+# LICENSE file in the root directory of this source tree.
+#
+# pyre-strict
+from textwrap import dedent
+
+import libcst as cst
+from libcst.helpers import generate_stub
+from libcst.testing.utils import UnitTest, data_provider
+
+
+class GenerateStubTest(UnitTest):
+    @data_provider(
+        (
+            # Functions
+            (
+                """
+                def foo(a: int, b: str = "x", *args: int, c=1, **kwargs) -> bool:
+                    return True
+
+                async def bar(a, /, b=None):
+                    await baz()
+                """,
+                """
+                def foo(a: int, b: str = ..., *args: int, c=..., **kwargs) -> bool: ...
+
+                async def bar(a, /, b=...): ...
+                """,
+            ),
+            # Classes
+            (
+                """
+                @dataclass
+                class Foo(Base, metaclass=Meta):
+                    \"\"\"Docstring.\"\"\"
+
+                    x: int = 1
+                    y = 2
+
+                    @property
+                    def prop(self) -> int:
+                        return self.x
+
+                    class Inner:
+                        pass
+
+                    if DEBUG:
+                        def debug(self) -> None:
+                            pass
+
+                class Empty:
+                    pass
+
+                class Short: x: int = 1; y = 2
+                """,
+                """
+                @dataclass
+                class Foo(Base, metaclass=Meta):
+
+                    x: int
+
+                    @property
+                    def prop(self) -> int: ...
+
+                    class Inner: ...
+
+                class Empty: ...
+
+                class Short: x: int
+                """,
+            ),
+            # Assignments and other top-level code
+            (
+                """
+                \"\"\"Docstring.\"\"\"
+                __all__ = ["foo"]
+                __all__ += ["bar"]
+                x = 1
+                y: List[int] = []
+                for i in range(3):
+                    print(i)
+                def foo() -> None:
+                    pass
+                """,
+                """
+                __all__ = ["foo"]
+                __all__ += ["bar"]
+                y: List[int]
+                def foo() -> None: ...
+                """,
+            ),
+            # Imports
+            (
+                """
+                from __future__ import annotations
+                import os
+                import os.path
+                import collections.abc as cabc
+                from typing import Dict, List, Optional
+                from . import reexported
+                from .models import *
+                from typing import TYPE_CHECKING
+                if TYPE_CHECKING:
+                    from .types import Foo, Bar
+                else:
+                    from .runtime import Baz
+
+                __all__ = ["reexported"]
+
+                def foo(a: "Foo", b: Optional["cabc.Mapping[str, int]"]) -> Dict:
+                    return os.path.join(a, b)
+                """,
+                """
+                import collections.abc as cabc
+                from typing import Dict, Optional
+                from . import reexported
+                from .models import *
+                from .types import Foo
+
+                __all__ = ["reexported"]
+
+                def foo(a: "Foo", b: Optional["cabc.Mapping[str, int]"]) -> Dict: ...
+                """,
+            ),
+        )
+    )
+    def test_generate_stub(self, code: str, expected: str) -> None:
+        stub = generate_stub(cst.parse_module(dedent(code)))
+        self.assertEqual(stub.code, dedent(expected))
+        self.assertEqual(cst.parse_module(stub.code).code, stub.code)
+
+    def test_header(self) -> None:
+        code = "# Copyright\n\nimport os\nx = os.sep\n"
+        self.assertEqual(generate_stub(cst.parse_module(code)).code, "# Copyright\n\n")
@@ -1883,6 +1883,12 @@ DecoratorMatchType = Union[
 EmptyLineMatchType = Union[
     "EmptyLine", MetadataMatchType, MatchIfTrue[Callable[[cst.EmptyLine], bool]]
 ]
+TypeParametersOrNoneMatchType = Union[
+    "TypeParameters",
+    None,
+    MetadataMatchType,
+    MatchIfTrue[Callable[[Union[cst.TypeParameters, None]], bool]],
+]
 
 
 @dataclass(frozen=True, eq=False, unsafe_hash=False)
@@ -2310,6 +2316,18 @@ class ClassDef(BaseCompoundStatement, BaseStatement, BaseMatcherNode):
         OneOf[SimpleWhitespaceMatchType],
         AllOf[SimpleWhitespaceMatchType],
     ] = DoNotCare()
+    type_parameters: Union[
+        TypeParametersOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[TypeParametersOrNoneMatchType],
+        AllOf[TypeParametersOrNoneMatchType],
+    ] = DoNotCare()
+    whitespace_after_type_parameters: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
     metadata: Union[
         MetadataMatchType,
         DoNotCareSentinel,
@@ -3074,13 +3092,6 @@ class Continue(BaseSmallStatement, BaseMatcherNode):
     ] = DoNotCare()
 
 
-NameOrAttributeOrCallMatchType = Union[
-    "Name",
-    "Attribute",
-    "Call",
-    MetadataMatchType,
-    MatchIfTrue[Callable[[Union[cst.Name, cst.Attribute, cst.Call]], bool]],
-]
 TrailingWhitespaceMatchType = Union[
     "TrailingWhitespace",
     MetadataMatchType,
@@ -3091,10 +3102,10 @@ TrailingWhitespaceMatchType = Union[
 @dataclass(frozen=True, eq=False, unsafe_hash=False)
 class Decorator(BaseMatcherNode):
     decorator: Union[
-        NameOrAttributeOrCallMatchType,
+        BaseExpressionMatchType,
         DoNotCareSentinel,
-        OneOf[NameOrAttributeOrCallMatchType],
-        AllOf[NameOrAttributeOrCallMatchType],
+        OneOf[BaseExpressionMatchType],
+        AllOf[BaseExpressionMatchType],
     ] = DoNotCare()
     leading_lines: Union[
         Sequence[
@@ -4314,6 +4325,132 @@ class ExceptHandler(BaseMatcherNode):
     ] = DoNotCare()
 
 
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class ExceptStarHandler(BaseMatcherNode):
+    body: Union[
+        BaseSuiteMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseSuiteMatchType],
+        AllOf[BaseSuiteMatchType],
+    ] = DoNotCare()
+    type: Union[
+        BaseExpressionMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseExpressionMatchType],
+        AllOf[BaseExpressionMatchType],
+    ] = DoNotCare()
+    name: Union[
+        AsNameOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[AsNameOrNoneMatchType],
+        AllOf[AsNameOrNoneMatchType],
+    ] = DoNotCare()
+    leading_lines: Union[
+        Sequence[
+            Union[
+                EmptyLineMatchType,
+                DoNotCareSentinel,
+                OneOf[EmptyLineMatchType],
+                AllOf[EmptyLineMatchType],
+                AtLeastN[
+                    Union[
+                        EmptyLineMatchType,
+                        DoNotCareSentinel,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        EmptyLineMatchType,
+                        DoNotCareSentinel,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        EmptyLineMatchType,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                        AtLeastN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        EmptyLineMatchType,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                        AtLeastN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    whitespace_after_except: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after_star: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_before_colon: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
 @dataclass(frozen=True, eq=False, unsafe_hash=False)
 class Expr(BaseSmallStatement, BaseMatcherNode):
     value: Union[
@@ -5811,6 +5948,18 @@ class FunctionDef(BaseCompoundStatement, BaseStatement, BaseMatcherNode):
         OneOf[SimpleWhitespaceMatchType],
         AllOf[SimpleWhitespaceMatchType],
     ] = DoNotCare()
+    type_parameters: Union[
+        TypeParametersOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[TypeParametersOrNoneMatchType],
+        AllOf[TypeParametersOrNoneMatchType],
+    ] = DoNotCare()
+    whitespace_after_type_parameters: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
     metadata: Union[
         MetadataMatchType,
         DoNotCareSentinel,
@@ -8506,133 +8655,2481 @@ class ListComp(BaseComp, BaseExpression, BaseList, BaseSimpleComp, BaseMatcherNo
     ] = DoNotCare()
 
 
-@dataclass(frozen=True, eq=False, unsafe_hash=False)
-class MatrixMultiply(BaseBinaryOp, BaseMatcherNode):
-    whitespace_before: Union[
-        BaseParenthesizableWhitespaceMatchType,
-        DoNotCareSentinel,
-        OneOf[BaseParenthesizableWhitespaceMatchType],
-        AllOf[BaseParenthesizableWhitespaceMatchType],
-    ] = DoNotCare()
-    whitespace_after: Union[
-        BaseParenthesizableWhitespaceMatchType,
-        DoNotCareSentinel,
-        OneOf[BaseParenthesizableWhitespaceMatchType],
-        AllOf[BaseParenthesizableWhitespaceMatchType],
-    ] = DoNotCare()
-    metadata: Union[
-        MetadataMatchType,
-        DoNotCareSentinel,
-        OneOf[MetadataMatchType],
-        AllOf[MetadataMatchType],
-    ] = DoNotCare()
-
-
-@dataclass(frozen=True, eq=False, unsafe_hash=False)
-class MatrixMultiplyAssign(BaseAugOp, BaseMatcherNode):
-    whitespace_before: Union[
-        BaseParenthesizableWhitespaceMatchType,
-        DoNotCareSentinel,
-        OneOf[BaseParenthesizableWhitespaceMatchType],
-        AllOf[BaseParenthesizableWhitespaceMatchType],
-    ] = DoNotCare()
-    whitespace_after: Union[
-        BaseParenthesizableWhitespaceMatchType,
-        DoNotCareSentinel,
-        OneOf[BaseParenthesizableWhitespaceMatchType],
-        AllOf[BaseParenthesizableWhitespaceMatchType],
-    ] = DoNotCare()
-    metadata: Union[
-        MetadataMatchType,
-        DoNotCareSentinel,
-        OneOf[MetadataMatchType],
-        AllOf[MetadataMatchType],
-    ] = DoNotCare()
+MatchCaseMatchType = Union[
+    "MatchCase", MetadataMatchType, MatchIfTrue[Callable[[cst.MatchCase], bool]]
+]
 
 
 @dataclass(frozen=True, eq=False, unsafe_hash=False)
-class Minus(BaseUnaryOp, BaseMatcherNode):
-    whitespace_after: Union[
-        BaseParenthesizableWhitespaceMatchType,
-        DoNotCareSentinel,
-        OneOf[BaseParenthesizableWhitespaceMatchType],
-        AllOf[BaseParenthesizableWhitespaceMatchType],
-    ] = DoNotCare()
-    metadata: Union[
-        MetadataMatchType,
+class Match(BaseCompoundStatement, BaseStatement, BaseMatcherNode):
+    subject: Union[
+        BaseExpressionMatchType,
         DoNotCareSentinel,
-        OneOf[MetadataMatchType],
-        AllOf[MetadataMatchType],
+        OneOf[BaseExpressionMatchType],
+        AllOf[BaseExpressionMatchType],
     ] = DoNotCare()
-
-
-SimpleStatementLineOrBaseCompoundStatementMatchType = Union[
-    "SimpleStatementLine",
-    "BaseCompoundStatement",
-    MetadataMatchType,
-    MatchIfTrue[
-        Callable[[Union[cst.SimpleStatementLine, cst.BaseCompoundStatement]], bool]
-    ],
-]
-
-
-@dataclass(frozen=True, eq=False, unsafe_hash=False)
-class Module(BaseMatcherNode):
-    body: Union[
+    cases: Union[
         Sequence[
             Union[
-                SimpleStatementLineOrBaseCompoundStatementMatchType,
+                MatchCaseMatchType,
                 DoNotCareSentinel,
-                OneOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
-                AllOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
+                OneOf[MatchCaseMatchType],
+                AllOf[MatchCaseMatchType],
                 AtLeastN[
                     Union[
-                        SimpleStatementLineOrBaseCompoundStatementMatchType,
+                        MatchCaseMatchType,
                         DoNotCareSentinel,
-                        OneOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
-                        AllOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
+                        OneOf[MatchCaseMatchType],
+                        AllOf[MatchCaseMatchType],
                     ]
                 ],
                 AtMostN[
                     Union[
-                        SimpleStatementLineOrBaseCompoundStatementMatchType,
+                        MatchCaseMatchType,
                         DoNotCareSentinel,
-                        OneOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
-                        AllOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
+                        OneOf[MatchCaseMatchType],
+                        AllOf[MatchCaseMatchType],
                     ]
                 ],
             ]
         ],
         DoNotCareSentinel,
-        MatchIfTrue[
-            Callable[
-                [
-                    Sequence[
-                        Union[
-                            cst.SimpleStatementLine,
-                            cst.BaseCompoundStatement,
-                            OneOf[
-                                Union[
-                                    cst.SimpleStatementLine, cst.BaseCompoundStatement
-                                ]
-                            ],
-                            AllOf[
-                                Union[
-                                    cst.SimpleStatementLine, cst.BaseCompoundStatement
-                                ]
-                            ],
-                        ]
-                    ]
-                ],
-                bool,
-            ]
-        ],
+        MatchIfTrue[Callable[[Sequence[cst.MatchCase]], bool]],
         OneOf[
             Union[
                 Sequence[
                     Union[
-                        SimpleStatementLineOrBaseCompoundStatementMatchType,
-                        OneOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
+                        MatchCaseMatchType,
+                        OneOf[MatchCaseMatchType],
+                        AllOf[MatchCaseMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchCaseMatchType,
+                                OneOf[MatchCaseMatchType],
+                                AllOf[MatchCaseMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchCaseMatchType,
+                                OneOf[MatchCaseMatchType],
+                                AllOf[MatchCaseMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.MatchCase]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchCaseMatchType,
+                        OneOf[MatchCaseMatchType],
+                        AllOf[MatchCaseMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchCaseMatchType,
+                                OneOf[MatchCaseMatchType],
+                                AllOf[MatchCaseMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchCaseMatchType,
+                                OneOf[MatchCaseMatchType],
+                                AllOf[MatchCaseMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.MatchCase]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    leading_lines: Union[
+        Sequence[
+            Union[
+                EmptyLineMatchType,
+                DoNotCareSentinel,
+                OneOf[EmptyLineMatchType],
+                AllOf[EmptyLineMatchType],
+                AtLeastN[
+                    Union[
+                        EmptyLineMatchType,
+                        DoNotCareSentinel,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        EmptyLineMatchType,
+                        DoNotCareSentinel,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        EmptyLineMatchType,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                        AtLeastN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        EmptyLineMatchType,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                        AtLeastN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    whitespace_after_match: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_before_colon: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after_colon: Union[
+        TrailingWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[TrailingWhitespaceMatchType],
+        AllOf[TrailingWhitespaceMatchType],
+    ] = DoNotCare()
+    indent: Union[
+        strOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[strOrNoneMatchType],
+        AllOf[strOrNoneMatchType],
+    ] = DoNotCare()
+    footer: Union[
+        Sequence[
+            Union[
+                EmptyLineMatchType,
+                DoNotCareSentinel,
+                OneOf[EmptyLineMatchType],
+                AllOf[EmptyLineMatchType],
+                AtLeastN[
+                    Union[
+                        EmptyLineMatchType,
+                        DoNotCareSentinel,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        EmptyLineMatchType,
+                        DoNotCareSentinel,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        EmptyLineMatchType,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                        AtLeastN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        EmptyLineMatchType,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                        AtLeastN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+MatchPatternOrNoneMatchType = Union[
+    "MatchPattern",
+    None,
+    MetadataMatchType,
+    MatchIfTrue[Callable[[Union[cst.MatchPattern, None]], bool]],
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchAs(BaseMatcherNode):
+    pattern: Union[
+        MatchPatternOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[MatchPatternOrNoneMatchType],
+        AllOf[MatchPatternOrNoneMatchType],
+    ] = DoNotCare()
+    name: Union[
+        NameOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[NameOrNoneMatchType],
+        AllOf[NameOrNoneMatchType],
+    ] = DoNotCare()
+    whitespace_before_as: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after_as: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    lpar: Union[
+        Sequence[
+            Union[
+                LeftParenMatchType,
+                DoNotCareSentinel,
+                OneOf[LeftParenMatchType],
+                AllOf[LeftParenMatchType],
+                AtLeastN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    rpar: Union[
+        Sequence[
+            Union[
+                RightParenMatchType,
+                DoNotCareSentinel,
+                OneOf[RightParenMatchType],
+                AllOf[RightParenMatchType],
+                AtLeastN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+MatchPatternMatchType = Union[
+    "MatchPattern", MetadataMatchType, MatchIfTrue[Callable[[cst.MatchPattern], bool]]
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchCase(BaseMatcherNode):
+    pattern: Union[
+        MatchPatternMatchType,
+        DoNotCareSentinel,
+        OneOf[MatchPatternMatchType],
+        AllOf[MatchPatternMatchType],
+    ] = DoNotCare()
+    body: Union[
+        BaseSuiteMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseSuiteMatchType],
+        AllOf[BaseSuiteMatchType],
+    ] = DoNotCare()
+    guard: Union[
+        BaseExpressionOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseExpressionOrNoneMatchType],
+        AllOf[BaseExpressionOrNoneMatchType],
+    ] = DoNotCare()
+    leading_lines: Union[
+        Sequence[
+            Union[
+                EmptyLineMatchType,
+                DoNotCareSentinel,
+                OneOf[EmptyLineMatchType],
+                AllOf[EmptyLineMatchType],
+                AtLeastN[
+                    Union[
+                        EmptyLineMatchType,
+                        DoNotCareSentinel,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        EmptyLineMatchType,
+                        DoNotCareSentinel,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        EmptyLineMatchType,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                        AtLeastN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        EmptyLineMatchType,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                        AtLeastN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    whitespace_after_case: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_before_if: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after_if: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_before_colon: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+MatchSequenceElementMatchType = Union[
+    "MatchSequenceElement",
+    MetadataMatchType,
+    MatchIfTrue[Callable[[cst.MatchSequenceElement], bool]],
+]
+MatchKeywordElementMatchType = Union[
+    "MatchKeywordElement",
+    MetadataMatchType,
+    MatchIfTrue[Callable[[cst.MatchKeywordElement], bool]],
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchClass(BaseMatcherNode):
+    cls: Union[
+        BaseExpressionMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseExpressionMatchType],
+        AllOf[BaseExpressionMatchType],
+    ] = DoNotCare()
+    patterns: Union[
+        Sequence[
+            Union[
+                MatchSequenceElementMatchType,
+                DoNotCareSentinel,
+                OneOf[MatchSequenceElementMatchType],
+                AllOf[MatchSequenceElementMatchType],
+                AtLeastN[
+                    Union[
+                        MatchSequenceElementMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchSequenceElementMatchType],
+                        AllOf[MatchSequenceElementMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        MatchSequenceElementMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchSequenceElementMatchType],
+                        AllOf[MatchSequenceElementMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.MatchSequenceElement]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchSequenceElementMatchType,
+                        OneOf[MatchSequenceElementMatchType],
+                        AllOf[MatchSequenceElementMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchSequenceElementMatchType,
+                                OneOf[MatchSequenceElementMatchType],
+                                AllOf[MatchSequenceElementMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchSequenceElementMatchType,
+                                OneOf[MatchSequenceElementMatchType],
+                                AllOf[MatchSequenceElementMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.MatchSequenceElement]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchSequenceElementMatchType,
+                        OneOf[MatchSequenceElementMatchType],
+                        AllOf[MatchSequenceElementMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchSequenceElementMatchType,
+                                OneOf[MatchSequenceElementMatchType],
+                                AllOf[MatchSequenceElementMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchSequenceElementMatchType,
+                                OneOf[MatchSequenceElementMatchType],
+                                AllOf[MatchSequenceElementMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.MatchSequenceElement]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    kwds: Union[
+        Sequence[
+            Union[
+                MatchKeywordElementMatchType,
+                DoNotCareSentinel,
+                OneOf[MatchKeywordElementMatchType],
+                AllOf[MatchKeywordElementMatchType],
+                AtLeastN[
+                    Union[
+                        MatchKeywordElementMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchKeywordElementMatchType],
+                        AllOf[MatchKeywordElementMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        MatchKeywordElementMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchKeywordElementMatchType],
+                        AllOf[MatchKeywordElementMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.MatchKeywordElement]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchKeywordElementMatchType,
+                        OneOf[MatchKeywordElementMatchType],
+                        AllOf[MatchKeywordElementMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchKeywordElementMatchType,
+                                OneOf[MatchKeywordElementMatchType],
+                                AllOf[MatchKeywordElementMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchKeywordElementMatchType,
+                                OneOf[MatchKeywordElementMatchType],
+                                AllOf[MatchKeywordElementMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.MatchKeywordElement]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchKeywordElementMatchType,
+                        OneOf[MatchKeywordElementMatchType],
+                        AllOf[MatchKeywordElementMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchKeywordElementMatchType,
+                                OneOf[MatchKeywordElementMatchType],
+                                AllOf[MatchKeywordElementMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchKeywordElementMatchType,
+                                OneOf[MatchKeywordElementMatchType],
+                                AllOf[MatchKeywordElementMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.MatchKeywordElement]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    whitespace_after_cls: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_before_patterns: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after_kwds: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    lpar: Union[
+        Sequence[
+            Union[
+                LeftParenMatchType,
+                DoNotCareSentinel,
+                OneOf[LeftParenMatchType],
+                AllOf[LeftParenMatchType],
+                AtLeastN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    rpar: Union[
+        Sequence[
+            Union[
+                RightParenMatchType,
+                DoNotCareSentinel,
+                OneOf[RightParenMatchType],
+                AllOf[RightParenMatchType],
+                AtLeastN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchKeywordElement(BaseMatcherNode):
+    key: Union[
+        NameMatchType, DoNotCareSentinel, OneOf[NameMatchType], AllOf[NameMatchType]
+    ] = DoNotCare()
+    pattern: Union[
+        MatchPatternMatchType,
+        DoNotCareSentinel,
+        OneOf[MatchPatternMatchType],
+        AllOf[MatchPatternMatchType],
+    ] = DoNotCare()
+    comma: Union[
+        CommaMatchType, DoNotCareSentinel, OneOf[CommaMatchType], AllOf[CommaMatchType]
+    ] = DoNotCare()
+    whitespace_before_equal: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after_equal: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+MatchSequenceElementOrMatchStarMatchType = Union[
+    "MatchSequenceElement",
+    "MatchStar",
+    MetadataMatchType,
+    MatchIfTrue[Callable[[Union[cst.MatchSequenceElement, cst.MatchStar]], bool]],
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchList(BaseMatcherNode):
+    patterns: Union[
+        Sequence[
+            Union[
+                MatchSequenceElementOrMatchStarMatchType,
+                DoNotCareSentinel,
+                OneOf[MatchSequenceElementOrMatchStarMatchType],
+                AllOf[MatchSequenceElementOrMatchStarMatchType],
+                AtLeastN[
+                    Union[
+                        MatchSequenceElementOrMatchStarMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchSequenceElementOrMatchStarMatchType],
+                        AllOf[MatchSequenceElementOrMatchStarMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        MatchSequenceElementOrMatchStarMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchSequenceElementOrMatchStarMatchType],
+                        AllOf[MatchSequenceElementOrMatchStarMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[
+            Callable[
+                [
+                    Sequence[
+                        Union[
+                            cst.MatchSequenceElement,
+                            cst.MatchStar,
+                            OneOf[Union[cst.MatchSequenceElement, cst.MatchStar]],
+                            AllOf[Union[cst.MatchSequenceElement, cst.MatchStar]],
+                        ]
+                    ]
+                ],
+                bool,
+            ]
+        ],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchSequenceElementOrMatchStarMatchType,
+                        OneOf[MatchSequenceElementOrMatchStarMatchType],
+                        AllOf[MatchSequenceElementOrMatchStarMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchSequenceElementOrMatchStarMatchType,
+                                OneOf[MatchSequenceElementOrMatchStarMatchType],
+                                AllOf[MatchSequenceElementOrMatchStarMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchSequenceElementOrMatchStarMatchType,
+                                OneOf[MatchSequenceElementOrMatchStarMatchType],
+                                AllOf[MatchSequenceElementOrMatchStarMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[
+                    Callable[
+                        [
+                            Sequence[
+                                Union[
+                                    cst.MatchSequenceElement,
+                                    cst.MatchStar,
+                                    OneOf[
+                                        Union[cst.MatchSequenceElement, cst.MatchStar]
+                                    ],
+                                    AllOf[
+                                        Union[cst.MatchSequenceElement, cst.MatchStar]
+                                    ],
+                                ]
+                            ]
+                        ],
+                        bool,
+                    ]
+                ],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchSequenceElementOrMatchStarMatchType,
+                        OneOf[MatchSequenceElementOrMatchStarMatchType],
+                        AllOf[MatchSequenceElementOrMatchStarMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchSequenceElementOrMatchStarMatchType,
+                                OneOf[MatchSequenceElementOrMatchStarMatchType],
+                                AllOf[MatchSequenceElementOrMatchStarMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchSequenceElementOrMatchStarMatchType,
+                                OneOf[MatchSequenceElementOrMatchStarMatchType],
+                                AllOf[MatchSequenceElementOrMatchStarMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[
+                    Callable[
+                        [
+                            Sequence[
+                                Union[
+                                    cst.MatchSequenceElement,
+                                    cst.MatchStar,
+                                    OneOf[
+                                        Union[cst.MatchSequenceElement, cst.MatchStar]
+                                    ],
+                                    AllOf[
+                                        Union[cst.MatchSequenceElement, cst.MatchStar]
+                                    ],
+                                ]
+                            ]
+                        ],
+                        bool,
+                    ]
+                ],
+            ]
+        ],
+    ] = DoNotCare()
+    lbracket: Union[
+        LeftSquareBracketMatchType,
+        DoNotCareSentinel,
+        OneOf[LeftSquareBracketMatchType],
+        AllOf[LeftSquareBracketMatchType],
+    ] = DoNotCare()
+    rbracket: Union[
+        RightSquareBracketMatchType,
+        DoNotCareSentinel,
+        OneOf[RightSquareBracketMatchType],
+        AllOf[RightSquareBracketMatchType],
+    ] = DoNotCare()
+    lpar: Union[
+        Sequence[
+            Union[
+                LeftParenMatchType,
+                DoNotCareSentinel,
+                OneOf[LeftParenMatchType],
+                AllOf[LeftParenMatchType],
+                AtLeastN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    rpar: Union[
+        Sequence[
+            Union[
+                RightParenMatchType,
+                DoNotCareSentinel,
+                OneOf[RightParenMatchType],
+                AllOf[RightParenMatchType],
+                AtLeastN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+MatchMappingElementMatchType = Union[
+    "MatchMappingElement",
+    MetadataMatchType,
+    MatchIfTrue[Callable[[cst.MatchMappingElement], bool]],
+]
+CommaOrNoneMatchType = Union[
+    "Comma",
+    None,
+    MetadataMatchType,
+    MatchIfTrue[Callable[[Union[cst.Comma, None]], bool]],
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchMapping(BaseMatcherNode):
+    elements: Union[
+        Sequence[
+            Union[
+                MatchMappingElementMatchType,
+                DoNotCareSentinel,
+                OneOf[MatchMappingElementMatchType],
+                AllOf[MatchMappingElementMatchType],
+                AtLeastN[
+                    Union[
+                        MatchMappingElementMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchMappingElementMatchType],
+                        AllOf[MatchMappingElementMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        MatchMappingElementMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchMappingElementMatchType],
+                        AllOf[MatchMappingElementMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.MatchMappingElement]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchMappingElementMatchType,
+                        OneOf[MatchMappingElementMatchType],
+                        AllOf[MatchMappingElementMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchMappingElementMatchType,
+                                OneOf[MatchMappingElementMatchType],
+                                AllOf[MatchMappingElementMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchMappingElementMatchType,
+                                OneOf[MatchMappingElementMatchType],
+                                AllOf[MatchMappingElementMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.MatchMappingElement]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchMappingElementMatchType,
+                        OneOf[MatchMappingElementMatchType],
+                        AllOf[MatchMappingElementMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchMappingElementMatchType,
+                                OneOf[MatchMappingElementMatchType],
+                                AllOf[MatchMappingElementMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchMappingElementMatchType,
+                                OneOf[MatchMappingElementMatchType],
+                                AllOf[MatchMappingElementMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.MatchMappingElement]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    lbrace: Union[
+        LeftCurlyBraceMatchType,
+        DoNotCareSentinel,
+        OneOf[LeftCurlyBraceMatchType],
+        AllOf[LeftCurlyBraceMatchType],
+    ] = DoNotCare()
+    rbrace: Union[
+        RightCurlyBraceMatchType,
+        DoNotCareSentinel,
+        OneOf[RightCurlyBraceMatchType],
+        AllOf[RightCurlyBraceMatchType],
+    ] = DoNotCare()
+    rest: Union[
+        NameOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[NameOrNoneMatchType],
+        AllOf[NameOrNoneMatchType],
+    ] = DoNotCare()
+    whitespace_before_rest: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    trailing_comma: Union[
+        CommaOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[CommaOrNoneMatchType],
+        AllOf[CommaOrNoneMatchType],
+    ] = DoNotCare()
+    lpar: Union[
+        Sequence[
+            Union[
+                LeftParenMatchType,
+                DoNotCareSentinel,
+                OneOf[LeftParenMatchType],
+                AllOf[LeftParenMatchType],
+                AtLeastN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    rpar: Union[
+        Sequence[
+            Union[
+                RightParenMatchType,
+                DoNotCareSentinel,
+                OneOf[RightParenMatchType],
+                AllOf[RightParenMatchType],
+                AtLeastN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchMappingElement(BaseMatcherNode):
+    key: Union[
+        BaseExpressionMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseExpressionMatchType],
+        AllOf[BaseExpressionMatchType],
+    ] = DoNotCare()
+    pattern: Union[
+        MatchPatternMatchType,
+        DoNotCareSentinel,
+        OneOf[MatchPatternMatchType],
+        AllOf[MatchPatternMatchType],
+    ] = DoNotCare()
+    comma: Union[
+        CommaMatchType, DoNotCareSentinel, OneOf[CommaMatchType], AllOf[CommaMatchType]
+    ] = DoNotCare()
+    whitespace_before_colon: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after_colon: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+MatchOrElementMatchType = Union[
+    "MatchOrElement",
+    MetadataMatchType,
+    MatchIfTrue[Callable[[cst.MatchOrElement], bool]],
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchOr(BaseMatcherNode):
+    patterns: Union[
+        Sequence[
+            Union[
+                MatchOrElementMatchType,
+                DoNotCareSentinel,
+                OneOf[MatchOrElementMatchType],
+                AllOf[MatchOrElementMatchType],
+                AtLeastN[
+                    Union[
+                        MatchOrElementMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchOrElementMatchType],
+                        AllOf[MatchOrElementMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        MatchOrElementMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchOrElementMatchType],
+                        AllOf[MatchOrElementMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.MatchOrElement]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchOrElementMatchType,
+                        OneOf[MatchOrElementMatchType],
+                        AllOf[MatchOrElementMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchOrElementMatchType,
+                                OneOf[MatchOrElementMatchType],
+                                AllOf[MatchOrElementMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchOrElementMatchType,
+                                OneOf[MatchOrElementMatchType],
+                                AllOf[MatchOrElementMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.MatchOrElement]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchOrElementMatchType,
+                        OneOf[MatchOrElementMatchType],
+                        AllOf[MatchOrElementMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchOrElementMatchType,
+                                OneOf[MatchOrElementMatchType],
+                                AllOf[MatchOrElementMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchOrElementMatchType,
+                                OneOf[MatchOrElementMatchType],
+                                AllOf[MatchOrElementMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.MatchOrElement]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    lpar: Union[
+        Sequence[
+            Union[
+                LeftParenMatchType,
+                DoNotCareSentinel,
+                OneOf[LeftParenMatchType],
+                AllOf[LeftParenMatchType],
+                AtLeastN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    rpar: Union[
+        Sequence[
+            Union[
+                RightParenMatchType,
+                DoNotCareSentinel,
+                OneOf[RightParenMatchType],
+                AllOf[RightParenMatchType],
+                AtLeastN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+BitOrMatchType = Union[
+    "BitOr", MetadataMatchType, MatchIfTrue[Callable[[cst.BitOr], bool]]
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchOrElement(BaseMatcherNode):
+    pattern: Union[
+        MatchPatternMatchType,
+        DoNotCareSentinel,
+        OneOf[MatchPatternMatchType],
+        AllOf[MatchPatternMatchType],
+    ] = DoNotCare()
+    separator: Union[
+        BitOrMatchType, DoNotCareSentinel, OneOf[BitOrMatchType], AllOf[BitOrMatchType]
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchPattern(BaseMatcherNode):
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchSequence(BaseMatcherNode):
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchSequenceElement(BaseMatcherNode):
+    value: Union[
+        MatchPatternMatchType,
+        DoNotCareSentinel,
+        OneOf[MatchPatternMatchType],
+        AllOf[MatchPatternMatchType],
+    ] = DoNotCare()
+    comma: Union[
+        CommaMatchType, DoNotCareSentinel, OneOf[CommaMatchType], AllOf[CommaMatchType]
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchSingleton(BaseMatcherNode):
+    value: Union[
+        NameMatchType, DoNotCareSentinel, OneOf[NameMatchType], AllOf[NameMatchType]
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchStar(BaseMatcherNode):
+    name: Union[
+        NameOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[NameOrNoneMatchType],
+        AllOf[NameOrNoneMatchType],
+    ] = DoNotCare()
+    comma: Union[
+        CommaMatchType, DoNotCareSentinel, OneOf[CommaMatchType], AllOf[CommaMatchType]
+    ] = DoNotCare()
+    whitespace_before_name: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchTuple(BaseMatcherNode):
+    patterns: Union[
+        Sequence[
+            Union[
+                MatchSequenceElementOrMatchStarMatchType,
+                DoNotCareSentinel,
+                OneOf[MatchSequenceElementOrMatchStarMatchType],
+                AllOf[MatchSequenceElementOrMatchStarMatchType],
+                AtLeastN[
+                    Union[
+                        MatchSequenceElementOrMatchStarMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchSequenceElementOrMatchStarMatchType],
+                        AllOf[MatchSequenceElementOrMatchStarMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        MatchSequenceElementOrMatchStarMatchType,
+                        DoNotCareSentinel,
+                        OneOf[MatchSequenceElementOrMatchStarMatchType],
+                        AllOf[MatchSequenceElementOrMatchStarMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[
+            Callable[
+                [
+                    Sequence[
+                        Union[
+                            cst.MatchSequenceElement,
+                            cst.MatchStar,
+                            OneOf[Union[cst.MatchSequenceElement, cst.MatchStar]],
+                            AllOf[Union[cst.MatchSequenceElement, cst.MatchStar]],
+                        ]
+                    ]
+                ],
+                bool,
+            ]
+        ],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchSequenceElementOrMatchStarMatchType,
+                        OneOf[MatchSequenceElementOrMatchStarMatchType],
+                        AllOf[MatchSequenceElementOrMatchStarMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchSequenceElementOrMatchStarMatchType,
+                                OneOf[MatchSequenceElementOrMatchStarMatchType],
+                                AllOf[MatchSequenceElementOrMatchStarMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchSequenceElementOrMatchStarMatchType,
+                                OneOf[MatchSequenceElementOrMatchStarMatchType],
+                                AllOf[MatchSequenceElementOrMatchStarMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[
+                    Callable[
+                        [
+                            Sequence[
+                                Union[
+                                    cst.MatchSequenceElement,
+                                    cst.MatchStar,
+                                    OneOf[
+                                        Union[cst.MatchSequenceElement, cst.MatchStar]
+                                    ],
+                                    AllOf[
+                                        Union[cst.MatchSequenceElement, cst.MatchStar]
+                                    ],
+                                ]
+                            ]
+                        ],
+                        bool,
+                    ]
+                ],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        MatchSequenceElementOrMatchStarMatchType,
+                        OneOf[MatchSequenceElementOrMatchStarMatchType],
+                        AllOf[MatchSequenceElementOrMatchStarMatchType],
+                        AtLeastN[
+                            Union[
+                                MatchSequenceElementOrMatchStarMatchType,
+                                OneOf[MatchSequenceElementOrMatchStarMatchType],
+                                AllOf[MatchSequenceElementOrMatchStarMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                MatchSequenceElementOrMatchStarMatchType,
+                                OneOf[MatchSequenceElementOrMatchStarMatchType],
+                                AllOf[MatchSequenceElementOrMatchStarMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[
+                    Callable[
+                        [
+                            Sequence[
+                                Union[
+                                    cst.MatchSequenceElement,
+                                    cst.MatchStar,
+                                    OneOf[
+                                        Union[cst.MatchSequenceElement, cst.MatchStar]
+                                    ],
+                                    AllOf[
+                                        Union[cst.MatchSequenceElement, cst.MatchStar]
+                                    ],
+                                ]
+                            ]
+                        ],
+                        bool,
+                    ]
+                ],
+            ]
+        ],
+    ] = DoNotCare()
+    lpar: Union[
+        Sequence[
+            Union[
+                LeftParenMatchType,
+                DoNotCareSentinel,
+                OneOf[LeftParenMatchType],
+                AllOf[LeftParenMatchType],
+                AtLeastN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        LeftParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        LeftParenMatchType,
+                        OneOf[LeftParenMatchType],
+                        AllOf[LeftParenMatchType],
+                        AtLeastN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                LeftParenMatchType,
+                                OneOf[LeftParenMatchType],
+                                AllOf[LeftParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.LeftParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    rpar: Union[
+        Sequence[
+            Union[
+                RightParenMatchType,
+                DoNotCareSentinel,
+                OneOf[RightParenMatchType],
+                AllOf[RightParenMatchType],
+                AtLeastN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        RightParenMatchType,
+                        DoNotCareSentinel,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        RightParenMatchType,
+                        OneOf[RightParenMatchType],
+                        AllOf[RightParenMatchType],
+                        AtLeastN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                RightParenMatchType,
+                                OneOf[RightParenMatchType],
+                                AllOf[RightParenMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.RightParen]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatchValue(BaseMatcherNode):
+    value: Union[
+        BaseExpressionMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseExpressionMatchType],
+        AllOf[BaseExpressionMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatrixMultiply(BaseBinaryOp, BaseMatcherNode):
+    whitespace_before: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class MatrixMultiplyAssign(BaseAugOp, BaseMatcherNode):
+    whitespace_before: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class Minus(BaseUnaryOp, BaseMatcherNode):
+    whitespace_after: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+SimpleStatementLineOrBaseCompoundStatementMatchType = Union[
+    "SimpleStatementLine",
+    "BaseCompoundStatement",
+    MetadataMatchType,
+    MatchIfTrue[
+        Callable[[Union[cst.SimpleStatementLine, cst.BaseCompoundStatement]], bool]
+    ],
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class Module(BaseMatcherNode):
+    body: Union[
+        Sequence[
+            Union[
+                SimpleStatementLineOrBaseCompoundStatementMatchType,
+                DoNotCareSentinel,
+                OneOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
+                AllOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
+                AtLeastN[
+                    Union[
+                        SimpleStatementLineOrBaseCompoundStatementMatchType,
+                        DoNotCareSentinel,
+                        OneOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
+                        AllOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        SimpleStatementLineOrBaseCompoundStatementMatchType,
+                        DoNotCareSentinel,
+                        OneOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
+                        AllOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[
+            Callable[
+                [
+                    Sequence[
+                        Union[
+                            cst.SimpleStatementLine,
+                            cst.BaseCompoundStatement,
+                            OneOf[
+                                Union[
+                                    cst.SimpleStatementLine, cst.BaseCompoundStatement
+                                ]
+                            ],
+                            AllOf[
+                                Union[
+                                    cst.SimpleStatementLine, cst.BaseCompoundStatement
+                                ]
+                            ],
+                        ]
+                    ]
+                ],
+                bool,
+            ]
+        ],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        SimpleStatementLineOrBaseCompoundStatementMatchType,
+                        OneOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
                         AllOf[SimpleStatementLineOrBaseCompoundStatementMatchType],
                         AtLeastN[
                             Union[
@@ -9668,6 +12165,25 @@ class ParamSlash(BaseMatcherNode):
     ] = DoNotCare()
 
 
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class ParamSpec(BaseMatcherNode):
+    name: Union[
+        NameMatchType, DoNotCareSentinel, OneOf[NameMatchType], AllOf[NameMatchType]
+    ] = DoNotCare()
+    whitespace_after_star: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
 @dataclass(frozen=True, eq=False, unsafe_hash=False)
 class ParamStar(BaseMatcherNode):
     comma: Union[
@@ -11854,28 +14370,233 @@ class SubtractAssign(BaseAugOp, BaseMatcherNode):
         OneOf[MetadataMatchType],
         AllOf[MetadataMatchType],
     ] = DoNotCare()
-
-
-@dataclass(frozen=True, eq=False, unsafe_hash=False)
-class TrailingWhitespace(BaseMatcherNode):
-    whitespace: Union[
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class TrailingWhitespace(BaseMatcherNode):
+    whitespace: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    comment: Union[
+        CommentOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[CommentOrNoneMatchType],
+        AllOf[CommentOrNoneMatchType],
+    ] = DoNotCare()
+    newline: Union[
+        NewlineMatchType,
+        DoNotCareSentinel,
+        OneOf[NewlineMatchType],
+        AllOf[NewlineMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+ExceptHandlerMatchType = Union[
+    "ExceptHandler", MetadataMatchType, MatchIfTrue[Callable[[cst.ExceptHandler], bool]]
+]
+FinallyOrNoneMatchType = Union[
+    "Finally",
+    None,
+    MetadataMatchType,
+    MatchIfTrue[Callable[[Union[cst.Finally, None]], bool]],
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class Try(BaseCompoundStatement, BaseStatement, BaseMatcherNode):
+    body: Union[
+        BaseSuiteMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseSuiteMatchType],
+        AllOf[BaseSuiteMatchType],
+    ] = DoNotCare()
+    handlers: Union[
+        Sequence[
+            Union[
+                ExceptHandlerMatchType,
+                DoNotCareSentinel,
+                OneOf[ExceptHandlerMatchType],
+                AllOf[ExceptHandlerMatchType],
+                AtLeastN[
+                    Union[
+                        ExceptHandlerMatchType,
+                        DoNotCareSentinel,
+                        OneOf[ExceptHandlerMatchType],
+                        AllOf[ExceptHandlerMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        ExceptHandlerMatchType,
+                        DoNotCareSentinel,
+                        OneOf[ExceptHandlerMatchType],
+                        AllOf[ExceptHandlerMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.ExceptHandler]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        ExceptHandlerMatchType,
+                        OneOf[ExceptHandlerMatchType],
+                        AllOf[ExceptHandlerMatchType],
+                        AtLeastN[
+                            Union[
+                                ExceptHandlerMatchType,
+                                OneOf[ExceptHandlerMatchType],
+                                AllOf[ExceptHandlerMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                ExceptHandlerMatchType,
+                                OneOf[ExceptHandlerMatchType],
+                                AllOf[ExceptHandlerMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.ExceptHandler]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        ExceptHandlerMatchType,
+                        OneOf[ExceptHandlerMatchType],
+                        AllOf[ExceptHandlerMatchType],
+                        AtLeastN[
+                            Union[
+                                ExceptHandlerMatchType,
+                                OneOf[ExceptHandlerMatchType],
+                                AllOf[ExceptHandlerMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                ExceptHandlerMatchType,
+                                OneOf[ExceptHandlerMatchType],
+                                AllOf[ExceptHandlerMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.ExceptHandler]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    orelse: Union[
+        ElseOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[ElseOrNoneMatchType],
+        AllOf[ElseOrNoneMatchType],
+    ] = DoNotCare()
+    finalbody: Union[
+        FinallyOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[FinallyOrNoneMatchType],
+        AllOf[FinallyOrNoneMatchType],
+    ] = DoNotCare()
+    leading_lines: Union[
+        Sequence[
+            Union[
+                EmptyLineMatchType,
+                DoNotCareSentinel,
+                OneOf[EmptyLineMatchType],
+                AllOf[EmptyLineMatchType],
+                AtLeastN[
+                    Union[
+                        EmptyLineMatchType,
+                        DoNotCareSentinel,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        EmptyLineMatchType,
+                        DoNotCareSentinel,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        EmptyLineMatchType,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                        AtLeastN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        EmptyLineMatchType,
+                        OneOf[EmptyLineMatchType],
+                        AllOf[EmptyLineMatchType],
+                        AtLeastN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                EmptyLineMatchType,
+                                OneOf[EmptyLineMatchType],
+                                AllOf[EmptyLineMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.EmptyLine]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    whitespace_before_colon: Union[
         SimpleWhitespaceMatchType,
         DoNotCareSentinel,
         OneOf[SimpleWhitespaceMatchType],
         AllOf[SimpleWhitespaceMatchType],
     ] = DoNotCare()
-    comment: Union[
-        CommentOrNoneMatchType,
-        DoNotCareSentinel,
-        OneOf[CommentOrNoneMatchType],
-        AllOf[CommentOrNoneMatchType],
-    ] = DoNotCare()
-    newline: Union[
-        NewlineMatchType,
-        DoNotCareSentinel,
-        OneOf[NewlineMatchType],
-        AllOf[NewlineMatchType],
-    ] = DoNotCare()
     metadata: Union[
         MetadataMatchType,
         DoNotCareSentinel,
@@ -11884,19 +14605,15 @@ class TrailingWhitespace(BaseMatcherNode):
     ] = DoNotCare()
 
 
-ExceptHandlerMatchType = Union[
-    "ExceptHandler", MetadataMatchType, MatchIfTrue[Callable[[cst.ExceptHandler], bool]]
-]
-FinallyOrNoneMatchType = Union[
-    "Finally",
-    None,
+ExceptStarHandlerMatchType = Union[
+    "ExceptStarHandler",
     MetadataMatchType,
-    MatchIfTrue[Callable[[Union[cst.Finally, None]], bool]],
+    MatchIfTrue[Callable[[cst.ExceptStarHandler], bool]],
 ]
 
 
 @dataclass(frozen=True, eq=False, unsafe_hash=False)
-class Try(BaseCompoundStatement, BaseStatement, BaseMatcherNode):
+class TryStar(BaseCompoundStatement, BaseStatement, BaseMatcherNode):
     body: Union[
         BaseSuiteMatchType,
         DoNotCareSentinel,
@@ -11906,80 +14623,80 @@ class Try(BaseCompoundStatement, BaseStatement, BaseMatcherNode):
     handlers: Union[
         Sequence[
             Union[
-                ExceptHandlerMatchType,
+                ExceptStarHandlerMatchType,
                 DoNotCareSentinel,
-                OneOf[ExceptHandlerMatchType],
-                AllOf[ExceptHandlerMatchType],
+                OneOf[ExceptStarHandlerMatchType],
+                AllOf[ExceptStarHandlerMatchType],
                 AtLeastN[
                     Union[
-                        ExceptHandlerMatchType,
+                        ExceptStarHandlerMatchType,
                         DoNotCareSentinel,
-                        OneOf[ExceptHandlerMatchType],
-                        AllOf[ExceptHandlerMatchType],
+                        OneOf[ExceptStarHandlerMatchType],
+                        AllOf[ExceptStarHandlerMatchType],
                     ]
                 ],
                 AtMostN[
                     Union[
-                        ExceptHandlerMatchType,
+                        ExceptStarHandlerMatchType,
                         DoNotCareSentinel,
-                        OneOf[ExceptHandlerMatchType],
-                        AllOf[ExceptHandlerMatchType],
+                        OneOf[ExceptStarHandlerMatchType],
+                        AllOf[ExceptStarHandlerMatchType],
                     ]
                 ],
             ]
         ],
         DoNotCareSentinel,
-        MatchIfTrue[Callable[[Sequence[cst.ExceptHandler]], bool]],
+        MatchIfTrue[Callable[[Sequence[cst.ExceptStarHandler]], bool]],
         OneOf[
             Union[
                 Sequence[
                     Union[
-                        ExceptHandlerMatchType,
-                        OneOf[ExceptHandlerMatchType],
-                        AllOf[ExceptHandlerMatchType],
+                        ExceptStarHandlerMatchType,
+                        OneOf[ExceptStarHandlerMatchType],
+                        AllOf[ExceptStarHandlerMatchType],
                         AtLeastN[
                             Union[
-                                ExceptHandlerMatchType,
-                                OneOf[ExceptHandlerMatchType],
-                                AllOf[ExceptHandlerMatchType],
+                                ExceptStarHandlerMatchType,
+                                OneOf[ExceptStarHandlerMatchType],
+                                AllOf[ExceptStarHandlerMatchType],
                             ]
                         ],
                         AtMostN[
                             Union[
-                                ExceptHandlerMatchType,
-                                OneOf[ExceptHandlerMatchType],
-                                AllOf[ExceptHandlerMatchType],
+                                ExceptStarHandlerMatchType,
+                                OneOf[ExceptStarHandlerMatchType],
+                                AllOf[ExceptStarHandlerMatchType],
                             ]
                         ],
                     ]
                 ],
-                MatchIfTrue[Callable[[Sequence[cst.ExceptHandler]], bool]],
+                MatchIfTrue[Callable[[Sequence[cst.ExceptStarHandler]], bool]],
             ]
         ],
         AllOf[
             Union[
                 Sequence[
                     Union[
-                        ExceptHandlerMatchType,
-                        OneOf[ExceptHandlerMatchType],
-                        AllOf[ExceptHandlerMatchType],
+                        ExceptStarHandlerMatchType,
+                        OneOf[ExceptStarHandlerMatchType],
+                        AllOf[ExceptStarHandlerMatchType],
                         AtLeastN[
                             Union[
-                                ExceptHandlerMatchType,
-                                OneOf[ExceptHandlerMatchType],
-                                AllOf[ExceptHandlerMatchType],
+                                ExceptStarHandlerMatchType,
+                                OneOf[ExceptStarHandlerMatchType],
+                                AllOf[ExceptStarHandlerMatchType],
                             ]
                         ],
                         AtMostN[
                             Union[
-                                ExceptHandlerMatchType,
-                                OneOf[ExceptHandlerMatchType],
-                                AllOf[ExceptHandlerMatchType],
+                                ExceptStarHandlerMatchType,
+                                OneOf[ExceptStarHandlerMatchType],
+                                AllOf[ExceptStarHandlerMatchType],
                             ]
                         ],
                     ]
                 ],
-                MatchIfTrue[Callable[[Sequence[cst.ExceptHandler]], bool]],
+                MatchIfTrue[Callable[[Sequence[cst.ExceptStarHandler]], bool]],
             ]
         ],
     ] = DoNotCare()
@@ -12341,6 +15058,237 @@ class Tuple(
     ] = DoNotCare()
 
 
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class TypeAlias(BaseSmallStatement, BaseMatcherNode):
+    name: Union[
+        NameMatchType, DoNotCareSentinel, OneOf[NameMatchType], AllOf[NameMatchType]
+    ] = DoNotCare()
+    value: Union[
+        BaseExpressionMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseExpressionMatchType],
+        AllOf[BaseExpressionMatchType],
+    ] = DoNotCare()
+    type_parameters: Union[
+        TypeParametersOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[TypeParametersOrNoneMatchType],
+        AllOf[TypeParametersOrNoneMatchType],
+    ] = DoNotCare()
+    whitespace_after_type: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after_name: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after_type_parameters: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    whitespace_after_equals: Union[
+        SimpleWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[SimpleWhitespaceMatchType],
+        AllOf[SimpleWhitespaceMatchType],
+    ] = DoNotCare()
+    semicolon: Union[
+        SemicolonMatchType,
+        DoNotCareSentinel,
+        OneOf[SemicolonMatchType],
+        AllOf[SemicolonMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+TypeVarOrTypeVarTupleOrParamSpecMatchType = Union[
+    "TypeVar",
+    "TypeVarTuple",
+    "ParamSpec",
+    MetadataMatchType,
+    MatchIfTrue[Callable[[Union[cst.TypeVar, cst.TypeVarTuple, cst.ParamSpec]], bool]],
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class TypeParam(BaseMatcherNode):
+    param: Union[
+        TypeVarOrTypeVarTupleOrParamSpecMatchType,
+        DoNotCareSentinel,
+        OneOf[TypeVarOrTypeVarTupleOrParamSpecMatchType],
+        AllOf[TypeVarOrTypeVarTupleOrParamSpecMatchType],
+    ] = DoNotCare()
+    comma: Union[
+        CommaMatchType, DoNotCareSentinel, OneOf[CommaMatchType], AllOf[CommaMatchType]
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+TypeParamMatchType = Union[
+    "TypeParam", MetadataMatchType, MatchIfTrue[Callable[[cst.TypeParam], bool]]
+]
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class TypeParameters(BaseMatcherNode):
+    params: Union[
+        Sequence[
+            Union[
+                TypeParamMatchType,
+                DoNotCareSentinel,
+                OneOf[TypeParamMatchType],
+                AllOf[TypeParamMatchType],
+                AtLeastN[
+                    Union[
+                        TypeParamMatchType,
+                        DoNotCareSentinel,
+                        OneOf[TypeParamMatchType],
+                        AllOf[TypeParamMatchType],
+                    ]
+                ],
+                AtMostN[
+                    Union[
+                        TypeParamMatchType,
+                        DoNotCareSentinel,
+                        OneOf[TypeParamMatchType],
+                        AllOf[TypeParamMatchType],
+                    ]
+                ],
+            ]
+        ],
+        DoNotCareSentinel,
+        MatchIfTrue[Callable[[Sequence[cst.TypeParam]], bool]],
+        OneOf[
+            Union[
+                Sequence[
+                    Union[
+                        TypeParamMatchType,
+                        OneOf[TypeParamMatchType],
+                        AllOf[TypeParamMatchType],
+                        AtLeastN[
+                            Union[
+                                TypeParamMatchType,
+                                OneOf[TypeParamMatchType],
+                                AllOf[TypeParamMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                TypeParamMatchType,
+                                OneOf[TypeParamMatchType],
+                                AllOf[TypeParamMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.TypeParam]], bool]],
+            ]
+        ],
+        AllOf[
+            Union[
+                Sequence[
+                    Union[
+                        TypeParamMatchType,
+                        OneOf[TypeParamMatchType],
+                        AllOf[TypeParamMatchType],
+                        AtLeastN[
+                            Union[
+                                TypeParamMatchType,
+                                OneOf[TypeParamMatchType],
+                                AllOf[TypeParamMatchType],
+                            ]
+                        ],
+                        AtMostN[
+                            Union[
+                                TypeParamMatchType,
+                                OneOf[TypeParamMatchType],
+                                AllOf[TypeParamMatchType],
+                            ]
+                        ],
+                    ]
+                ],
+                MatchIfTrue[Callable[[Sequence[cst.TypeParam]], bool]],
+            ]
+        ],
+    ] = DoNotCare()
+    lbracket: Union[
+        LeftSquareBracketMatchType,
+        DoNotCareSentinel,
+        OneOf[LeftSquareBracketMatchType],
+        AllOf[LeftSquareBracketMatchType],
+    ] = DoNotCare()
+    rbracket: Union[
+        RightSquareBracketMatchType,
+        DoNotCareSentinel,
+        OneOf[RightSquareBracketMatchType],
+        AllOf[RightSquareBracketMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class TypeVar(BaseMatcherNode):
+    name: Union[
+        NameMatchType, DoNotCareSentinel, OneOf[NameMatchType], AllOf[NameMatchType]
+    ] = DoNotCare()
+    bound: Union[
+        BaseExpressionOrNoneMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseExpressionOrNoneMatchType],
+        AllOf[BaseExpressionOrNoneMatchType],
+    ] = DoNotCare()
+    colon: Union[
+        ColonMatchType, DoNotCareSentinel, OneOf[ColonMatchType], AllOf[ColonMatchType]
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
+@dataclass(frozen=True, eq=False, unsafe_hash=False)
+class TypeVarTuple(BaseMatcherNode):
+    name: Union[
+        NameMatchType, DoNotCareSentinel, OneOf[NameMatchType], AllOf[NameMatchType]
+    ] = DoNotCare()
+    whitespace_after_star: Union[
+        BaseParenthesizableWhitespaceMatchType,
+        DoNotCareSentinel,
+        OneOf[BaseParenthesizableWhitespaceMatchType],
+        AllOf[BaseParenthesizableWhitespaceMatchType],
+    ] = DoNotCare()
+    metadata: Union[
+        MetadataMatchType,
+        DoNotCareSentinel,
+        OneOf[MetadataMatchType],
+        AllOf[MetadataMatchType],
+    ] = DoNotCare()
+
+
 BaseUnaryOpMatchType = Union[
     "BaseUnaryOp", MetadataMatchType, MatchIfTrue[Callable[[cst.BaseUnaryOp], bool]]
 ]
@@ -13146,6 +16094,7 @@ __all__ = [
     "EmptyLine",
     "Equal",
     "ExceptHandler",
+    "ExceptStarHandler",
     "Expr",
     "Finally",
     "Float",
@@ -13184,11 +16133,28 @@ __all__ = [
     "LessThanEqual",
     "List",
     "ListComp",
+    "Match",
+    "MatchAs",
+    "MatchCase",
+    "MatchClass",
     "MatchDecoratorMismatch",
     "MatchIfTrue",
+    "MatchKeywordElement",
+    "MatchList",
+    "MatchMapping",
+    "MatchMappingElement",
     "MatchMetadata",
     "MatchMetadataIfTrue",
+    "MatchOr",
+    "MatchOrElement",
+    "MatchPattern",
     "MatchRegex",
+    "MatchSequence",
+    "MatchSequenceElement",
+    "MatchSingleton",
+    "MatchStar",
+    "MatchTuple",
+    "MatchValue",
     "MatcherDecoratableTransformer",
     "MatcherDecoratableVisitor",
     "MatrixMultiply",
@@ -13211,6 +16177,7 @@ __all__ = [
     "Or",
     "Param",
     "ParamSlash",
+    "ParamSpec",
     "ParamStar",
     "Parameters",
     "ParenthesizedWhitespace",
@@ -13242,7 +16209,13 @@ __all__ = [
     "SubtractAssign",
     "TrailingWhitespace",
     "Try",
+    "TryStar",
     "Tuple",
+    "TypeAlias",
+    "TypeParam",
+    "TypeParameters",
+    "TypeVar",
+    "TypeVarTuple",
     "UnaryOperation",
     "While",
     "With",
@@ -144,6 +144,7 @@ from libcst._nodes.statement import (
     Del,
     Else,
     ExceptHandler,
+    ExceptStarHandler,
     Expr,
     Finally,
     For,
@@ -154,14 +155,38 @@ from libcst._nodes.statement import (
     ImportAlias,
     ImportFrom,
     IndentedBlock,
+    Match,
+    MatchAs,
+    MatchCase,
+    MatchClass,
+    MatchKeywordElement,
+    MatchList,
+    MatchMapping,
+    MatchMappingElement,
+    MatchOr,
+    MatchOrElement,
+    MatchPattern,
+    MatchSequence,
+    MatchSequenceElement,
+    MatchSingleton,
+    MatchStar,
+    MatchTuple,
+    MatchValue,
     NameItem,
     Nonlocal,
+    ParamSpec,
     Pass,
     Raise,
     Return,
     SimpleStatementLine,
     SimpleStatementSuite,
     Try,
+    TryStar,
+    TypeAlias,
+    TypeParam,
+    TypeParameters,
+    TypeVar,
+    TypeVarTuple,
     While,
     With,
     WithItem,
@@ -198,7 +223,7 @@ TYPED_FUNCTION_RETURN_MAPPING: TypingDict[Type[CSTNode], object] = {
     BitAnd: BaseBinaryOp,
     BitAndAssign: BaseAugOp,
     BitInvert: BaseUnaryOp,
-    BitOr: BaseBinaryOp,
+    BitOr: Union[BaseBinaryOp, MaybeSentinel],
     BitOrAssign: BaseAugOp,
     BitXor: BaseBinaryOp,
     BitXorAssign: BaseAugOp,
@@ -229,6 +254,7 @@ TYPED_FUNCTION_RETURN_MAPPING: TypingDict[Type[CSTNode], object] = {
     EmptyLine: Union[EmptyLine, RemovalSentinel],
     Equal: BaseCompOp,
     ExceptHandler: Union[ExceptHandler, RemovalSentinel],
+    ExceptStarHandler: Union[ExceptStarHandler, RemovalSentinel],
     Expr: Union[BaseSmallStatement, RemovalSentinel],
     Finally: Finally,
     Float: BaseExpression,
@@ -267,6 +293,23 @@ TYPED_FUNCTION_RETURN_MAPPING: TypingDict[Type[CSTNode], object] = {
     LessThanEqual: BaseCompOp,
     List: BaseExpression,
     ListComp: BaseExpression,
+    Match: Union[BaseStatement, RemovalSentinel],
+    MatchAs: MatchPattern,
+    MatchCase: Union[MatchCase, RemovalSentinel],
+    MatchClass: MatchPattern,
+    MatchKeywordElement: Union[MatchKeywordElement, RemovalSentinel],
+    MatchList: MatchPattern,
+    MatchMapping: MatchPattern,
+    MatchMappingElement: Union[MatchMappingElement, RemovalSentinel],
+    MatchOr: MatchPattern,
+    MatchOrElement: Union[MatchOrElement, RemovalSentinel],
+    MatchPattern: MatchPattern,
+    MatchSequence: MatchPattern,
+    MatchSequenceElement: Union[MatchSequenceElement, RemovalSentinel],
+    MatchSingleton: MatchPattern,
+    MatchStar: MatchStar,
+    MatchTuple: MatchPattern,
+    MatchValue: MatchPattern,
     MatrixMultiply: BaseBinaryOp,
     MatrixMultiplyAssign: BaseAugOp,
     Minus: BaseUnaryOp,
@@ -286,6 +329,7 @@ TYPED_FUNCTION_RETURN_MAPPING: TypingDict[Type[CSTNode], object] = {
     Or: BaseBooleanOp,
     Param: Union[Param, MaybeSentinel, RemovalSentinel],
     ParamSlash: Union[ParamSlash, MaybeSentinel],
+    ParamSpec: ParamSpec,
     ParamStar: Union[ParamStar, MaybeSentinel],
     Parameters: Parameters,
     ParenthesizedWhitespace: Union[BaseParenthesizableWhitespace, MaybeSentinel],
@@ -316,7 +360,13 @@ TYPED_FUNCTION_RETURN_MAPPING: TypingDict[Type[CSTNode], object] = {
     SubtractAssign: BaseAugOp,
     TrailingWhitespace: TrailingWhitespace,
     Try: Union[BaseStatement, RemovalSentinel],
+    TryStar: Union[BaseStatement, RemovalSentinel],
     Tuple: BaseExpression,
+    TypeAlias: Union[BaseSmallStatement, RemovalSentinel],
+    TypeParam: Union[TypeParam, RemovalSentinel],
+    TypeParameters: TypeParameters,
+    TypeVar: TypeVar,
+    TypeVarTuple: TypeVarTuple,
     UnaryOperation: BaseExpression,
     While: Union[BaseStatement, RemovalSentinel],
     With: Union[BaseStatement, RemovalSentinel],
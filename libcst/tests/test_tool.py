@@ -153,6 +153,10 @@ class PrettyPrintNodesTest(UnitTest):
                   whitespace_before_colon=SimpleWhitespace(
                     value='',
                   ),
+                  type_parameters=None,
+                  whitespace_after_type_parameters=SimpleWhitespace(
+                    value='',
+                  ),
                 ),
               ],
               header=[],
@@ -244,6 +248,7 @@ class PrettyPrintNodesTest(UnitTest):
                     ),
                   ),
                   asynchronous=None,
+                  type_parameters=None,
                 ),
               ],
               encoding='utf-8',
@@ -533,6 +538,10 @@ class PrettyPrintNodesTest(UnitTest):
                   whitespace_before_colon=SimpleWhitespace(
                     value='',
                   ),
+                  type_parameters=None,
+                  whitespace_after_type_parameters=SimpleWhitespace(
+                    value='',
+                  ),
                 ),
               ],
               header=[],
@@ -613,6 +622,7 @@ class PrettyPrintNodesTest(UnitTest):
                     ),
                   ),
                   asynchronous=None,
+                  type_parameters=None,
                 ),
               ],
             )